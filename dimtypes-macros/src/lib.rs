@@ -0,0 +1,476 @@
+//! Procedural macros for `dimtypes`, re-exported from the main crate
+
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenTree};
+use quote::quote_spanned;
+use syn::{parse_macro_input, Error, Expr, FnArg, Ident, ItemFn, Lit, LitStr, Pat, ReturnType, Type};
+
+/// Check at compile time that the dimensions of a function's parameters and return type satisfy a relation.
+///
+/// The relation is written `lhs = expression`.  Identifiers in the expression name parameters of the function (constants in scope
+/// may also be used), and `lhs` names either a parameter or, if it names none of them, the return value.  For example
+/// `#[check_dims("F = m*a")]` on `fn force(m: Mass, a: Acceleration) -> Force` passes:
+///
+/// ```
+/// use dimtypes::check_dims;
+/// use dimtypes::dimens::*;
+///
+/// #[check_dims("F = m*a")]
+/// fn force(m: Mass, a: Acceleration) -> Force {
+///     m*a
+/// }
+/// ```
+///
+/// while swapping the return type for `Energy` fails to compile with a type mismatch pointing at the attribute:
+///
+/// ```compile_fail
+/// use dimtypes::check_dims;
+/// use dimtypes::dimens::*;
+///
+/// #[check_dims("E = m*a")]
+/// fn force(m: Mass, a: Acceleration) -> Energy {
+///     unimplemented!()
+/// }
+/// ```
+///
+/// The check compiles the relation as a hidden function taking the parameter types and returning the type of `lhs`, so it is
+/// verified by the same [Mul]/[Div] impls as ordinary code and costs nothing at runtime.  Generic functions are not supported.
+///
+/// [Mul]: std::ops::Mul
+/// [Div]: std::ops::Div
+#[proc_macro_attribute]
+pub fn check_dims(attr: TokenStream, item: TokenStream) -> TokenStream {
+	let relation = parse_macro_input!(attr as LitStr);
+	let mut function = parse_macro_input!(item as ItemFn);
+	match check(&relation, &function) {
+		Ok(checker) => {
+			function.block.stmts.insert(0, checker);
+			quote::quote!(#function).into()
+		},
+		Err(e) => {
+			let error = e.to_compile_error();
+			quote::quote!(#error #function).into()
+		},
+	}
+}
+
+fn check(relation: &LitStr, function: &ItemFn) -> Result<syn::Stmt, Error> {
+	let span = relation.span();
+	let text = relation.value();
+	let (lhs, rhs) = text.split_once('=')
+		.filter(|(_, rhs)| !rhs.starts_with('='))
+		.ok_or_else(|| Error::new(span, "expected a relation of the form `lhs = expression`"))?;
+	let lhs = lhs.trim();
+	let rhs: Expr = syn::parse_str(rhs).map_err(|e| Error::new(span, format!("invalid expression in relation: {}", e)))?;
+
+	if !function.sig.generics.params.is_empty() {
+		return Err(Error::new(span, "check_dims does not support generic functions"));
+	}
+
+	let mut names: Vec<Ident> = Vec::new();
+	let mut types: Vec<Type> = Vec::new();
+	for arg in &function.sig.inputs {
+		if let FnArg::Typed(arg) = arg
+			&& let Pat::Ident(pat) = &*arg.pat {
+			names.push(Ident::new(&pat.ident.to_string(), span));
+			types.push((*arg.ty).clone());
+		}
+	}
+
+	let lhs_type = match names.iter().position(|n| n == lhs) {
+		Some(index) => types[index].clone(),
+		None => match &function.sig.output {
+			ReturnType::Type(_, ty) => (**ty).clone(),
+			ReturnType::Default => return Err(Error::new(span,
+				format!("`{}` is not a parameter and the function has no return value", lhs))),
+		},
+	};
+
+	let checker = Ident::new("__check_dims", Span::mixed_site());
+	Ok(syn::parse_quote_spanned! {span=>
+		#[allow(dead_code, unused_variables, non_snake_case, clippy::all)]
+		fn #checker(#(#names: #types),*) -> #lhs_type { #rhs }
+	})
+}
+
+/// Construct a quantity from a number and a unit written inline, e.g. `qty!(55.0 mi/h)`, `qty!(9.81 m/s^2)` or `qty!(20 degC)`.
+///
+/// The unit uses the syntax of `dimtypes::parse` restricted to Rust tokens: symbols with optional SI prefixes, combined by
+/// juxtaposition, `*` or `.` for multiplication and `/` for division of the next factor, with integer powers written `s^2`, `s^-2`,
+/// `s**2` or `s2`, and parentheses for grouping.  Symbols which are not Rust identifiers, such as `µs`, `Ω` or `%`, can be given as
+/// string literals (`qty!(10 "µs")`).  Symbols are resolved and combined at compile time, so the type of the result is that of the
+/// unit, an unknown symbol is a compile error, and there is no runtime parsing.  As in the parser, an offset unit such as `degC`
+/// keeps its offset only when it is the whole unit.
+///
+/// ```
+/// use dimtypes::qty;
+/// use dimtypes::units::*;
+///
+/// let g = qty!(9.81 m/s^2);
+/// assert!((g.as_unit(FOOT/SECOND/SECOND) - 32.185).abs() < 1e-3);
+/// let period = qty!(10 "µs");
+/// assert!((period.as_unit(SECOND) - 1e-5).abs() < 1e-18);
+/// assert!((qty!(20 degC).as_unit(KELVIN) - 293.15).abs() < 1e-9);
+/// ```
+///
+/// An unknown symbol does not compile:
+///
+/// ```compile_fail
+/// let x = dimtypes::qty!(3 furlongs);
+/// ```
+#[proc_macro]
+pub fn qty(input: TokenStream) -> TokenStream {
+	match literal(input.into()) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+enum Unit {
+	Symbol(String, Span),
+	Mul(Box<Unit>, Box<Unit>),
+	Div(Box<Unit>, Box<Unit>),
+	Pow(Box<Unit>, i32, Span),
+}
+
+struct Tokens {
+	tokens: Vec<TokenTree>,
+	pos: usize,
+}
+
+impl Tokens {
+	fn peek(&self) -> Option<&TokenTree> { self.tokens.get(self.pos) }
+	fn punct(&self, c: char) -> bool {
+		matches!(self.peek(), Some(TokenTree::Punct(p)) if p.as_char() == c)
+	}
+	fn span(&self) -> Span {
+		self.peek().map_or_else(Span::call_site, TokenTree::span)
+	}
+
+	/// Product of factors up to the end of the tokens
+	fn expression(&mut self) -> Result<Unit, Error> {
+		let mut unit = self.factor()?;
+		while self.peek().is_some() {
+			let divide = if self.punct('/') {
+				self.pos += 1;
+				true
+			} else {
+				if self.punct('*') || self.punct('.') {
+					self.pos += 1;
+				}
+				false
+			};
+			let factor = self.factor()?;
+			unit = if divide { Unit::Div(Box::new(unit), Box::new(factor)) } else { Unit::Mul(Box::new(unit), Box::new(factor)) };
+		}
+		Ok(unit)
+	}
+
+	/// A symbol or parenthesized expression with an optional integer power
+	fn factor(&mut self) -> Result<Unit, Error> {
+		let span = self.span();
+		let (base, implicit_power) = match self.peek().cloned() {
+			Some(TokenTree::Ident(ident)) => {
+				self.pos += 1;
+				let text = ident.to_string();
+				let symbol = text.trim_end_matches(|c: char| c.is_ascii_digit());
+				let power = match &text[symbol.len()..] {
+					"" => None,
+					digits => Some(digits.parse().map_err(|_| Error::new(span, "invalid power"))?),
+				};
+				(Unit::Symbol(symbol.to_string(), span), power)
+			},
+			Some(TokenTree::Literal(literal)) => match Lit::new(literal) {
+				Lit::Str(s) => {
+					self.pos += 1;
+					(Unit::Symbol(s.value(), span), None)
+				},
+				_ => return Err(Error::new(span, "expected a unit symbol; numeric factors are not supported in qty!")),
+			},
+			Some(TokenTree::Punct(p)) if p.as_char() == '%' => {
+				self.pos += 1;
+				(Unit::Symbol("%".to_string(), span), None)
+			},
+			Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+				self.pos += 1;
+				let mut inner = Tokens { tokens: group.stream().into_iter().collect(), pos: 0 };
+				if inner.peek().is_none() {
+					return Err(Error::new(span, "empty parentheses"));
+				}
+				(inner.expression()?, None)
+			},
+			_ => return Err(Error::new(span, "expected a unit symbol")),
+		};
+		let power = match implicit_power {
+			Some(power) => Some(power),
+			None => self.power()?,
+		};
+		Ok(match power {
+			None | Some(1) => base,
+			Some(0) => return Err(Error::new(span, "a unit cannot be raised to the power 0")),
+			Some(power) => Unit::Pow(Box::new(base), power, span),
+		})
+	}
+
+	/// Power following a factor, `^n` or `**n`
+	fn power(&mut self) -> Result<Option<i32>, Error> {
+		if self.punct('^') {
+			self.pos += 1;
+		} else if self.punct('*') && matches!(self.tokens.get(self.pos + 1), Some(TokenTree::Punct(p)) if p.as_char() == '*') {
+			self.pos += 2;
+		} else {
+			return Ok(None);
+		}
+		let negative = self.punct('-');
+		if negative || self.punct('+') {
+			self.pos += 1;
+		}
+		let span = self.span();
+		let power: i32 = match self.peek() {
+			Some(TokenTree::Literal(literal)) => literal.to_string().parse()
+				.map_err(|_| Error::new(span, "expected an integer power; fractional powers are not supported in qty!"))?,
+			_ => return Err(Error::new(span, "expected an integer power")),
+		};
+		self.pos += 1;
+		Ok(Some(if negative { -power } else { power }))
+	}
+}
+
+/// Const expression for the `ParsedUnit` of a unit
+fn unit_tokens(unit: &Unit) -> proc_macro2::TokenStream {
+	match unit {
+		Unit::Symbol(symbol, span) => quote_spanned!(*span=> ::dimtypes::parse::symbol_unit(#symbol)),
+		Unit::Mul(a, b) => {
+			let (a, b) = (unit_tokens(a), unit_tokens(b));
+			quote::quote!(::dimtypes::parse::unit_product(#a, #b))
+		},
+		Unit::Div(a, b) => {
+			let (a, b) = (unit_tokens(a), unit_tokens(b));
+			quote::quote!(::dimtypes::parse::unit_quotient(#a, #b))
+		},
+		Unit::Pow(base, power, span) => {
+			let base = unit_tokens(base);
+			quote_spanned!(*span=> ::dimtypes::parse::unit_power(#base, #power))
+		},
+	}
+}
+
+/// Const expressions for the value and `ParsedUnit` of a number followed by a unit
+fn value_and_unit(input: proc_macro2::TokenStream) -> Result<(proc_macro2::TokenStream, proc_macro2::TokenStream), Error> {
+	let mut tokens = Tokens { tokens: input.into_iter().collect(), pos: 0 };
+	let negative = tokens.punct('-');
+	if negative {
+		tokens.pos += 1;
+	}
+	let span = tokens.span();
+	let number = match tokens.peek() {
+		Some(TokenTree::Literal(literal)) => match Lit::new(literal.clone()) {
+			Lit::Int(_) | Lit::Float(_) => literal.clone(),
+			_ => return Err(Error::new(span, "expected a number")),
+		},
+		_ => return Err(Error::new(span, "expected a number followed by a unit, e.g. `qty!(55.0 mi/h)`")),
+	};
+	tokens.pos += 1;
+	let sign = if negative { quote::quote!(-) } else { quote::quote!() };
+	let value = quote::quote!((#sign (#number as ::dimtypes::Float)));
+	if tokens.peek().is_none() {
+		return Err(Error::new(tokens.span(), "expected a unit after the number"));
+	}
+	Ok((value, unit_tokens(&tokens.expression()?)))
+}
+
+fn literal(input: proc_macro2::TokenStream) -> Result<proc_macro2::TokenStream, Error> {
+	// Only const fns are called so the expansion needs no unstable features in the calling crate, and the unit and value are
+	// evaluated in const items so nothing is left to do at runtime
+	let (value, unit) = value_and_unit(input)?;
+	Ok(quote::quote!({
+		const UNIT: ::dimtypes::parse::ParsedUnit = #unit;
+		const VALUE: ::dimtypes::Quantity<
+			{UNIT.dimension().time}, {UNIT.dimension().length}, {UNIT.dimension().mass}, {UNIT.dimension().current},
+			{UNIT.dimension().temperature}, {UNIT.dimension().amount}, {UNIT.dimension().luminous_intensity}, {UNIT.dimension().angle},
+			{UNIT.dimension().count}, {UNIT.dimension().denominator},
+		> = ::dimtypes::Quantity::from_si(UNIT.to_si(#value));
+		VALUE
+	}))
+}
+
+/// Declare a table of typed constants, each written as a number and unit as in [qty!], checking at compile time that every unit has
+/// the dimension of the type declared for its row:
+///
+/// ```
+/// use dimtypes::const_table;
+/// use dimtypes::dimens::*;
+/// use dimtypes::units::*;
+///
+/// const_table! {
+///     /// Density of 6061 aluminium
+///     pub AL6061_DENSITY: Density = 2.70 g/cm^3;
+///     pub AL6061_MODULUS: Pressure = 68.9 GPa;
+///     pub AL6061_CONDUCTIVITY: ThermalConductivity = 167 W/(m K);
+/// }
+///
+/// assert!((AL6061_DENSITY.as_unit(KILO*GRAM/METER/METER/METER) - 2700.0).abs() < 1e-3);
+/// ```
+///
+/// A row whose unit does not match, such as a modulus entered in `GPa/m`, fails to compile with a message naming the row, its unit
+/// and the expected type:
+///
+/// ```compile_fail
+/// use dimtypes::const_table;
+/// use dimtypes::dimens::*;
+///
+/// const_table! {
+///     pub AL6061_MODULUS: Pressure = 68.9 GPa/m;
+/// }
+/// ```
+///
+/// Rows are separated by `;` and may carry attributes and a visibility.
+#[proc_macro]
+pub fn const_table(input: TokenStream) -> TokenStream {
+	match table(input.into()) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+/// Attributes, visibility, name and type of a row of a `const_table!`, before its `=`
+struct RowHead {
+	attrs: Vec<syn::Attribute>,
+	vis: syn::Visibility,
+	name: Ident,
+	ty: Type,
+}
+
+impl syn::parse::Parse for RowHead {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<RowHead> {
+		let attrs = input.call(syn::Attribute::parse_outer)?;
+		let vis = input.parse()?;
+		let name = input.parse()?;
+		input.parse::<syn::Token![:]>()?;
+		let ty = input.parse()?;
+		Ok(RowHead { attrs, vis, name, ty })
+	}
+}
+
+fn table(input: proc_macro2::TokenStream) -> Result<proc_macro2::TokenStream, Error> {
+	let mut rows = vec![Vec::new()];
+	for token in input {
+		match &token {
+			TokenTree::Punct(p) if p.as_char() == ';' => rows.push(Vec::new()),
+			_ => rows.last_mut().expect("rows starts non-empty").push(token),
+		}
+	}
+	let mut output = proc_macro2::TokenStream::new();
+	for row in rows.into_iter().filter(|row| !row.is_empty()) {
+		let span = row[0].span();
+		let equals = row.iter()
+			.position(|t| matches!(t, TokenTree::Punct(p) if p.as_char() == '='))
+			.ok_or_else(|| Error::new(span, "expected a row of the form `NAME: Type = value unit;`"))?;
+		let head: RowHead = syn::parse2(row[..equals].iter().cloned().collect())?;
+		let quantity: proc_macro2::TokenStream = row[equals + 1..].iter().cloned().collect();
+		let unit_text = quantity.clone().into_iter().skip_while(|t| !matches!(t, TokenTree::Literal(_))).skip(1)
+			.collect::<proc_macro2::TokenStream>().to_string();
+		let (value, unit) = value_and_unit(quantity)?;
+		let RowHead { attrs, vis, name, ty } = head;
+		let span = name.span();
+		let message = format!("const_table!: unit `{}` of {} does not have the dimension of `{}`", unit_text, name,
+			quote::quote!(#ty).to_string().replace(' ', ""));
+		output.extend(quote_spanned! {span=>
+			#(#attrs)*
+			#vis const #name: #ty = {
+				const UNIT: ::dimtypes::parse::ParsedUnit = #unit;
+				const _: () = ::core::assert!(UNIT.dimension().equals(&<#ty as ::dimtypes::AnyQuantity>::DIMENSION), #message);
+				<#ty>::from_si(UNIT.to_si(#value))
+			};
+		});
+	}
+	Ok(output)
+}
+
+/// Derive `dimtypes::params::Params` for a struct whose fields are all quantities, reading and storing each field as the parameter
+/// of the same name.  Fields are saved in SI base units unless given `#[param(units = "...")]`, and may be stored under another name
+/// with `#[param(name = "...")]`:
+///
+/// ```
+/// use dimtypes::dimens::*;
+/// use dimtypes::params::{ParamSet, Params};
+/// use dimtypes::units::*;
+///
+/// #[derive(Params)]
+/// struct Launch {
+///     #[param(units = "m/s")]
+///     speed: Velocity,
+///     #[param(name = "launch_angle", units = "deg")]
+///     angle: Angle,
+///     mass: Mass,
+/// }
+///
+/// let launch = Launch { speed: 30.0*METER/SECOND, angle: 45.0*DEGREE, mass: 0.5*KILO*GRAM };
+/// let toml = launch.to_params().to_toml();
+/// assert!(toml.contains("launch_angle = \"45 deg\""));
+/// let loaded = Launch::from_params(&ParamSet::from_toml(&toml).unwrap()).unwrap();
+/// assert!((loaded.angle.as_unit(DEGREE) - 45.0).abs() < 1e-9);
+/// ```
+///
+/// A field whose type is not a quantity does not compile, and a `units` attribute of the wrong dimension for its field panics in
+/// `to_params`.
+#[proc_macro_derive(Params, attributes(param))]
+pub fn derive_params(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as syn::DeriveInput);
+	match params(&input) {
+		Ok(tokens) => tokens.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+fn params(input: &syn::DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+	let name = &input.ident;
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(&input.generics, "Params cannot be derived for generic structs"));
+	}
+	let fields = match &input.data {
+		syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(fields), .. }) => &fields.named,
+		_ => return Err(Error::new_spanned(name, "Params can only be derived for structs with named fields")),
+	};
+
+	let mut reads = Vec::new();
+	let mut writes = Vec::new();
+	for field in fields {
+		let ident = field.ident.as_ref().expect("named fields have identifiers");
+		let mut key = ident.to_string();
+		let mut units: Option<LitStr> = None;
+		for attr in field.attrs.iter().filter(|a| a.path().is_ident("param")) {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("name") {
+					key = meta.value()?.parse::<LitStr>()?.value();
+				} else if meta.path.is_ident("units") {
+					units = Some(meta.value()?.parse()?);
+				} else {
+					return Err(meta.error("expected `name` or `units`"));
+				}
+				Ok(())
+			})?;
+		}
+		reads.push(quote::quote!(#ident: set.get(#key)?));
+		writes.push(match units {
+			Some(units) => {
+				let message = format!("units `{}` of parameter '{}' do not match its type", units.value(), key);
+				quote_spanned!(units.span()=> set.set(#key, self.#ident, #units).expect(#message);)
+			},
+			None => quote::quote!(set.set_si(#key, self.#ident);),
+		});
+	}
+
+	Ok(quote::quote! {
+		impl ::dimtypes::params::Params for #name {
+			fn from_params(set: &::dimtypes::params::ParamSet) -> ::core::result::Result<Self, ::dimtypes::params::ParamError> {
+				::core::result::Result::Ok(#name { #(#reads,)* })
+			}
+			fn to_params(&self) -> ::dimtypes::params::ParamSet {
+				let mut set = ::dimtypes::params::ParamSet::new();
+				#(#writes)*
+				set
+			}
+		}
+	})
+}
@@ -0,0 +1,124 @@
+//! Octave-band and frequency weighting utilities for noise analysis
+//!
+//! Sound levels are handled as RMS [Pressure] quantities, which can be entered and displayed in dB using the [SPL][crate::units::SPL] log unit
+//! (e.g. `85.0*SPL` or `p.as_unit(SPL)`).
+
+use crate::{Float,Quantity};
+use crate::dimens::*;
+use crate::units::HERTZ;
+
+/// Ratio between successive octave band center frequencies in the base-ten system of IEC 61260 (10<sup>0.3</sup> ≈ 1.995)
+pub const OCTAVE_RATIO: Float = 1.9952623149688795;
+
+/// Band center frequencies from `lowest` to `highest` (inclusive of any band whose center lies in that range) for 1/`fraction` octave bands,
+/// e.g. `fraction = 1` for octave bands and `fraction = 3` for third-octave bands.  Centers are the exact IEC 61260-1 midband frequencies
+/// referenced to 1 kHz, which round to the familiar nominal values (31.5, 63, 125 Hz, ...).  For even `fraction` the bands are offset
+/// by half a band so 1 kHz falls on a band edge, with centers 1000·G<sup>(2k+1)/(2·fraction)</sup> Hz.  Panics if `fraction` is zero,
+/// `lowest` is not positive or `highest` is not finite, as the range would have no lower or upper end
+pub fn band_centers(fraction: u32, lowest: Frequency, highest: Frequency) -> Vec<Frequency> {
+	assert!(fraction > 0, "band fraction must be at least 1");
+	let step = OCTAVE_RATIO.log10()/(fraction as Float);
+	let offset = if fraction.is_multiple_of(2) { 0.5 } else { 0.0 };
+	let lo = lowest.as_unit(HERTZ);
+	let hi = highest.as_unit(HERTZ);
+	assert!(lo > 0.0 && hi.is_finite(), "band range must be from a positive frequency to a finite one");
+	let first = ((lo.log10() - 3.0)/step - offset).ceil() as i32;
+	let last = ((hi.log10() - 3.0)/step - offset).floor() as i32;
+	(first..=last).map(|k| Float::powf(10.0, 3.0 + (k as Float + offset)*step)*HERTZ).collect()
+}
+
+/// Center frequencies of the octave bands from `lowest` to `highest`
+pub fn octave_band_centers(lowest: Frequency, highest: Frequency) -> Vec<Frequency> {
+	band_centers(1, lowest, highest)
+}
+
+/// Center frequencies of the third-octave bands from `lowest` to `highest`
+pub fn third_octave_band_centers(lowest: Frequency, highest: Frequency) -> Vec<Frequency> {
+	band_centers(3, lowest, highest)
+}
+
+/// Lower and upper edge frequencies of the 1/`fraction` octave band centered on `center`
+pub fn band_edges(fraction: u32, center: Frequency) -> (Frequency, Frequency) {
+	let half = OCTAVE_RATIO.powf(0.5/(fraction as Float));
+	(center/half, center*half)
+}
+
+/// Standard frequency weighting curves from IEC 61672
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weighting {
+	A,
+	C,
+	/// Flat (unweighted) response
+	Z,
+}
+
+impl Weighting {
+	/// Gain of this weighting curve at frequency `f` as a power ratio.  Use <code>.as_unit([DECIBEL][crate::units::DECIBEL])</code> to get
+	/// the familiar tabulated dB values (e.g. -19.1 dB for A-weighting at 100 Hz)
+	pub fn gain(self, f: Frequency) -> Unitless {
+		let f2 = f.as_unit(HERTZ).powi(2);
+		const F1: Float = 20.598997;
+		const F2: Float = 107.65265;
+		const F3: Float = 737.86223;
+		const F4: Float = 12194.217;
+		let amplitude = match self {
+			Weighting::A => {
+				let r = F4*F4*f2*f2/((f2 + F1*F1)*((f2 + F2*F2)*(f2 + F3*F3)).sqrt()*(f2 + F4*F4));
+				r*Float::powf(10.0, 2.0/20.0)
+			},
+			Weighting::C => {
+				let r = F4*F4*f2/((f2 + F1*F1)*(f2 + F4*F4));
+				r*Float::powf(10.0, 0.062/20.0)
+			},
+			Weighting::Z => 1.0,
+		};
+		Unitless::from(amplitude*amplitude)
+	}
+
+	/// Apply this weighting to the sound pressure `level` of a band centered at `f`
+	pub fn apply(self, level: Pressure, f: Frequency) -> Pressure {
+		level*Float::from(self.gain(f)).sqrt()
+	}
+}
+
+/// Energetic sum of several sound pressure levels, as when combining bands into an overall level or adding uncorrelated sources
+pub fn combine_levels(levels: &[Pressure]) -> Pressure {
+	levels.iter().fold(Quantity::from_si(0.0), |acc, p| acc + p.pow::<2>()).root::<2>()
+}
+
+/// Overall weighted level of a band spectrum given as (center frequency, band level) pairs, e.g. the dB(A) level of an octave-band measurement
+pub fn weighted_level(bands: &[(Frequency, Pressure)], weighting: Weighting) -> Pressure {
+	let weighted: Vec<Pressure> = bands.iter().map(|&(f, p)| weighting.apply(p, f)).collect();
+	combine_levels(&weighted)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn band_centers_odd_and_even_fractions() {
+		let hz = |centers: Vec<Frequency>| centers.iter().map(|f| f.as_unit(HERTZ)).collect::<Vec<_>>();
+		let close = |a: Float, b: Float| (a - b).abs() <= 64.0*Float::EPSILON*b;
+		let octaves = hz(octave_band_centers(30.0*HERTZ, 20000.0*HERTZ));
+		assert_eq!(octaves.len(), 10);
+		assert!((octaves[0] - 31.62).abs() < 0.01 && close(octaves[5], 1000.0));
+		// Half-octave bands straddle 1 kHz at 1000·G^(±1/4)
+		let halves = hz(band_centers(2, 800.0*HERTZ, 1250.0*HERTZ));
+		assert_eq!(halves.len(), 2);
+		assert!(close(halves[0], 1000.0/OCTAVE_RATIO.powf(0.25)));
+		assert!(close(halves[1], 1000.0*OCTAVE_RATIO.powf(0.25)));
+	}
+
+	#[test]
+	#[should_panic]
+	fn band_centers_reject_zero_lowest() {
+		band_centers(3, 0.0*HERTZ, 1000.0*HERTZ);
+	}
+
+	#[test]
+	#[should_panic]
+	fn band_centers_reject_zero_fraction() {
+		band_centers(0, 20.0*HERTZ, 1000.0*HERTZ);
+	}
+}
@@ -0,0 +1,58 @@
+//! Lift, drag and related relations for flight performance calculations
+//!
+//! Aerodynamic forces are the product of the dynamic pressure q = ½ρv², a reference area S (usually the wing area) and a dimensionless
+//! coefficient.
+
+use crate::float_consts::PI;
+use crate::dimens::*;
+
+/// Dynamic pressure ½ρv² of a flow of density `density` at speed `velocity`
+pub fn dynamic_pressure(density: Density, velocity: Velocity) -> Pressure {
+	0.5*density*velocity*velocity
+}
+
+/// Lift generated at dynamic pressure `q` by a reference area `area` with lift coefficient `cl`
+pub fn lift(q: Pressure, area: Area, cl: Unitless) -> Force {
+	q*area*cl
+}
+
+/// Drag at dynamic pressure `q` on a reference area `area` with drag coefficient `cd`
+pub fn drag(q: Pressure, area: Area, cd: Unitless) -> Force {
+	q*area*cd
+}
+
+/// Lift coefficient needed to generate `lift` at dynamic pressure `q` on a reference area `area`
+pub fn lift_coefficient(lift: Force, q: Pressure, area: Area) -> Unitless {
+	lift/(q*area)
+}
+
+/// Aspect ratio b²/S of a wing of span `span` and area `area`
+pub fn aspect_ratio(span: Length, area: Area) -> Unitless {
+	span*span/area
+}
+
+/// Induced drag coefficient C<sub>L</sub>²/(π e AR) at lift coefficient `cl`, for a wing of aspect ratio `aspect_ratio` and Oswald
+/// efficiency factor `oswald` (1 for an elliptical lift distribution)
+pub fn induced_drag_coefficient(cl: Unitless, aspect_ratio: Unitless, oswald: Unitless) -> Unitless {
+	cl*cl/(PI*oswald*aspect_ratio)
+}
+
+/// Induced drag L²/(q π e b²) of a wing of span `span` and Oswald efficiency factor `oswald` generating `lift` at dynamic pressure `q`
+pub fn induced_drag(lift: Force, q: Pressure, span: Length, oswald: Unitless) -> Force {
+	lift*lift/(q*PI*oswald*span*span)
+}
+
+/// Drag coefficient of the drag polar C<sub>D</sub> = C<sub>D0</sub> + C<sub>L</sub>²/(π e AR)
+pub fn drag_polar(cd0: Unitless, cl: Unitless, aspect_ratio: Unitless, oswald: Unitless) -> Unitless {
+	cd0 + induced_drag_coefficient(cl, aspect_ratio, oswald)
+}
+
+/// Wing loading of an aircraft of mass `mass` with wing area `area`, under standard gravity
+pub fn wing_loading(mass: Mass, area: Area) -> WingLoading {
+	mass*crate::consts::STANDARD_GRAVITY/area
+}
+
+/// Stall speed at `wing_loading` in air of density `density`, for a maximum lift coefficient `cl_max`
+pub fn stall_speed(wing_loading: WingLoading, density: Density, cl_max: Unitless) -> Velocity {
+	(2.0*wing_loading/(density*cl_max)).root::<2>()
+}
@@ -0,0 +1,73 @@
+//! Unit-aware math on fixed-size arrays of quantities
+//!
+//! Small state vectors such as 3-axis accelerometer or magnetometer readings can be kept as plain `[Quantity; N]` arrays.  These
+//! functions provide the usual vector operations on them with dimension checking and without allocation.
+
+use std::array;
+use crate::{Float,Product,Quantity};
+use crate::dimens::Unitless;
+
+/// Elementwise sum of two arrays of the same dimension
+pub fn add<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(a: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N], b: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N]) -> [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N] {
+	array::from_fn(|i| a[i] + b[i])
+}
+
+/// Elementwise difference of two arrays of the same dimension
+pub fn sub<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(a: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N], b: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N]) -> [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N] {
+	array::from_fn(|i| a[i] - b[i])
+}
+
+/// Multiply every element of `a` by the quantity `k`
+pub fn scale<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize, const N: usize>
+	(a: [Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>; N], k: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> [Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; N] {
+	array::from_fn(|i| a[i]*k)
+}
+
+/// Elementwise (Hadamard) product of two arrays
+pub fn mul_elementwise<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize, const N: usize>
+	(a: [Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>; N], b: [Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; N]) -> [Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; N] {
+	array::from_fn(|i| a[i]*b[i])
+}
+
+/// Dot product of two arrays.  The result has the product of their dimensions
+pub fn dot<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize, const N: usize>
+	(a: [Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>; N], b: [Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; N]) -> Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2> {
+	Quantity::from_si((0..N).map(|i| a[i].as_si()*b[i].as_si()).sum())
+}
+
+/// Cross product of two 3-vectors.  The result has the product of their dimensions
+pub fn cross<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(a: [Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>; 3], b: [Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; 3]) -> [Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>; 3] {
+	[a[1]*b[2] - a[2]*b[1], a[2]*b[0] - a[0]*b[2], a[0]*b[1] - a[1]*b[0]]
+}
+
+/// Euclidean norm (magnitude) of `a`
+pub fn norm<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(a: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N]) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	Quantity::from_si(a.iter().map(|x| x.as_si().powi(2)).sum::<Float>().sqrt())
+}
+
+/// Unit vector in the direction of `a`
+pub fn normalize<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(a: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N]) -> [Unitless; N] {
+	let magnitude = norm(a);
+	array::from_fn(|i| Unitless::from(a[i].as_si()/magnitude.as_si()))
+}
+
+/// Numerical values of each element of `a` in `unit`
+pub fn as_unit<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(a: [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N], unit: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> [Float; N] {
+	array::from_fn(|i| a[i].as_unit(unit))
+}
+
+/// Array of quantities from numerical `values` in `unit`
+pub fn from_values<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize, const N: usize>
+	(values: [Float; N], unit: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> [Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>; N] {
+	array::from_fn(|i| values[i]*unit)
+}
@@ -0,0 +1,97 @@
+//! Lock-free shared quantities for multi-threaded control and telemetry code
+//!
+//! An [AtomicQuantity] stores the bits of a quantity's SI value in an atomic integer, so a setpoint written by one thread (e.g. a
+//! UI or supervisory loop) can be read by another (e.g. a control loop) without a mutex, while both sides keep the quantity's type.
+//! Read-modify-write operations such as [AtomicQuantity::fetch_add] are compare-and-swap loops over the SI value.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use crate::{AnyQuantity,Float};
+
+#[cfg(not(feature = "f32"))]
+type AtomicBits = std::sync::atomic::AtomicU64;
+#[cfg(feature = "f32")]
+type AtomicBits = std::sync::atomic::AtomicU32;
+
+/// A quantity of type `Q` which can be shared between threads, with the same [Ordering] semantics as the standard atomics
+pub struct AtomicQuantity<Q> {
+	bits: AtomicBits,
+	kind: PhantomData<Q>,
+}
+
+impl<Q: AnyQuantity> AtomicQuantity<Q> {
+	pub fn new(value: Q) -> AtomicQuantity<Q> {
+		AtomicQuantity { bits: AtomicBits::new(value.as_si().to_bits()), kind: PhantomData }
+	}
+
+	pub fn load(&self, order: Ordering) -> Q {
+		Q::from_si(Float::from_bits(self.bits.load(order)))
+	}
+	pub fn store(&self, value: Q, order: Ordering) {
+		self.bits.store(value.as_si().to_bits(), order)
+	}
+	/// Store `value`, returning the previous value
+	pub fn swap(&self, value: Q, order: Ordering) -> Q {
+		Q::from_si(Float::from_bits(self.bits.swap(value.as_si().to_bits(), order)))
+	}
+	/// Store `new` if the current value is bitwise identical to `current`, returning the previous value in [Ok] if it was replaced and in
+	/// [Err] otherwise
+	pub fn compare_exchange(&self, current: Q, new: Q, success: Ordering, failure: Ordering) -> Result<Q, Q> {
+		self.bits.compare_exchange(current.as_si().to_bits(), new.as_si().to_bits(), success, failure)
+			.map(|bits| Q::from_si(Float::from_bits(bits)))
+			.map_err(|bits| Q::from_si(Float::from_bits(bits)))
+	}
+	/// Replace the value with `f` of the value, retrying if another thread changes it in between, as in
+	/// [AtomicU64::fetch_update][std::sync::atomic::AtomicU64::fetch_update].  Returns the previous value, or [Err] if `f` returned [None]
+	pub fn fetch_update(&self, set_order: Ordering, fetch_order: Ordering, mut f: impl FnMut(Q) -> Option<Q>) -> Result<Q, Q> {
+		self.bits.fetch_update(set_order, fetch_order, |bits| f(Q::from_si(Float::from_bits(bits))).map(|v| v.as_si().to_bits()))
+			.map(|bits| Q::from_si(Float::from_bits(bits)))
+			.map_err(|bits| Q::from_si(Float::from_bits(bits)))
+	}
+	/// Add `value`, returning the previous value
+	pub fn fetch_add(&self, value: Q, order: Ordering) -> Q {
+		self.update(order, |v| v + value)
+	}
+	/// Subtract `value`, returning the previous value
+	pub fn fetch_sub(&self, value: Q, order: Ordering) -> Q {
+		self.update(order, |v| v - value)
+	}
+	/// Replace the value with the larger of it and `value` (ignoring NaN as [Float::max] does), returning the previous value
+	pub fn fetch_max(&self, value: Q, order: Ordering) -> Q {
+		self.update(order, |v| Q::from_si(v.as_si().max(value.as_si())))
+	}
+	/// Replace the value with the smaller of it and `value` (ignoring NaN as [Float::min] does), returning the previous value
+	pub fn fetch_min(&self, value: Q, order: Ordering) -> Q {
+		self.update(order, |v| Q::from_si(v.as_si().min(value.as_si())))
+	}
+
+	fn update(&self, order: Ordering, mut f: impl FnMut(Q) -> Q) -> Q {
+		let fetch_order = match order {
+			Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+			_ => Ordering::Acquire,
+		};
+		match self.fetch_update(order, fetch_order, |v| Some(f(v))) {
+			Ok(previous) | Err(previous) => previous,
+		}
+	}
+
+	pub fn into_inner(self) -> Q {
+		Q::from_si(Float::from_bits(self.bits.into_inner()))
+	}
+}
+
+impl<Q: AnyQuantity> From<Q> for AtomicQuantity<Q> {
+	fn from(value: Q) -> Self { AtomicQuantity::new(value) }
+}
+
+impl<Q: AnyQuantity> Default for AtomicQuantity<Q> {
+	fn default() -> Self { AtomicQuantity::new(Q::from_si(0.0)) }
+}
+
+/// Formats the current value, loaded with [Ordering::Relaxed]
+impl<Q: AnyQuantity> fmt::Debug for AtomicQuantity<Q> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+	}
+}
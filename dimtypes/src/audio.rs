@@ -0,0 +1,63 @@
+//! Sample-rate, buffer and tempo arithmetic for audio software
+//!
+//! Sample rates and tempos are [Frequency] quantities, so `48.0*KILO*HERTZ` and `120.0*BPM` can be mixed freely with latencies in
+//! [Time].  Sample counts are plain integers, rounded to the nearest sample unless noted otherwise.
+
+use crate::Float;
+use crate::dimens::*;
+
+/// Duration of `samples` samples at `sample_rate`
+pub fn duration_of(samples: usize, sample_rate: Frequency) -> Time {
+	samples as Float/sample_rate
+}
+
+/// Number of samples spanning `duration` at `sample_rate`, rounded to the nearest sample
+pub fn sample_count(duration: Time, sample_rate: Frequency) -> usize {
+	Float::from(duration*sample_rate).round().max(0.0) as usize
+}
+
+/// Latency added by a buffer of `buffer_size` samples at `sample_rate`
+pub fn buffer_latency(buffer_size: usize, sample_rate: Frequency) -> Time {
+	duration_of(buffer_size, sample_rate)
+}
+
+/// Smallest buffer size whose latency at `sample_rate` is at least `latency`
+pub fn buffer_size_for(latency: Time, sample_rate: Frequency) -> usize {
+	Float::from(latency*sample_rate).ceil().max(0.0) as usize
+}
+
+/// Smallest power-of-two buffer size whose latency at `sample_rate` is at least `latency`, as most audio drivers require
+pub fn power_of_two_buffer_for(latency: Time, sample_rate: Frequency) -> usize {
+	buffer_size_for(latency, sample_rate).max(1).next_power_of_two()
+}
+
+/// Round trip latency through input and output paths that each queue `periods` buffers of `buffer_size` samples, plus a fixed
+/// converter and driver latency `extra`
+pub fn round_trip_latency(buffer_size: usize, periods: usize, sample_rate: Frequency, extra: Time) -> Time {
+	2.0*buffer_latency(buffer_size*periods, sample_rate) + extra
+}
+
+/// Convert a sample position at `from` to the same instant at sample rate `to`, as needed when resampling
+pub fn convert_position(position: usize, from: Frequency, to: Frequency) -> usize {
+	sample_count(duration_of(position, from), to)
+}
+
+/// Duration of one beat at `tempo`
+pub fn beat_period(tempo: Frequency) -> Time {
+	1.0/tempo
+}
+
+/// Duration of `beats` beats at `tempo`
+pub fn beats_duration(beats: Float, tempo: Frequency) -> Time {
+	beats/tempo
+}
+
+/// Number of (possibly fractional) samples per beat at `tempo` and `sample_rate`
+pub fn samples_per_beat(tempo: Frequency, sample_rate: Frequency) -> Float {
+	Float::from(sample_rate/tempo)
+}
+
+/// Tempo at which one beat spans `duration`, e.g. to find the tempo of a loop of known length and beat count
+pub fn tempo_for(duration: Time, beats: Float) -> Frequency {
+	beats/duration
+}
@@ -0,0 +1,147 @@
+//! Control-volume balance checks
+//!
+//! A [ControlVolume] collects the flows of a conserved quantity across its boundary (e.g. [MassFlowRate][crate::dimens::MassFlowRate]
+//! for a mass balance or [Power][crate::dimens::Power] for an energy balance) and checks that inflow − outflow + generation − accumulation
+//! closes to within a tolerance.
+
+use std::fmt;
+use crate::{Float,Quantity};
+
+/// Role of a term in a balance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Term {
+	Inflow,
+	Outflow,
+	/// Generation (positive) or consumption (negative) within the volume, e.g. a reaction or heater
+	Generation,
+	/// Rate of accumulation of the quantity inside the volume (zero at steady state)
+	Accumulation,
+}
+
+/// Control volume with a set of named rate terms of dimension `Q`
+#[derive(Clone, Debug)]
+pub struct ControlVolume<Q> {
+	name: String,
+	terms: Vec<(String, Term, Q)>,
+	display_unit: Q,
+	display_symbol: String,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+ControlVolume<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	/// Create an empty control volume called `name`.  Imbalances are reported in SI base units unless [ControlVolume::report_in] is used
+	pub fn new(name: &str) -> Self {
+		ControlVolume {
+			name: name.to_string(),
+			terms: Vec::new(),
+			display_unit: Quantity::from_si(1.0),
+			display_symbol: Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION.to_string(),
+		}
+	}
+
+	/// Report imbalances as values of `unit`, labelled with `symbol` (e.g. `report_in(KILO*GRAM/HOUR, "kg/h")`)
+	pub fn report_in(&mut self, unit: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, symbol: &str) -> &mut Self {
+		self.display_unit = unit;
+		self.display_symbol = symbol.to_string();
+		self
+	}
+
+	/// Add a term `rate` called `name` playing the given `role`
+	pub fn term(&mut self, name: &str, role: Term, rate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		self.terms.push((name.to_string(), role, rate));
+		self
+	}
+	pub fn inflow(&mut self, name: &str, rate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self { self.term(name, Term::Inflow, rate) }
+	pub fn outflow(&mut self, name: &str, rate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self { self.term(name, Term::Outflow, rate) }
+	pub fn generation(&mut self, name: &str, rate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self { self.term(name, Term::Generation, rate) }
+	pub fn accumulation(&mut self, name: &str, rate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self { self.term(name, Term::Accumulation, rate) }
+
+	fn total(&self, role: Term) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		self.terms.iter().filter(|(_, r, _)| *r == role).fold(Quantity::from_si(0.0), |sum, (_, _, rate)| sum + *rate)
+	}
+
+	/// Total of all inflow terms
+	pub fn total_in(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { self.total(Term::Inflow) }
+	/// Total of all outflow terms
+	pub fn total_out(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { self.total(Term::Outflow) }
+
+	/// Balance residual: inflow − outflow + generation − accumulation.  Zero for a closed balance
+	pub fn residual(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		self.total(Term::Inflow) - self.total(Term::Outflow) + self.total(Term::Generation) - self.total(Term::Accumulation)
+	}
+
+	/// Check that the magnitude of the [residual][ControlVolume::residual] is no more than `tolerance`, returning the residual if so
+	pub fn check(&self, tolerance: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Result<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, Imbalance> {
+		let residual = self.residual();
+		if residual.as_si().abs() <= tolerance.as_si().abs() {
+			Ok(residual)
+		} else {
+			Err(Imbalance {
+				volume: self.name.clone(),
+				residual: residual.as_unit(self.display_unit),
+				tolerance: tolerance.as_unit(self.display_unit),
+				relative: Some(self.total_in().as_si()).filter(|&inflow| inflow != 0.0).map(|inflow| residual.as_si()/inflow),
+				symbol: self.display_symbol.clone(),
+			})
+		}
+	}
+
+	/// Check that the magnitude of the [residual][ControlVolume::residual] is no more than `fraction` of the total inflow.  With no
+	/// inflow the tolerance is zero, so only an exactly closed balance passes
+	pub fn check_relative(&self, fraction: Float) -> Result<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, Imbalance> {
+		self.check(fraction*self.total_in())
+	}
+}
+
+/// Error describing a balance which did not close, with values in the control volume's report unit
+#[derive(Clone, Debug)]
+pub struct Imbalance {
+	pub volume: String,
+	pub residual: Float,
+	pub tolerance: Float,
+	/// Residual as a fraction of the total inflow, or [None] if the total inflow is zero
+	pub relative: Option<Float>,
+	pub symbol: String,
+}
+
+impl fmt::Display for Imbalance {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let digits = f.precision().unwrap_or(3);
+		write!(f, "balance on '{}' does not close: residual {:.*} {} ", self.volume, digits, self.residual, self.symbol)?;
+		match self.relative {
+			Some(relative) => write!(f, "({:.2} % of inflow)", 100.0*relative)?,
+			None => write!(f, "(no inflow)")?,
+		}
+		write!(f, " exceeds tolerance {:.*} {}", digits, self.tolerance, self.symbol)
+	}
+}
+
+impl std::error::Error for Imbalance {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::dimens::MassFlowRate;
+	use crate::units::*;
+
+	#[test]
+	fn relative_imbalance() {
+		let mut tank = ControlVolume::new("tank");
+		tank.inflow("feed", 10.0*KILO*GRAM/SECOND).outflow("drain", 9.0*KILO*GRAM/SECOND);
+		let imbalance = tank.check_relative(0.05).unwrap_err();
+		assert_eq!(imbalance.relative, Some(0.1));
+		assert_eq!(imbalance.to_string(),
+			"balance on 'tank' does not close: residual 1.000 kg s^-1 (10.00 % of inflow) exceeds tolerance 0.500 kg s^-1");
+	}
+
+	#[test]
+	fn no_inflow() {
+		let mut tank = ControlVolume::new("tank");
+		tank.outflow("drain", 2.0*KILO*GRAM/SECOND);
+		let imbalance = tank.check_relative(0.05).unwrap_err();
+		assert_eq!(imbalance.relative, None);
+		assert!(imbalance.to_string().contains("(no inflow)"));
+		let mut empty = ControlVolume::<MassFlowRate>::new("empty");
+		assert!(empty.outflow("drain", 0.0*KILO*GRAM/SECOND).check_relative(0.05).is_ok());
+	}
+}
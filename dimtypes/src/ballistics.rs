@@ -0,0 +1,52 @@
+//! Exterior ballistics helpers: projectile energy, sectional density and vacuum trajectories
+//!
+//! Inputs are plain quantities, so the customary grains and feet per second work directly, e.g.
+//! `muzzle_energy(150.0*GRAIN, 2800.0*FOOT/SECOND).as_unit(FOOT_POUND)`.  Trajectory helpers ignore air resistance, which makes them
+//! upper bounds on range and lower bounds on drop.
+
+use crate::dimens::*;
+use crate::math::{sin,cos,tan};
+
+/// Kinetic energy ½mv² of a projectile of mass `mass` at `velocity`
+pub fn muzzle_energy(mass: Mass, velocity: Velocity) -> Energy {
+	0.5*mass*velocity*velocity
+}
+
+/// Momentum of a projectile of mass `mass` at `velocity`
+pub fn projectile_momentum(mass: Mass, velocity: Velocity) -> Momentum {
+	mass*velocity
+}
+
+/// Sectional density m/d² of a projectile of mass `mass` and diameter `diameter`.  Customarily quoted in lb/in²
+pub fn sectional_density(mass: Mass, diameter: Length) -> ArealDensity {
+	mass/(diameter*diameter)
+}
+
+/// Ballistic coefficient of a projectile with sectional density `sectional_density` and form factor `form_factor` relative to a
+/// standard projectile (such as G1)
+pub fn ballistic_coefficient(sectional_density: ArealDensity, form_factor: Unitless) -> ArealDensity {
+	sectional_density/form_factor
+}
+
+/// Time for a projectile fired horizontally at `velocity` to travel `range` in a vacuum
+pub fn time_of_flight(range: Length, velocity: Velocity) -> Time {
+	range/velocity
+}
+
+/// Drop below the line of departure of a projectile fired horizontally at `velocity`, after travelling `range` in a vacuum under `gravity`
+pub fn drop(range: Length, velocity: Velocity, gravity: Acceleration) -> Length {
+	let t = time_of_flight(range, velocity);
+	0.5*gravity*t*t
+}
+
+/// Height relative to the muzzle at horizontal distance `range` of a projectile launched at `velocity` and `elevation` above the
+/// horizontal, in a vacuum under `gravity`
+pub fn trajectory_height(range: Length, velocity: Velocity, elevation: Angle, gravity: Acceleration) -> Length {
+	let vx = velocity*cos(elevation);
+	range*tan(elevation) - gravity*range*range/(2.0*vx*vx)
+}
+
+/// Range on level ground of a projectile launched at `velocity` and `elevation` above the horizontal, in a vacuum under `gravity`
+pub fn vacuum_range(velocity: Velocity, elevation: Angle, gravity: Acceleration) -> Length {
+	velocity*velocity*sin(2.0*elevation)/gravity
+}
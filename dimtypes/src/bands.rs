@@ -0,0 +1,130 @@
+//! Classification of quantities into contiguous ranges
+//!
+//! Scales such as the Beaufort scale of wind speeds, or the alarm levels of a temperature, map ranges of a quantity to a category.
+//! [Bands] holds such a mapping with typed bounds, checked when it is built so that no two bands overlap and, unless gaps are asked
+//! for, every value between the first and last bound falls in exactly one band.  Each band includes its lower bound and excludes its
+//! upper bound; open-ended bands use infinite bounds.
+
+use std::cmp::Ordering;
+use std::fmt;
+use crate::AnyQuantity;
+
+/// Reason a set of ranges cannot form [Bands]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BandError {
+	/// There are no bands
+	Empty,
+	/// The band at this index has an upper bound not greater than its lower bound, or a NaN bound
+	Inverted(usize),
+	/// The band at this index starts before the band before it ends
+	Overlap(usize),
+	/// The band at this index starts after the band before it ends
+	Gap(usize),
+	/// [Bands::from_edges] was given this many values for one fewer bands
+	EdgeCount { edges: usize, values: usize },
+}
+
+impl fmt::Display for BandError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			BandError::Empty => write!(f, "no bands"),
+			BandError::Inverted(index) => write!(f, "band {} does not have an upper bound above its lower bound", index),
+			BandError::Overlap(index) => write!(f, "band {} overlaps the band before it", index),
+			BandError::Gap(index) => write!(f, "band {} leaves a gap after the band before it", index),
+			BandError::EdgeCount { edges, values } => write!(f, "{} edges bound {} bands, not {}", edges, edges.saturating_sub(1), values),
+		}
+	}
+}
+
+impl std::error::Error for BandError {}
+
+/// Range of a quantity from `lower`, inclusive, to `upper`, exclusive, and the value it maps to
+#[derive(Clone, Debug, PartialEq)]
+pub struct Band<D, T> {
+	pub lower: D,
+	pub upper: D,
+	pub value: T,
+}
+
+impl<D: AnyQuantity, T> Band<D, T> {
+	pub fn contains(&self, x: D) -> bool {
+		self.lower.as_si() <= x.as_si() && x.as_si() < self.upper.as_si()
+	}
+}
+
+/// Ranges of a quantity of type `D`, sorted and not overlapping, each mapping to a value of type `T`
+#[derive(Clone, Debug)]
+pub struct Bands<D, T> {
+	bands: Vec<Band<D, T>>,
+}
+
+impl<D: AnyQuantity, T> Bands<D, T> {
+	/// Bands from (lower, upper, value) in any order, which must cover a single range without gaps or overlaps
+	pub fn new(bands: Vec<(D, D, T)>) -> Result<Bands<D, T>, BandError> {
+		let bands = Bands::with_gaps(bands)?;
+		match (1..bands.bands.len()).find(|&i| bands.bands[i].lower.as_si() != bands.bands[i - 1].upper.as_si()) {
+			Some(index) => Err(BandError::Gap(index)),
+			None => Ok(bands),
+		}
+	}
+
+	/// Bands from (lower, upper, value) in any order, which must not overlap but may leave gaps.  Values in a gap are not classified.
+	/// [BandError::Inverted] gives the index of a band as passed, [BandError::Overlap] and [BandError::Gap] as sorted by lower bound
+	pub fn with_gaps(bands: Vec<(D, D, T)>) -> Result<Bands<D, T>, BandError> {
+		if bands.is_empty() {
+			return Err(BandError::Empty);
+		}
+		let mut bands: Vec<Band<D, T>> = bands.into_iter().map(|(lower, upper, value)| Band { lower, upper, value }).collect();
+		if let Some(index) = bands.iter().position(|b| b.upper.as_si().partial_cmp(&b.lower.as_si()) != Some(Ordering::Greater)) {
+			return Err(BandError::Inverted(index));
+		}
+		bands.sort_by(|a, b| a.lower.as_si().total_cmp(&b.lower.as_si()));
+		if let Some(index) = (1..bands.len()).find(|&i| bands[i].lower.as_si() < bands[i - 1].upper.as_si()) {
+			return Err(BandError::Overlap(index));
+		}
+		Ok(Bands { bands })
+	}
+
+	/// Contiguous bands between successive `edges`, which must strictly increase, with one fewer `values` than edges.  E.g. edges
+	/// `[0, 10, 20, inf] degC` and values `[Cold, Mild, Warm]`
+	pub fn from_edges(edges: Vec<D>, values: Vec<T>) -> Result<Bands<D, T>, BandError> {
+		if edges.len() != values.len() + 1 {
+			return Err(BandError::EdgeCount { edges: edges.len(), values: values.len() });
+		}
+		Bands::new(edges.windows(2).zip(values).map(|(pair, value)| (pair[0], pair[1], value)).collect())
+	}
+
+	/// The bands, sorted by lower bound
+	pub fn bands(&self) -> &[Band<D, T>] {
+		&self.bands
+	}
+
+	/// Lower bound of the first band and upper bound of the last
+	pub fn range(&self) -> (D, D) {
+		(self.bands[0].lower, self.bands[self.bands.len() - 1].upper)
+	}
+
+	/// Index of the band containing `x`, or `None` if it is outside the bands, in a gap or NaN
+	pub fn index_of(&self, x: D) -> Option<usize> {
+		let index = self.bands.partition_point(|b| b.upper.as_si() <= x.as_si());
+		self.bands.get(index).filter(|b| b.contains(x)).map(|_| index)
+	}
+
+	/// Value of the band containing `x`, or `None` if it is outside the bands, in a gap or NaN
+	pub fn classify(&self, x: D) -> Option<&T> {
+		self.index_of(x).map(|index| &self.bands[index].value)
+	}
+
+	/// Value of the band containing `x`, taking values below the first band to be in it and values above the last to be in the last.
+	/// `None` only in a gap or for NaN
+	pub fn classify_clamped(&self, x: D) -> Option<&T> {
+		let (lower, upper) = self.range();
+		if x.as_si() < lower.as_si() {
+			Some(&self.bands[0].value)
+		} else if x.as_si() >= upper.as_si() {
+			Some(&self.bands[self.bands.len() - 1].value)
+		} else {
+			self.classify(x)
+		}
+	}
+}
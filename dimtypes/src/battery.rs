@@ -0,0 +1,106 @@
+//! State of charge estimation for battery management
+//!
+//! A [SocEstimator] tracks a cell's state of charge by coulomb counting with a [CoulombCounter], which follows the charge accurately
+//! from moment to moment but drifts with current sensor offset, and corrects it from the open circuit voltage (OCV), which gives the
+//! state of charge absolutely but only once the cell has rested long enough for its terminal voltage to relax.  The OCV curve is a
+//! [LookupTable] of state of charge against voltage, typically from the cell datasheet or a slow discharge test.
+
+use crate::dimens::*;
+use crate::integration::CoulombCounter;
+use crate::lookup::{LookupTable,TableError};
+use crate::units::{AMPERE,MILLI,MINUTE};
+
+/// State of charge estimator combining coulomb counting with open circuit voltage correction at rest
+#[derive(Clone, Debug)]
+pub struct SocEstimator {
+	counter: CoulombCounter,
+	ocv: LookupTable<Voltage, Unitless>,
+	rest_current: Current,
+	relaxation_time: Time,
+	/// Time the cell has spent at rest since the current last exceeded the rest current
+	rested: Time,
+}
+
+impl SocEstimator {
+	/// Estimator for a cell of `capacity` with OCV curve `ocv`, starting at state of charge `soc`.  Treats currents below 10 mA as rest
+	/// and corrects from the OCV after 30 minutes at rest
+	pub fn new(capacity: Charge, ocv: LookupTable<Voltage, Unitless>, soc: Unitless) -> SocEstimator {
+		SocEstimator {
+			counter: CoulombCounter::new(capacity, capacity*soc),
+			ocv,
+			rest_current: 10.0*MILLI*AMPERE,
+			relaxation_time: 30.0*MINUTE,
+			rested: Time::from_si(0.0),
+		}
+	}
+
+	/// Estimator for a rested cell of `capacity` reading `voltage`, starting from the state of charge on the OCV curve `ocv`
+	pub fn from_rest(capacity: Charge, ocv: LookupTable<Voltage, Unitless>, voltage: Voltage) -> SocEstimator {
+		let soc = ocv.get(voltage);
+		SocEstimator::new(capacity, ocv, soc)
+	}
+
+	/// Estimator with the OCV curve given as (state of charge, voltage) points in increasing state of charge, as datasheets tabulate it
+	pub fn from_curve(capacity: Charge, curve: &[(Unitless, Voltage)], soc: Unitless) -> Result<SocEstimator, TableError> {
+		let ocv = LookupTable::new(curve.to_vec())?.inverse()?;
+		Ok(SocEstimator::new(capacity, ocv, soc))
+	}
+
+	/// Treat currents smaller than `current` as rest, e.g. the quiescent draw of the system
+	pub fn rest_current(self, current: Current) -> SocEstimator {
+		SocEstimator { rest_current: current, ..self }
+	}
+
+	/// Correct from the OCV after `time` at rest, long enough for the terminal voltage to relax (longer for LFP and in the cold)
+	pub fn relaxation_time(self, time: Time) -> SocEstimator {
+		SocEstimator { relaxation_time: time, ..self }
+	}
+
+	/// Apply the coulombic efficiency `efficiency` to charging currents
+	pub fn charge_efficiency(self, efficiency: Unitless) -> SocEstimator {
+		SocEstimator { counter: self.counter.with_charge_efficiency(efficiency), ..self }
+	}
+
+	/// Update with `current` (positive charging) and terminal `voltage` measured over the last `interval`
+	pub fn update(&mut self, current: Current, voltage: Voltage, interval: Time) {
+		self.counter.update(current, interval);
+		if current.as_si().abs() < self.rest_current.as_si() {
+			self.rested += interval;
+			if self.rested.as_si() >= self.relaxation_time.as_si() {
+				self.counter.reset(self.counter.capacity()*self.ocv.get(voltage));
+			}
+		} else {
+			self.rested = Time::from_si(0.0);
+		}
+	}
+
+	/// Whether the last update corrected the estimate from the OCV
+	pub fn is_relaxed(&self) -> bool {
+		self.rested.as_si() >= self.relaxation_time.as_si()
+	}
+
+	/// Estimated state of charge
+	pub fn state_of_charge(&self) -> Unitless {
+		self.counter.state_of_charge()
+	}
+
+	/// Estimated charge remaining
+	pub fn remaining(&self) -> Charge {
+		self.counter.charge()
+	}
+
+	/// Time until empty at a steady discharge `current` (given as a positive value)
+	pub fn time_to_empty(&self, current: Current) -> Time {
+		self.remaining()/current
+	}
+
+	/// State of charge on the OCV curve at `voltage`, for a cell at rest
+	pub fn ocv_soc(&self, voltage: Voltage) -> Unitless {
+		self.ocv.get(voltage)
+	}
+
+	/// Underlying coulomb counter, e.g. for its throughput totals
+	pub fn counter(&self) -> &CoulombCounter {
+		&self.counter
+	}
+}
@@ -0,0 +1,114 @@
+//! Strain and force from Wheatstone bridge readings
+//!
+//! Strain gauges and load cells are read as the ratio of bridge output to excitation voltage, conventionally quoted in mV/V, so the
+//! reading does not depend on drift in the excitation supply.  [ratio] forms it from the measured voltages, after which a
+//! [StrainGauge] converts it to [Strain] from the gauge factor and bridge arrangement, and a [LoadCell] to [Force] from its
+//! calibration.  Ratios are taken as positive for tension (or compression for a load cell, following its datasheet).
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::{MILLI,VOLT};
+
+/// Bridge output as a ratio of the excitation voltage
+pub fn ratio(output: Voltage, excitation: Voltage) -> Unitless {
+	output/excitation
+}
+
+/// Millivolts per volt, the usual unit of bridge output ratios and load cell rated outputs
+pub const MV_PER_V: Unitless = MILLI*VOLT/VOLT;
+
+/// Arrangement of active strain gauges in a bridge
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arrangement {
+	/// One active gauge and three completion resistors.  Nonlinear in strain, which [StrainGauge::strain] corrects for
+	Quarter,
+	/// Two active gauges on opposite faces in bending, one in tension and one in compression
+	HalfBending,
+	/// Four active gauges in bending, two in tension and two in compression
+	FullBending,
+}
+
+/// Strain gauge of a given gauge factor wired into a bridge
+#[derive(Clone, Copy, Debug)]
+pub struct StrainGauge {
+	gauge_factor: Float,
+	arrangement: Arrangement,
+	/// Fraction of the gauge signal lost to lead wire resistance
+	lead_loss: Float,
+}
+
+impl StrainGauge {
+	pub const fn new(gauge_factor: Float, arrangement: Arrangement) -> StrainGauge {
+		StrainGauge { gauge_factor, arrangement, lead_loss: 0.0 }
+	}
+
+	/// Correct for the resistance `lead` of the wire in series with each gauge of resistance `gauge` (e.g. 350 Ω), which
+	/// desensitises the bridge by `gauge/(gauge + lead)`
+	pub fn leads(self, lead: Resistance, gauge: Resistance) -> StrainGauge {
+		StrainGauge { lead_loss: (lead/(gauge + lead)).into(), ..self }
+	}
+
+	/// Strain at bridge output ratio `ratio`, measured from the unstrained reading
+	pub fn strain(&self, ratio: Unitless) -> Strain {
+		let vr: Float = ratio.into();
+		let strain = match self.arrangement {
+			Arrangement::Quarter => 4.0*vr/(self.gauge_factor*(1.0 - 2.0*vr)),
+			Arrangement::HalfBending => 2.0*vr/self.gauge_factor,
+			Arrangement::FullBending => vr/self.gauge_factor,
+		};
+		Strain::from(strain/(1.0 - self.lead_loss))
+	}
+
+	/// Bridge output ratio at `strain`, the inverse of [StrainGauge::strain]
+	pub fn ratio(&self, strain: Strain) -> Unitless {
+		let e = Float::from(strain)*(1.0 - self.lead_loss)*self.gauge_factor;
+		Unitless::from(match self.arrangement {
+			Arrangement::Quarter => e/(4.0 + 2.0*e),
+			Arrangement::HalfBending => e/2.0,
+			Arrangement::FullBending => e,
+		})
+	}
+}
+
+/// Load cell converting bridge output ratio linearly to force
+#[derive(Clone, Copy, Debug)]
+pub struct LoadCell {
+	/// Output ratio with no load
+	zero: Unitless,
+	/// Force per unit change of output ratio
+	sensitivity: Force,
+}
+
+impl LoadCell {
+	/// Load cell from its datasheet `capacity` and `rated_output` at capacity (e.g. `2.0*MV_PER_V`), with zero output at no load
+	pub fn new(capacity: Force, rated_output: Unitless) -> LoadCell {
+		LoadCell { zero: Unitless::from(0.0), sensitivity: capacity/Float::from(rated_output) }
+	}
+
+	/// Load cell calibrated by two points, reading ratio `r0` under force `f0` and `r1` under `f1` (e.g. empty and with a
+	/// reference weight)
+	pub fn calibrated((r0, f0): (Unitless, Force), (r1, f1): (Unitless, Force)) -> LoadCell {
+		let sensitivity = (f1 - f0)/Float::from(r1 - r0);
+		LoadCell { zero: r0 - f0/sensitivity, sensitivity }
+	}
+
+	/// Take `ratio` as the zero load reading, e.g. with the fixture mounted but unloaded
+	pub fn tare(self, ratio: Unitless) -> LoadCell {
+		LoadCell { zero: ratio, ..self }
+	}
+
+	/// Force at bridge output ratio `ratio`
+	pub fn force(&self, ratio: Unitless) -> Force {
+		self.sensitivity*Float::from(ratio - self.zero)
+	}
+
+	/// Force when the bridge outputs `output` under `excitation`
+	pub fn force_from(&self, output: Voltage, excitation: Voltage) -> Force {
+		self.force(ratio(output, excitation))
+	}
+
+	/// Bridge output ratio under `force`, the inverse of [LoadCell::force]
+	pub fn ratio(&self, force: Force) -> Unitless {
+		self.zero + force/self.sensitivity
+	}
+}
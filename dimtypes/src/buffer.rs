@@ -0,0 +1,180 @@
+//! Structure-of-arrays sample storage for data acquisition
+//!
+//! A [SampleBuffer] holds a set of named channels, each with a fixed dimension declared when the channel is added.  Samples are stored
+//! column-wise as contiguous [Float] values in SI base units, and read back through typed [ChannelView]s.
+
+use std::fmt;
+use std::marker::PhantomData;
+use crate::{Dimension,Float,Quantity};
+
+/// Typed handle to a channel of a [SampleBuffer]
+#[derive(Debug)]
+pub struct Channel<Q> {
+	index: usize,
+	kind: PhantomData<Q>,
+}
+impl<Q> Clone for Channel<Q> {
+	fn clone(&self) -> Self { *self }
+}
+impl<Q> Copy for Channel<Q> {}
+impl<Q> Channel<Q> {
+	/// Position of the channel among the channels of its buffer
+	pub(crate) fn index(&self) -> usize { self.index }
+}
+
+/// Error accessing a [SampleBuffer] channel by name
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChannelError {
+	/// No channel with this name exists
+	UnknownChannel(String),
+	/// A channel with this name already exists
+	DuplicateChannel(String),
+	/// The channel exists but has a different dimension than requested
+	DimensionMismatch { name: String, expected: Dimension, found: Dimension },
+}
+
+impl fmt::Display for ChannelError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ChannelError::UnknownChannel(name) => write!(f, "no channel named '{}'", name),
+			ChannelError::DuplicateChannel(name) => write!(f, "a channel named '{}' already exists", name),
+			ChannelError::DimensionMismatch { name, expected, found } =>
+				write!(f, "channel '{}' has dimension [{}], not [{}]", name, found, expected),
+		}
+	}
+}
+
+impl std::error::Error for ChannelError {}
+
+#[derive(Clone, Debug)]
+struct Column {
+	name: String,
+	dimension: Dimension,
+	data: Vec<Float>,
+}
+
+/// Column-oriented buffer of samples from several named, dimensioned channels
+#[derive(Clone, Debug, Default)]
+pub struct SampleBuffer {
+	columns: Vec<Column>,
+	rows: usize,
+}
+
+impl SampleBuffer {
+	pub fn new() -> SampleBuffer {
+		SampleBuffer::default()
+	}
+
+	/// Add a channel called `name` holding quantities of type `Q`.  Existing rows are filled with NaN for the new channel
+	pub fn add_channel<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str) -> Result<Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, ChannelError> {
+		if self.columns.iter().any(|c| c.name == name) {
+			return Err(ChannelError::DuplicateChannel(name.to_string()));
+		}
+		self.columns.push(Column {
+			name: name.to_string(),
+			dimension: Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION,
+			data: vec![Float::NAN; self.rows],
+		});
+		Ok(Channel { index: self.columns.len() - 1, kind: PhantomData })
+	}
+
+	/// Look up the channel called `name`, checking that it holds quantities of type `Q`
+	pub fn channel_by_name<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, name: &str) -> Result<Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, ChannelError> {
+		let index = self.columns.iter().position(|c| c.name == name).ok_or_else(|| ChannelError::UnknownChannel(name.to_string()))?;
+		let expected = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		let found = self.columns[index].dimension;
+		if expected != found {
+			return Err(ChannelError::DimensionMismatch { name: name.to_string(), expected, found });
+		}
+		Ok(Channel { index, kind: PhantomData })
+	}
+
+	/// Append a row of samples.  `fill` sets the value of each channel through the provided [RowWriter]; channels it leaves unset are NaN
+	pub fn push_row(&mut self, fill: impl FnOnce(&mut RowWriter)) {
+		let mut row = RowWriter { values: vec![Float::NAN; self.columns.len()] };
+		fill(&mut row);
+		for (column, value) in self.columns.iter_mut().zip(row.values) {
+			column.data.push(value);
+		}
+		self.rows += 1;
+	}
+
+	/// Typed view of the samples in `channel`
+	pub fn view<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, channel: Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> ChannelView<'_, Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		ChannelView { data: &self.columns[channel.index].data, kind: PhantomData }
+	}
+
+	/// Number of rows of samples
+	pub fn len(&self) -> usize { self.rows }
+	pub fn is_empty(&self) -> bool { self.rows == 0 }
+
+	/// Names and dimensions of each channel, in the order they were added
+	pub fn channels(&self) -> impl Iterator<Item=(&str, Dimension)> {
+		self.columns.iter().map(|c| (c.name.as_str(), c.dimension))
+	}
+
+	/// Raw SI values of the channel called `name` along with its dimension, for dimension-erased processing such as file export
+	pub fn raw_column(&self, name: &str) -> Option<(&[Float], Dimension)> {
+		self.columns.iter().find(|c| c.name == name).map(|c| (c.data.as_slice(), c.dimension))
+	}
+
+	/// Remove all rows, keeping the channel definitions
+	pub fn clear(&mut self) {
+		for column in &mut self.columns {
+			column.data.clear();
+		}
+		self.rows = 0;
+	}
+}
+
+/// Values for one row being appended by [SampleBuffer::push_row]
+pub struct RowWriter {
+	values: Vec<Float>
+}
+
+impl RowWriter {
+	/// Set the value of `channel` in this row
+	pub fn set<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, channel: Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		self.values[channel.index] = value.as_si();
+		self
+	}
+	/// Set the value of the channel at `index` in SI base units
+	pub(crate) fn set_raw(&mut self, index: usize, value: Float) {
+		self.values[index] = value;
+	}
+	/// Value of `channel` set so far in this row, NaN if it has not been set
+	pub fn get<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, channel: Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.values[channel.index])
+	}
+}
+
+/// Borrowed, typed view of a single channel of a [SampleBuffer]
+#[derive(Clone, Copy, Debug)]
+pub struct ChannelView<'a, Q> {
+	data: &'a [Float],
+	kind: PhantomData<Q>,
+}
+
+impl<'a, const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+ChannelView<'a, Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	pub fn len(&self) -> usize { self.data.len() }
+	pub fn is_empty(&self) -> bool { self.data.is_empty() }
+	/// Sample at `index`, if in range
+	pub fn get(&self, index: usize) -> Option<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		self.data.get(index).map(|&v| Quantity::from_si(v))
+	}
+	pub fn iter(&self) -> impl Iterator<Item=Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> + 'a {
+		self.data.iter().map(|&v| Quantity::from_si(v))
+	}
+	/// Samples as numerical values of `unit`
+	pub fn to_unit(&self, unit: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Vec<Float> {
+		self.iter().map(|q| q.as_unit(unit)).collect()
+	}
+	/// Underlying contiguous samples in SI base units
+	pub fn as_si_slice(&self) -> &'a [Float] { self.data }
+}
@@ -0,0 +1,82 @@
+//! Calibration records of instruments, mapping a raw reading to a calibrated value over a period of validity
+//!
+//! A [Calibration] holds the linear correction found when an instrument was calibrated, `value = gain*reading + offset`, together with
+//! when it was done and for how long it stays valid, e.g. a load cell reading in millivolts corrected to newtons for one year.  Times
+//! are [Time]s since the Unix epoch, as given by [unix_time].  With the `serde` feature records serialize with every field in its
+//! units, so a calibration file giving the gain of a load cell in `N/mV` loads, while one giving it in `N/V` per kelvin is rejected.
+
+use std::time::{SystemTime,UNIX_EPOCH};
+use crate::{AnyQuantity,Float};
+use crate::control::Gain;
+use crate::dimens::Time;
+
+/// Time since the Unix epoch of the system clock, negative if the clock is set before 1970
+pub fn unix_time() -> Time {
+	match SystemTime::now().duration_since(UNIX_EPOCH) {
+		Ok(since) => Time::from_si(since.as_secs_f64() as Float),
+		Err(before) => Time::from_si(-before.duration().as_secs_f64() as Float),
+	}
+}
+
+/// Linear calibration from a reading of type `I` to a value of type `O`, valid for `validity` from `calibrated_at`
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "I: AnyQuantity, O: AnyQuantity + serde::Serialize",
+	deserialize = "I: AnyQuantity, O: AnyQuantity + serde::Deserialize<'de>")))]
+pub struct Calibration<I, O> {
+	pub gain: Gain<I, O>,
+	/// Value for a zero reading
+	pub offset: O,
+	/// Time since the Unix epoch the calibration was made
+	pub calibrated_at: Time,
+	/// How long after `calibrated_at` the calibration may be used
+	pub validity: Time,
+}
+
+impl<I: AnyQuantity, O: AnyQuantity> Calibration<I, O> {
+	pub fn new(gain: Gain<I, O>, offset: O, calibrated_at: Time, validity: Time) -> Calibration<I, O> {
+		Calibration { gain, offset, calibrated_at, validity }
+	}
+
+	/// Calibration through two points of (reading, reference value), e.g. readings at the zero and span of a reference standard.
+	/// Readings must differ
+	pub fn two_point(low: (I, O), high: (I, O), calibrated_at: Time, validity: Time) -> Calibration<I, O> {
+		let gain: Gain<I, O> = Gain::from_si((high.1.as_si() - low.1.as_si())/(high.0.as_si() - low.0.as_si()));
+		Calibration::new(gain, low.1 - gain.apply(low.0), calibrated_at, validity)
+	}
+
+	/// Calibrated value of `reading`
+	pub fn apply(&self, reading: I) -> O {
+		self.gain.apply(reading) + self.offset
+	}
+
+	/// Reading which gives `value`, e.g. to find the raw setpoint for a calibrated target
+	pub fn invert(&self, value: O) -> I {
+		I::from_si((value - self.offset).as_si()/self.gain.as_si())
+	}
+
+	/// Time since the Unix epoch after which the calibration is out of date
+	pub fn expires_at(&self) -> Time {
+		self.calibrated_at + self.validity
+	}
+
+	/// Whether the calibration may be used at `time` since the Unix epoch, i.e. it was made at or before `time` and has not expired
+	pub fn is_valid_at(&self, time: Time) -> bool {
+		self.calibrated_at.as_si() <= time.as_si() && time.as_si() < self.expires_at().as_si()
+	}
+
+	/// Whether the calibration may be used now, by the system clock
+	pub fn is_valid_now(&self) -> bool {
+		self.is_valid_at(unix_time())
+	}
+
+	/// Time left at `time` since the Unix epoch before the calibration expires, negative once it has
+	pub fn remaining_at(&self, time: Time) -> Time {
+		self.expires_at() - time
+	}
+
+	/// Calibrated value of `reading` at `time` since the Unix epoch, or `None` if the calibration is not valid then
+	pub fn apply_at(&self, reading: I, time: Time) -> Option<O> {
+		self.is_valid_at(time).then(|| self.apply(reading))
+	}
+}
@@ -0,0 +1,64 @@
+//! Clinical laboratory unit conversions
+//!
+//! Lab results are reported by mass (mg/dL, common in the US) or by amount of substance (mmol/L, common elsewhere).  Converting
+//! between them requires the molar mass of the analyte, for which presets are provided.
+
+use std::fmt;
+use crate::{Float,NamedUnit};
+use crate::dimens::*;
+use crate::units::{GRAM,MM_HG,MOLE};
+
+/// Glucose (C<sub>6</sub>H<sub>12</sub>O<sub>6</sub>)
+pub const GLUCOSE: MolarMass = 180.156*GRAM/MOLE;
+/// Cholesterol (C<sub>27</sub>H<sub>46</sub>O), used for total, HDL and LDL cholesterol
+pub const CHOLESTEROL: MolarMass = 386.654*GRAM/MOLE;
+/// Triglycerides, by the clinical convention of using triolein (C<sub>57</sub>H<sub>104</sub>O<sub>6</sub>)
+pub const TRIGLYCERIDES: MolarMass = 885.432*GRAM/MOLE;
+/// Creatinine (C<sub>4</sub>H<sub>7</sub>N<sub>3</sub>O)
+pub const CREATININE: MolarMass = 113.12*GRAM/MOLE;
+/// Blood urea nitrogen, reported as the mass of its two nitrogen atoms
+pub const UREA_NITROGEN: MolarMass = 28.014*GRAM/MOLE;
+
+/// Convert a mass concentration (e.g. in [MG_PER_DL][crate::units::MG_PER_DL]) of an analyte with molar mass `molar_mass` to an amount concentration
+pub fn to_molar(concentration: MassConcentration, molar_mass: MolarMass) -> Concentration {
+	concentration/molar_mass
+}
+
+/// Convert an amount concentration (e.g. in [MMOL_PER_L][crate::units::MMOL_PER_L]) of an analyte with molar mass `molar_mass` to a mass concentration
+pub fn to_mass(concentration: Concentration, molar_mass: MolarMass) -> MassConcentration {
+	concentration*molar_mass
+}
+
+/// Unit in which blood pressures are conventionally displayed
+pub const BLOOD_PRESSURE_UNIT: NamedUnit<Pressure> = MM_HG;
+
+/// Arterial blood pressure reading.  Pressures are gauge values (relative to atmospheric), as is conventional for blood pressure
+#[derive(Clone, Copy, Debug)]
+pub struct BloodPressure {
+	pub systolic: Pressure,
+	pub diastolic: Pressure,
+}
+
+impl BloodPressure {
+	/// Construct a reading from `systolic` and `diastolic` values in mmHg
+	pub fn from_mm_hg(systolic: Float, diastolic: Float) -> BloodPressure {
+		BloodPressure { systolic: systolic*MM_HG, diastolic: diastolic*MM_HG }
+	}
+	/// Mean arterial pressure, estimated as the diastolic pressure plus one third of the pulse pressure
+	pub fn mean_arterial(&self) -> Pressure {
+		self.diastolic + (self.systolic - self.diastolic)/3.0
+	}
+	/// Pulse pressure (systolic minus diastolic)
+	pub fn pulse(&self) -> Pressure {
+		self.systolic - self.diastolic
+	}
+}
+
+/// Formats as "systolic/diastolic mmHg" in [BLOOD_PRESSURE_UNIT], rounded to whole mmHg unless a precision is given
+impl fmt::Display for BloodPressure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let digits = f.precision().unwrap_or(0);
+		write!(f, "{:.*}/{:.*} {}", digits, self.systolic.as_unit(BLOOD_PRESSURE_UNIT), digits, self.diastolic.as_unit(BLOOD_PRESSURE_UNIT),
+			BLOOD_PRESSURE_UNIT)
+	}
+}
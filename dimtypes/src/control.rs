@@ -0,0 +1,300 @@
+//! PID control of physical processes, with step-response analysis and relay autotuning
+//!
+//! A [Pid] drives an output of one quantity (e.g. heater [Power] or valve position) from a process variable of another (e.g.
+//! [Temperature]).  Its [PidGains] are in the standard ISA form, a proportional [Gain] from process variable to output with
+//! integral and derivative times, so every tuning parameter carries its units and gains tuned on one process cannot be applied to
+//! another by mistake.  [StepMetrics] summarises a recorded step response for judging a tuning, and a [RelayTuner] finds the
+//! ultimate gain and period of a process by relay feedback, from which [UltimatePoint::ziegler_nichols] gives initial gains.
+
+use std::marker::PhantomData;
+use crate::{AnyQuantity,Float};
+use crate::float_consts::PI;
+use crate::dimens::*;
+
+/// Ratio of an output quantity of type `O` to an input quantity of type `P`, e.g. watts of heating per kelvin of error
+#[derive(Clone, Copy, Debug)]
+pub struct Gain<P, O> {
+	si: Float,
+	quantities: PhantomData<(P, O)>,
+}
+
+impl<P: AnyQuantity, O: AnyQuantity> Gain<P, O> {
+	/// Gain producing `output` for each `input`, e.g. `Gain::new(50.0*WATT, 1.0*KELVIN)`
+	pub fn new(output: O, input: P) -> Gain<P, O> {
+		Gain::from_si(output.as_si()/input.as_si())
+	}
+
+	/// Gain from its value in SI base units of the output per SI base unit of the input
+	pub fn from_si(si: Float) -> Gain<P, O> {
+		Gain { si, quantities: PhantomData }
+	}
+
+	/// Value of this gain in SI base units of the output per SI base unit of the input
+	pub fn as_si(&self) -> Float {
+		self.si
+	}
+
+	/// Output for `input`
+	pub fn apply(&self, input: P) -> O {
+		O::from_si(self.si*input.as_si())
+	}
+}
+
+impl<P, O> std::ops::Mul<Float> for Gain<P, O> {
+	type Output = Gain<P, O>;
+	fn mul(self, factor: Float) -> Gain<P, O> {
+		Gain { si: self.si*factor, quantities: PhantomData }
+	}
+}
+
+/// Tuning of a [Pid] controller in the standard ISA form, u = K<sub>p</sub>(e + ∫e dt/T<sub>i</sub> + T<sub>d</sub> de/dt)
+#[derive(Clone, Copy, Debug)]
+pub struct PidGains<P, O> {
+	/// Output per unit of error
+	pub proportional: Gain<P, O>,
+	/// Time over which the integral action repeats the proportional action for a constant error, or `None` for no integral action
+	pub integral_time: Option<Time>,
+	/// Time by which the derivative action anticipates the error, zero for no derivative action
+	pub derivative_time: Time,
+}
+
+impl<P: AnyQuantity, O: AnyQuantity> PidGains<P, O> {
+	/// Proportional-only tuning
+	pub fn proportional(gain: Gain<P, O>) -> PidGains<P, O> {
+		PidGains { proportional: gain, integral_time: None, derivative_time: Time::from_si(0.0) }
+	}
+
+	/// Proportional-integral tuning
+	pub fn pi(gain: Gain<P, O>, integral_time: Time) -> PidGains<P, O> {
+		PidGains { proportional: gain, integral_time: Some(integral_time), derivative_time: Time::from_si(0.0) }
+	}
+
+	/// Full PID tuning
+	pub fn pid(gain: Gain<P, O>, integral_time: Time, derivative_time: Time) -> PidGains<P, O> {
+		PidGains { proportional: gain, integral_time: Some(integral_time), derivative_time }
+	}
+}
+
+/// PID controller driving an output of type `O` to hold a process variable of type `P` at a setpoint
+#[derive(Clone, Copy, Debug)]
+pub struct Pid<P, O> {
+	gains: PidGains<P, O>,
+	limits: Option<(O, O)>,
+	/// Integral of the error divided by the integral time, in SI base units
+	integral: Float,
+	previous: Option<P>,
+}
+
+impl<P: AnyQuantity, O: AnyQuantity> Pid<P, O> {
+	pub fn new(gains: PidGains<P, O>) -> Pid<P, O> {
+		Pid { gains, limits: None, integral: 0.0, previous: None }
+	}
+
+	/// Clamp the output between `low` and `high`, e.g. the range of an actuator.  The integral stops accumulating while the output is
+	/// saturated, so it does not wind up
+	pub fn limits(self, low: O, high: O) -> Pid<P, O> {
+		Pid { limits: Some((low, high)), ..self }
+	}
+
+	pub fn gains(&self) -> PidGains<P, O> {
+		self.gains
+	}
+
+	/// Change the tuning without disturbing the output, e.g. for gain scheduling
+	pub fn set_gains(&mut self, gains: PidGains<P, O>) {
+		// The integral is held divided by the integral time, so only the proportional gain scales its contribution
+		let (old, new) = (self.gains.proportional.as_si(), gains.proportional.as_si());
+		if new != 0.0 {
+			self.integral *= old/new;
+		}
+		self.gains = gains;
+	}
+
+	/// Clear the integral and derivative history, e.g. when switching from manual to automatic control
+	pub fn reset(&mut self) {
+		self.integral = 0.0;
+		self.previous = None;
+	}
+
+	/// Output for `measurement` of the process variable with target `setpoint`, `dt` after the previous update.  The derivative acts
+	/// on the measurement rather than the error, so setpoint changes do not kick the output
+	pub fn update(&mut self, setpoint: P, measurement: P, dt: Time) -> O {
+		let error = (setpoint - measurement).as_si();
+		let dt = dt.as_si();
+		let kp = self.gains.proportional.as_si();
+		let derivative = match self.previous {
+			Some(previous) if dt > 0.0 => -(measurement - previous).as_si()/dt,
+			_ => 0.0,
+		};
+		self.previous = Some(measurement);
+		let integral = match self.gains.integral_time {
+			Some(ti) => self.integral + error*dt/ti.as_si(),
+			None => 0.0,
+		};
+		let output = kp*(error + integral + self.gains.derivative_time.as_si()*derivative);
+		let Some((low, high)) = self.limits else {
+			self.integral = integral;
+			return O::from_si(output);
+		};
+		// Integrate only while unsaturated, or while the error drives the output back out of saturation
+		if (output < high.as_si() || error*kp < 0.0) && (output > low.as_si() || error*kp > 0.0) {
+			self.integral = integral;
+		}
+		O::from_si(output.clamp(low.as_si(), high.as_si()))
+	}
+}
+
+/// Figures of merit of a step response, from [StepMetrics::new]
+#[derive(Clone, Copy, Debug)]
+pub struct StepMetrics<P> {
+	/// Time to rise from 10% to 90% of the way to the final value
+	pub rise_time: Time,
+	/// Peak excursion beyond the final value as a fraction of the step, zero if the response never passed it
+	pub overshoot: Unitless,
+	/// Time after which the response stays within the settling band of the final value
+	pub settling_time: Time,
+	/// Setpoint minus the final value
+	pub steady_state_error: P,
+}
+
+impl<P: AnyQuantity> StepMetrics<P> {
+	/// Metrics of the response `samples` of (time since the step, process variable) to a step in setpoint to `setpoint`, taking the
+	/// first sample as the starting value and the last as the final value.  Settling is judged by the usual 2% band
+	pub fn new(samples: &[(Time, P)], setpoint: P) -> StepMetrics<P> {
+		StepMetrics::with_band(samples, setpoint, Unitless::from(0.02))
+	}
+
+	/// Metrics as [StepMetrics::new] with a settling band of `band` of the step either side of the final value, e.g. 5%
+	pub fn with_band(samples: &[(Time, P)], setpoint: P, band: Unitless) -> StepMetrics<P> {
+		assert!(!samples.is_empty(), "step response needs at least one sample");
+		let (start, end) = (samples[0].1.as_si(), samples[samples.len() - 1].1.as_si());
+		let step = end - start;
+		// Progress from the starting value towards the final value, 1 at the final value
+		let progress = |value: P| if step == 0.0 { 1.0 } else { (value.as_si() - start)/step };
+		// The last sample is at the final value, so every level up to 1 is crossed
+		let crossing = |level: Float| samples.iter().find(|(_, value)| progress(*value) >= level).map_or(samples[0].0, |(t, _)| *t);
+		let rise_time = crossing(0.9) - crossing(0.1);
+		let peak = samples.iter().map(|(_, value)| progress(*value)).fold(1.0, Float::max);
+		let band: Float = band.into();
+		let settling_time = samples.iter().rev()
+			.find(|(_, value)| (progress(*value) - 1.0).abs() > band)
+			.map_or(samples[0].0, |(t, _)| *t);
+		StepMetrics {
+			rise_time,
+			overshoot: Unitless::from(peak - 1.0),
+			settling_time: settling_time - samples[0].0,
+			steady_state_error: P::from_si(setpoint.as_si() - end),
+		}
+	}
+}
+
+/// Gain at which a process under proportional control oscillates steadily, and the period of the oscillation
+#[derive(Clone, Copy, Debug)]
+pub struct UltimatePoint<P, O> {
+	pub gain: Gain<P, O>,
+	pub period: Time,
+}
+
+/// Tuning rules of the Ziegler–Nichols family, from the ultimate point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TuningRule {
+	/// Classic Ziegler–Nichols proportional-only tuning
+	P,
+	/// Classic Ziegler–Nichols PI tuning
+	PI,
+	/// Classic Ziegler–Nichols PID tuning, aggressive with a quarter-amplitude decay
+	PID,
+	/// PID tuning giving some overshoot but less than [TuningRule::PID]
+	SomeOvershoot,
+	/// PID tuning giving little or no overshoot
+	NoOvershoot,
+}
+
+impl<P: AnyQuantity, O: AnyQuantity> UltimatePoint<P, O> {
+	/// Initial gains by `rule`, to be refined on the running process
+	pub fn ziegler_nichols(&self, rule: TuningRule) -> PidGains<P, O> {
+		let (ku, tu) = (self.gain, self.period);
+		match rule {
+			TuningRule::P => PidGains::proportional(ku*0.5),
+			TuningRule::PI => PidGains::pi(ku*0.45, tu/1.2),
+			TuningRule::PID => PidGains::pid(ku*0.6, tu/2.0, tu/8.0),
+			TuningRule::SomeOvershoot => PidGains::pid(ku*(1.0/3.0), tu/2.0, tu/3.0),
+			TuningRule::NoOvershoot => PidGains::pid(ku*0.2, tu/2.0, tu/3.0),
+		}
+	}
+}
+
+/// Relay feedback autotuner (Åström–Hägglund).  In place of the controller, it switches the output between `bias ± amplitude` as the
+/// process variable crosses the setpoint, driving the process into a limit cycle whose amplitude and period give the ultimate point.
+/// This stays in control of the process throughout, unlike raising a proportional gain until it oscillates
+#[derive(Clone, Debug)]
+pub struct RelayTuner<P, O> {
+	bias: O,
+	amplitude: O,
+	hysteresis: P,
+	cycles: usize,
+	high: bool,
+	time: Time,
+	/// Times at which the output switched high
+	switches: Vec<Time>,
+	/// Extremes of the process variable over the current and previous cycles
+	extremes: Vec<(Float, Float)>,
+}
+
+impl<P: AnyQuantity, O: AnyQuantity> RelayTuner<P, O> {
+	/// Tuner switching the output `amplitude` either side of `bias`, e.g. the output which roughly holds the setpoint.  Measures 3
+	/// cycles after the first, as the first is distorted by the approach to the setpoint
+	pub fn new(bias: O, amplitude: O) -> RelayTuner<P, O> {
+		RelayTuner { bias, amplitude, hysteresis: P::from_si(0.0), cycles: 3, high: true, time: Time::from_si(0.0), switches: Vec::new(), extremes: Vec::new() }
+	}
+
+	/// Switch only once the process variable is `hysteresis` past the setpoint, so noise does not chatter the relay.  Should exceed the
+	/// noise band of the measurement
+	pub fn hysteresis(self, hysteresis: P) -> RelayTuner<P, O> {
+		RelayTuner { hysteresis, ..self }
+	}
+
+	/// Number of cycles to average over after the first
+	pub fn cycles(self, cycles: usize) -> RelayTuner<P, O> {
+		RelayTuner { cycles: cycles.max(1), ..self }
+	}
+
+	/// Output for `measurement` with target `setpoint`, `dt` after the previous update
+	pub fn update(&mut self, setpoint: P, measurement: P, dt: Time) -> O {
+		self.time += dt;
+		let (error, h, m) = ((setpoint - measurement).as_si(), self.hysteresis.as_si(), measurement.as_si());
+		if let Some((low, high)) = self.extremes.last_mut() {
+			*low = low.min(m);
+			*high = high.max(m);
+		}
+		if self.high && error < -h {
+			self.high = false;
+		} else if !self.high && error > h {
+			self.high = true;
+			self.switches.push(self.time);
+			self.extremes.push((m, m));
+		}
+		if self.high { self.bias + self.amplitude } else { self.bias - self.amplitude }
+	}
+
+	/// Whether enough cycles have been measured for [RelayTuner::result]
+	pub fn is_done(&self) -> bool {
+		self.switches.len() > self.cycles + 1
+	}
+
+	/// Ultimate point from the measured cycles, once [RelayTuner::is_done].  The ultimate gain is 4d/πa for relay amplitude d and
+	/// oscillation amplitude a, corrected for hysteresis ε to 4d/π√(a² - ε²)
+	pub fn result(&self) -> Option<UltimatePoint<P, O>> {
+		if !self.is_done() {
+			return None;
+		}
+		// Cycles are complete between consecutive switches, skipping the first
+		let n = self.cycles;
+		let last = self.switches.len() - 1;
+		let period = (self.switches[last] - self.switches[last - n])/(n as Float);
+		let amplitude = self.extremes[last - n..last].iter().map(|(low, high)| (high - low)/2.0).sum::<Float>()/(n as Float);
+		let h = self.hysteresis.as_si();
+		let gain = 4.0*self.amplitude.as_si()/(PI*(amplitude*amplitude - h*h).max(0.0).sqrt());
+		Some(UltimatePoint { gain: Gain::from_si(gain), period })
+	}
+}
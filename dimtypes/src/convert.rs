@@ -0,0 +1,142 @@
+//! Cached conversion of many quantities to preferred display units
+//!
+//! Output code such as report generators and UI render loops shows quantities of many dimensions, each in a unit chosen once
+//! (e.g. speeds in km/h, temperatures in °C, levels in dB).  A [Converter] resolves those units up front, from unit strings via
+//! [parse_unit] or from typed [Unit]s including offset, logarithmic and reciprocal ones, and keys them by [Dimension].  Converting a
+//! quantity is then a short scan and an affine map (or a call to the nonlinear unit) with no string handling.  Quantities of a
+//! dimension without a preferred unit are left in SI base units.
+
+use std::borrow::Cow;
+use std::fmt;
+use crate::{AnyQuantity,Dimension,Float,Unit,UnitSymbol};
+use crate::parse::{parse_unit,UnitParseError};
+
+enum Mapping {
+	/// `value = (si - offset)/scale`, covering every unit a string can describe
+	Affine { scale: Float, offset: Float },
+	/// Any other [Unit], such as a [LogUnit][crate::LogUnit] or [ReciprocalUnit][crate::ReciprocalUnit]
+	Nonlinear(Box<dyn Fn(Float) -> Float + Send + Sync>),
+}
+
+struct Target {
+	dimension: Dimension,
+	symbol: String,
+	mapping: Mapping,
+}
+
+impl Target {
+	fn value(&self, si: Float) -> Float {
+		match &self.mapping {
+			Mapping::Affine { scale, offset } => (si - offset)/scale,
+			Mapping::Nonlinear(f) => f(si),
+		}
+	}
+}
+
+/// Set of preferred display units, at most one per [Dimension]
+#[derive(Default)]
+pub struct Converter {
+	targets: Vec<Target>,
+}
+
+impl Converter {
+	pub fn new() -> Converter {
+		Converter::default()
+	}
+
+	/// Display quantities of the dimension of `units` (e.g. `"km/h"` or `"degC"`) in that unit, replacing any earlier choice
+	pub fn with(self, units: &str) -> Result<Converter, UnitParseError> {
+		let unit = parse_unit(units)?;
+		Ok(self.insert(Target {
+			dimension: unit.dimension(),
+			symbol: units.to_string(),
+			mapping: Mapping::Affine { scale: unit.scale(), offset: unit.offset() },
+		}))
+	}
+
+	/// Display quantities of the dimension of `unit` in that unit, labelled `symbol`, replacing any earlier choice.  Accepts any
+	/// [Unit] of a quantity, e.g. <code>with_unit("°F", [FAHRENHEIT][crate::units::FAHRENHEIT])</code> or a decibel [LogUnit][crate::LogUnit]
+	pub fn with_unit<U>(self, symbol: &str, unit: U) -> Converter where
+		U: Unit + Send + Sync + 'static,
+		U::Dimen: AnyQuantity,
+	{
+		self.insert(Target {
+			dimension: U::Dimen::DIMENSION,
+			symbol: symbol.to_string(),
+			mapping: Mapping::Nonlinear(Box::new(move |si| unit.qty_to_val(U::Dimen::from_si(si)))),
+		})
+	}
+
+	/// Display quantities of the dimension of `unit` in that unit, labelled with its own symbol, e.g. <code>with_named([KNOT][crate::units::KNOT])</code>
+	/// or a unit declared with [define_unit!][crate::define_unit]
+	pub fn with_named<U>(self, unit: U) -> Converter where
+		U: UnitSymbol + Send + Sync + 'static,
+		U::Dimen: AnyQuantity,
+	{
+		let symbol = unit.symbol().to_string();
+		self.with_unit(&symbol, unit)
+	}
+
+	fn insert(mut self, target: Target) -> Converter {
+		match self.targets.iter_mut().find(|t| t.dimension == target.dimension) {
+			Some(existing) => *existing = target,
+			None => self.targets.push(target),
+		}
+		self
+	}
+
+	/// Preferred unit symbol for quantities of `dimension`, if any
+	pub fn units_for(&self, dimension: Dimension) -> Option<&str> {
+		self.target(dimension).map(|t| t.symbol.as_str())
+	}
+
+	fn target(&self, dimension: Dimension) -> Option<&Target> {
+		self.targets.iter().find(|t| t.dimension == dimension)
+	}
+
+	/// Numerical value of `qty` in its preferred unit, or [None] if there is none for its dimension
+	pub fn value<Q: AnyQuantity>(&self, qty: Q) -> Option<Float> {
+		self.target(Q::DIMENSION).map(|t| t.value(qty.as_si()))
+	}
+
+	/// Convert `qty` to its preferred unit, falling back to SI base units
+	pub fn convert<Q: AnyQuantity>(&self, qty: Q) -> Converted<'_> {
+		self.convert_si(qty.as_si(), Q::DIMENSION)
+	}
+
+	/// Convert a value in SI base units of `dimension` to its preferred unit, falling back to SI base units.  For values whose
+	/// type is only known at runtime
+	pub fn convert_si(&self, value: Float, dimension: Dimension) -> Converted<'_> {
+		match self.target(dimension) {
+			Some(t) => Converted { value: t.value(value), units: Cow::Borrowed(&t.symbol) },
+			None => Converted { value, units: Cow::Owned(dimension.to_string()) },
+		}
+	}
+}
+
+impl fmt::Debug for Converter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_map().entries(self.targets.iter().map(|t| (t.dimension, &t.symbol))).finish()
+	}
+}
+
+/// A value converted by a [Converter] along with the symbol of its unit
+#[derive(Clone, Debug, PartialEq)]
+pub struct Converted<'a> {
+	pub value: Float,
+	pub units: Cow<'a, str>,
+}
+
+/// Formats as `value units`, passing any precision through to the value (e.g. `{:.1}` gives `88.5 km/h`)
+impl fmt::Display for Converted<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match f.precision() {
+			Some(precision) => write!(f, "{:.*}", precision, self.value)?,
+			None => write!(f, "{}", self.value)?,
+		}
+		if !self.units.is_empty() {
+			write!(f, " {}", self.units)?;
+		}
+		Ok(())
+	}
+}
@@ -1,83 +1,135 @@
-use std::fmt;
-use std::ops::{Add,Sub,Mul,Div,Neg};
+use core::fmt;
+use core::ops::{Add,Sub,Mul,Div,Neg,AddAssign,SubAssign,MulAssign,DivAssign,Deref};
+#[cfg(feature = "std")]
+use crate::parse::{split_value,UnitParseError};
+#[cfg(not(feature = "std"))]
+use crate::real::Real;
+use crate::Float;
 use crate::dimens::Unitless;
 
 /**
-A [Quantity] represents a physical quantity with the power of each physical dimension encoded in the five [`isize`] const generics. Since this is generally clumsy to work with, the [dimens][crate::dimens] module provides type definitions for most quantities
-of interest. For example [`Length`][crate::dimens::Length] aliases `Quantity<0,1,0,0,0>`, [`Force`][crate::dimens::Force] aliases `Quantity<-2,1,1,0,0>`, etc.
+A [Quantity] represents a physical quantity with the power of each physical dimension encoded in the nine [`isize`] const generics. Since this is generally clumsy to work with, the [dimens][crate::dimens] module provides type definitions for most quantities
+of interest. For example [`Length`][crate::dimens::Length] aliases `Quantity<0,1,0,0,0,0,0,0,0>`, [`Force`][crate::dimens::Force] aliases `Quantity<-2,1,1,0,0,0,0,0,0>`, etc.
 
-Internally, Quantity wraps a single [f64] value representing the physical quantity in SI base units. This ensures math between instances of Quantity always follows a consistent unit system.
+Powers may be rational: the tenth const generic `D` (defaulting to 1) is a common denominator of all nine, so `Quantity<-1,0,0,0,0,0,0,0,0,2>` is
+s<sup>-1/2</sup> and a noise density in V/√Hz type-checks.  Results of arithmetic are always reduced to lowest terms with a positive denominator,
+so each dimension has exactly one type.
+
+Internally, Quantity wraps a single [Float] value ([f64] unless the `f32` feature is enabled) representing the physical quantity in SI base units. This ensures math between instances of Quantity always follows a consistent unit system.
 */
 #[derive(Clone, Copy)]
-pub struct Quantity<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize> {
-	value_si: f64
+pub struct Quantity<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize = 1> {
+	value_si: Float
 }
 
-/// Helper function to 
-pub const fn div_evenly(num: isize, den: isize) -> isize {
-	if num % den != 0 {
-		panic!("Result would have non-integer power of dimension");
+const fn gcd(a: isize, b: isize) -> isize {
+	let (mut a, mut b) = (a.abs(), b.abs());
+	while b != 0 {
+		(a, b) = (b, a % b);
 	}
-	num/den
+	a
 }
 
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-Quantity<T,L,M,I,TEMP> {
+/// Reduce the dimension powers `nums/den` to lowest terms with a positive denominator, returning numerator `k` (or the denominator
+/// for `k = 9`).  Keeps the const generics of [Quantity] canonical so equal dimensions have the same type
+const fn reduced(nums: [isize; 9], den: isize, k: usize) -> isize {
+	if den == 0 {
+		panic!("Dimension power has a zero denominator");
+	}
+	let mut g = den;
+	let mut i = 0;
+	while i < 9 {
+		g = gcd(g, nums[i]);
+		i += 1;
+	}
+	let g = if den < 0 { -g } else { g };
+	if k < 9 { nums[k]/g } else { den/g }
+}
+
+/// Helper function giving const generic `k` of [Product] (with `sign = 1`) or [Quotient] (with `sign = -1`)
+#[allow(clippy::too_many_arguments)]
+pub const fn combined_power(k: usize, sign: isize,
+	t1: isize, l1: isize, m1: isize, i1: isize, temp1: isize, mol1: isize, lum1: isize, ang1: isize, cnt1: isize, d1: isize,
+	t2: isize, l2: isize, m2: isize, i2: isize, temp2: isize, mol2: isize, lum2: isize, ang2: isize, cnt2: isize, d2: isize) -> isize {
+	let d2 = sign*d2;
+	reduced([t1*d2 + t2*d1, l1*d2 + l2*d1, m1*d2 + m2*d1, i1*d2 + i2*d1, temp1*d2 + temp2*d1, mol1*d2 + mol2*d1, lum1*d2 + lum2*d1, ang1*d2 + ang2*d1,
+		cnt1*d2 + cnt2*d1], d1*d2, k)
+}
+
+/// Helper function giving const generic `k` of [Power]
+#[allow(clippy::too_many_arguments)]
+pub const fn raised_power(k: usize, p: isize, r: isize,
+	t: isize, l: isize, m: isize, i: isize, temp: isize, mol: isize, lum: isize, ang: isize, cnt: isize, d: isize) -> isize {
+	reduced([p*t, p*l, p*m, p*i, p*temp, p*mol, p*lum, p*ang, p*cnt], r*d, k)
+}
+
+/// Type of the product of `Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>` and `Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>`, for
+/// naming the result of a multiplication in generic code
+pub type Product<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize> =
+	Quantity<{combined_power(0,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(1,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(2,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(3,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(4,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(5,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(6,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(7,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(8,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(9,1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)}>;
+/// Type of the quotient of `Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>` by `Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>`, for
+/// naming the result of a division in generic code
+pub type Quotient<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize> =
+	Quantity<{combined_power(0,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(1,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(2,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(3,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(4,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(5,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(6,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(7,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(8,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)},{combined_power(9,-1,T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2)}>;
+/// Type of `Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>` raised to the power `P/R`, for naming the result of [Quantity::pow] (`R = 1`) or
+/// [Quantity::root] (`P = 1`) in generic code
+pub type Power<const P: isize, const R: isize, const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> =
+	Quantity<{raised_power(0,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(1,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(2,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(3,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(4,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(5,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(6,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(7,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(8,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)},{raised_power(9,P,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D)}>;
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	/// Get the numerical value of this quantity in the given `unit`.  `unit` must implement [Unit] with [Unit::Dimen] matching this quantity.
-	pub fn as_unit(self, unit: impl Unit<Dimen=Self>) -> f64 {
+	pub fn as_unit(self, unit: impl Unit<Dimen=Self>) -> Float {
 		unit.qty_to_val(self)
 	}
 
-	/// Get the numerical value of this quantity in SI base units (s<sup>T</sup>m<sup>L</sup>kg<sup>M</sup>A<sup>I</sup>K<sup>TEMP</sup>)
-	pub const fn as_si(self) -> f64 {
+	/// Get the numerical value of this quantity in SI base units (s<sup>T</sup>m<sup>L</sup>kg<sup>M</sup>A<sup>I</sup>K<sup>TEMP</sup>mol<sup>MOL</sup>cd<sup>LUM</sup>rad<sup>ANG</sup>count<sup>CNT</sup>)
+	pub const fn as_si(self) -> Float {
 		self.value_si
 	}
 
-	/// Create a [Quantity] from a numerical value in the appropriate combination of SI base units (s<sup>T</sup>m<sup>L</sup>kg<sup>M</sup>A<sup>I</sup>K<sup>TEMP</sup>)  
-	/// For [Unitless] quantities also consider using the [`From<f64>`] implementation (e.g. `Unitless::from(1.5)`)
-	pub const fn from_si(val: f64) -> Self {
+	/// Create a [Quantity] from a numerical value in the appropriate combination of SI base units (s<sup>T</sup>m<sup>L</sup>kg<sup>M</sup>A<sup>I</sup>K<sup>TEMP</sup>mol<sup>MOL</sup>cd<sup>LUM</sup>rad<sup>ANG</sup>count<sup>CNT</sup>)  
+	/// For [Unitless] quantities also consider using the [`From<Float>`] implementation (e.g. `Unitless::from(1.5)`)
+	pub const fn from_si(val: Float) -> Self {
 		Quantity { value_si:val }
 	}
 
 
+	/// The runtime [Dimension] of this quantity type
+	pub const DIMENSION: Dimension = Dimension::new(T,L,M,I,TEMP,MOL,LUM,ANG,CNT).over(D);
+	/// The runtime [Dimension] of this quantity, for use where the type is not named explicitly
+	pub const fn dimension(&self) -> Dimension { Self::DIMENSION }
+
 	/// Raise `self` to an integer power `P`.  Implemented as generic function since the dimenson (and thus type) of the result is dependent on the power
-	pub fn pow<const P:isize>(self) ->
-		Quantity<{P*T},{P*L},{P*M},{P*I},{P*TEMP}>
-	{ 
+	pub fn pow<const P:isize>(self) -> Power<P,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> where
+		Power<P,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+	{
 			Quantity{value_si:self.value_si.powi(P as i32)}
 	}
 
 	/// Take the `R`th root of `self`.  Implemented as generic function since the dimenson (and thus type) of the result is dependent on the power.  
-	/// `root::<R>` can only be called on types where all dimension powers are integer multiples of `R`.
-	pub fn root<const R:isize>(self) ->
-		Quantity<{div_evenly(T,R)},{div_evenly(L,R)},{div_evenly(M,R)},{div_evenly(I,R)},{div_evenly(TEMP,R)}>
+	/// Powers of the result which do not divide evenly by `R` become fractions, e.g. `(1.0*HERTZ).root::<2>()` is s<sup>-1/2</sup>.
+	pub fn root<const R:isize>(self) -> Power<1,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> where
+		Power<1,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
 	{
-		Quantity{value_si:self.value_si.powf(1.0/(R as f64)) }
+		Quantity{value_si:self.value_si.powf(1.0/(R as Float)) }
 	}
 }
 
 
-/// [Unitless] quantities can coerce directly to/from [f64]
-impl const From<f64> for Unitless {
-	fn from(value: f64) -> Self { Quantity {value_si:value} }
+/// [Unitless] quantities can coerce directly to/from [Float]
+impl const From<Float> for Unitless {
+	fn from(value: Float) -> Self { Quantity {value_si:value} }
 }
-/// [Unitless] quantities can coerce directly to/from [f64]
-impl const From<Unitless> for f64 {
+/// [Unitless] quantities can coerce directly to/from [Float]
+impl const From<Unitless> for Float {
 	fn from(value: Unitless) -> Self { value.value_si }
 }
 
 
 
-macro_rules! write_unit_power {
-	($fmt:expr, $power:expr, $symbol:literal) => {
-		if $power != 0 {
-			write!($fmt, concat!(" ",$symbol))?;
-			if $power != 1 {
-				write!($fmt, "^{}", $power)?;
-			}
-		}
-	}
-}
 macro_rules! fmt_impl_with_suffix {
 	($suffix:literal) => {
 		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -86,85 +138,315 @@ macro_rules! fmt_impl_with_suffix {
 			} else {
 				write!(f, concat!("{:",$suffix,"}"),  self.value_si)?;
 			}
-			write_unit_power!(f,M,"kg");
-			write_unit_power!(f,L,"m");
-			write_unit_power!(f,T,"s");
-			write_unit_power!(f,I,"A");
-			write_unit_power!(f,TEMP,"K");
+			if !Self::DIMENSION.is_dimensionless() {
+				if f.alternate() {
+					write!(f, " {:#}", Self::DIMENSION)?;
+				} else {
+					write!(f, " {}", Self::DIMENSION)?;
+				}
+			}
 			Ok(())
 		}
 	}
 }
 
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-fmt::Display for Quantity<T,L,M,I,TEMP> {
+/// Formats as the value in SI base units followed by the units, e.g. `1500 kg m^2 s^-2`.  The alternate form (`{:#}`) names the units by
+/// their SI derived unit where there is one, e.g. `1500 J`, falling back to base units otherwise
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Display for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	fmt_impl_with_suffix!("");
 }
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-fmt::LowerExp for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::LowerExp for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	fmt_impl_with_suffix!("e");
 }
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-fmt::UpperExp for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::UpperExp for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	fmt_impl_with_suffix!("E");
 }
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-fmt::Debug for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Debug for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Display::fmt(self, f) }
 }
 
 
 
+/// Runtime representation of the dimension of a [Quantity], i.e. the power of each SI base dimension.  Every [Quantity] type exposes its
+/// dimension as [Quantity::DIMENSION].  Multiplying and dividing dimensions adds and subtracts the exponents, mirroring the type-level
+/// arithmetic on [Quantity].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Dimension {
+	pub time: isize,
+	pub length: isize,
+	pub mass: isize,
+	pub current: isize,
+	pub temperature: isize,
+	pub amount: isize,
+	pub luminous_intensity: isize,
+	pub angle: isize,
+	/// Number of items or events counted, see [Count][crate::dimens::Count]
+	pub count: isize,
+	/// Common denominator of the powers above, e.g. the power of time is `time/denominator`.  Positive and in lowest terms, so it is 1
+	/// for every dimension with integer powers
+	pub denominator: isize,
+}
+
+impl Dimension {
+	/// The dimension of [Unitless] quantities
+	pub const NONE: Dimension = Dimension::new(0,0,0,0,0,0,0,0,0);
+
+	/// Construct a dimension from the powers of each base dimension, in the same order as the [Quantity] const generics
+	#[allow(clippy::too_many_arguments)]
+	pub const fn new(time: isize, length: isize, mass: isize, current: isize, temperature: isize, amount: isize, luminous_intensity: isize, angle: isize, count: isize) -> Dimension {
+		Dimension { time, length, mass, current, temperature, amount, luminous_intensity, angle, count, denominator: 1 }
+	}
+	/// This dimension with every power divided by `denominator`, e.g. `Dimension::new(-1,0,0,0,0,0,0,0,0).over(2)` is s<sup>-1/2</sup>
+	pub const fn over(self, denominator: isize) -> Dimension {
+		Dimension::reduced(self.numerators(), self.denominator*denominator)
+	}
+	/// [Dimension::over], or `None` if `denominator` is zero or the powers overflow
+	pub const fn checked_over(self, denominator: isize) -> Option<Dimension> {
+		match self.denominator.checked_mul(denominator) {
+			Some(den) => Dimension::checked_reduced(self.numerators(), den),
+			None => None,
+		}
+	}
+	const fn numerators(&self) -> [isize; 9] {
+		[self.time, self.length, self.mass, self.current, self.temperature, self.amount, self.luminous_intensity, self.angle, self.count]
+	}
+	/// [Dimension::reduced], or `None` if `den` is zero or a power is too large to reduce
+	const fn checked_reduced(n: [isize; 9], den: isize) -> Option<Dimension> {
+		// `isize::MIN` has no absolute value to take the gcd of
+		let mut i = 0;
+		while i < n.len() {
+			if n[i] == isize::MIN {
+				return None;
+			}
+			i += 1;
+		}
+		if den == 0 || den == isize::MIN { None } else { Some(Dimension::reduced(n, den)) }
+	}
+	const fn reduced(n: [isize; 9], den: isize) -> Dimension {
+		Dimension {
+			time: reduced(n, den, 0), length: reduced(n, den, 1), mass: reduced(n, den, 2), current: reduced(n, den, 3),
+			temperature: reduced(n, den, 4), amount: reduced(n, den, 5), luminous_intensity: reduced(n, den, 6), angle: reduced(n, den, 7),
+			count: reduced(n, den, 8), denominator: reduced(n, den, 9),
+		}
+	}
+	/// Same as `==`, for use in const context
+	pub const fn equals(&self, other: &Dimension) -> bool {
+		let (a, b) = (self.numerators(), other.numerators());
+		let mut i = 0;
+		while i < a.len() {
+			if a[i] != b[i] {
+				return false;
+			}
+			i += 1;
+		}
+		self.denominator == other.denominator
+	}
+	pub const fn is_dimensionless(&self) -> bool {
+		self.time == 0 && self.length == 0 && self.mass == 0 && self.current == 0 && self.temperature == 0 && self.amount == 0 && self.luminous_intensity == 0 && self.angle == 0
+			&& self.count == 0
+	}
+	/// Dimension of a quantity of this dimension raised to the power `p`
+	pub const fn powi(self, p: isize) -> Dimension {
+		let n = self.numerators();
+		Dimension::reduced([p*n[0], p*n[1], p*n[2], p*n[3], p*n[4], p*n[5], p*n[6], p*n[7], p*n[8]], self.denominator)
+	}
+	/// [Dimension::powi], or `None` if the powers overflow
+	pub const fn checked_powi(self, p: isize) -> Option<Dimension> {
+		let mut n = self.numerators();
+		let mut i = 0;
+		while i < n.len() {
+			n[i] = match n[i].checked_mul(p) {
+				Some(power) => power,
+				None => return None,
+			};
+			i += 1;
+		}
+		Dimension::checked_reduced(n, self.denominator)
+	}
+	/// Dimension of the `r`th root of a quantity of this dimension
+	pub const fn root(self, r: isize) -> Dimension {
+		self.over(r)
+	}
+	/// [Dimension::root], or `None` if `r` is zero or the powers overflow
+	pub const fn checked_root(self, r: isize) -> Option<Dimension> {
+		self.checked_over(r)
+	}
+	/// Power of each base dimension as a [Float], in the same order as the [Quantity] const generics
+	pub fn powers(&self) -> [Float; 9] {
+		self.numerators().map(|n| n as Float/self.denominator as Float)
+	}
+	/// Symbol of the named SI derived unit of this dimension, e.g. `"J"` for kg m<sup>2</sup> s<sup>-2</sup>, if it has one.  Where
+	/// several units share a dimension the most general is chosen, e.g. `"Hz"` rather than `"Bq"`
+	pub fn derived_symbol(&self) -> Option<&'static str> {
+		DERIVED_SYMBOLS.iter().find(|(dimension, _)| dimension == self).map(|(_, symbol)| *symbol)
+	}
+	/// `self*rhs`, or `None` if the powers overflow
+	pub const fn checked_mul(self, rhs: Dimension) -> Option<Dimension> {
+		self.checked_combine(rhs, 1)
+	}
+	/// `self/rhs`, or `None` if the powers overflow
+	pub const fn checked_div(self, rhs: Dimension) -> Option<Dimension> {
+		self.checked_combine(rhs, -1)
+	}
+	const fn checked_combine(self, rhs: Dimension, sign: isize) -> Option<Dimension> {
+		let (a, b) = (self.numerators(), rhs.numerators());
+		let (da, db) = (self.denominator, sign*rhs.denominator);
+		let mut n = [0; 9];
+		let mut i = 0;
+		while i < n.len() {
+			n[i] = match (a[i].checked_mul(db), b[i].checked_mul(da)) {
+				(Some(x), Some(y)) => match x.checked_add(y) {
+					Some(power) => power,
+					None => return None,
+				},
+				_ => return None,
+			};
+			i += 1;
+		}
+		match da.checked_mul(db) {
+			Some(den) => Dimension::checked_reduced(n, den),
+			None => None,
+		}
+	}
+	const fn combine(self, rhs: Dimension, sign: isize) -> Dimension {
+		let (a, b) = (self.numerators(), rhs.numerators());
+		let (da, db) = (self.denominator, sign*rhs.denominator);
+		Dimension::reduced([a[0]*db + b[0]*da, a[1]*db + b[1]*da, a[2]*db + b[2]*da, a[3]*db + b[3]*da,
+			a[4]*db + b[4]*da, a[5]*db + b[5]*da, a[6]*db + b[6]*da, a[7]*db + b[7]*da, a[8]*db + b[8]*da], da*db)
+	}
+}
+
+impl Default for Dimension {
+	fn default() -> Dimension { Dimension::NONE }
+}
+
+impl const Mul for Dimension {
+	type Output = Dimension;
+	fn mul(self, rhs: Dimension) -> Dimension {
+		self.combine(rhs, 1)
+	}
+}
+impl const Div for Dimension {
+	type Output = Dimension;
+	fn div(self, rhs: Dimension) -> Dimension {
+		self.combine(rhs, -1)
+	}
+}
+
+/// Named SI derived units, by dimension in the order of the [Dimension::new] arguments
+const DERIVED_SYMBOLS: [(Dimension, &str); 16] = [
+	(Dimension::new(-1,0,0,0,0,0,0,0,0), "Hz"),
+	(Dimension::new(-2,1,1,0,0,0,0,0,0), "N"),
+	(Dimension::new(-2,-1,1,0,0,0,0,0,0), "Pa"),
+	(Dimension::new(-2,2,1,0,0,0,0,0,0), "J"),
+	(Dimension::new(-3,2,1,0,0,0,0,0,0), "W"),
+	(Dimension::new(1,0,0,1,0,0,0,0,0), "C"),
+	(Dimension::new(-3,2,1,-1,0,0,0,0,0), "V"),
+	(Dimension::new(4,-2,-1,2,0,0,0,0,0), "F"),
+	(Dimension::new(-3,2,1,-2,0,0,0,0,0), "Ω"),
+	(Dimension::new(3,-2,-1,2,0,0,0,0,0), "S"),
+	(Dimension::new(-2,2,1,-1,0,0,0,0,0), "Wb"),
+	(Dimension::new(-2,0,1,-1,0,0,0,0,0), "T"),
+	(Dimension::new(-2,2,1,-2,0,0,0,0,0), "H"),
+	(Dimension::new(0,0,0,0,0,0,1,2,0), "lm"),
+	(Dimension::new(0,-2,0,0,0,0,1,2,0), "lx"),
+	(Dimension::new(-1,0,0,0,0,1,0,0,0), "kat"),
+];
+
+/// Formats as the product of SI base units, e.g. `kg m^2 s^-2`, with fractional powers in parentheses as in `s^(-1/2)`.  Dimensionless
+/// formats as an empty string.  The alternate form (`{:#}`) gives the symbol of the named derived unit instead where there is one, e.g. `J`
+impl fmt::Display for Dimension {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if let Some(symbol) = self.derived_symbol().filter(|_| f.alternate()) {
+			return f.write_str(symbol);
+		}
+		let mut first = true;
+		for (power, symbol) in [(self.mass,"kg"), (self.length,"m"), (self.time,"s"), (self.current,"A"), (self.temperature,"K"), (self.amount,"mol"), (self.luminous_intensity,"cd"), (self.angle,"rad"),
+			(self.count,"count")] {
+			if power != 0 {
+				if !first {
+					write!(f, " ")?;
+				}
+				write!(f, "{}", symbol)?;
+				let g = gcd(power, self.denominator);
+				let (num, den) = (power/g, self.denominator/g);
+				if den != 1 {
+					write!(f, "^({}/{})", num, den)?;
+				} else if num != 1 {
+					write!(f, "^{}", num)?;
+				}
+				first = false;
+			}
+		}
+		Ok(())
+	}
+}
+
+
 
 // Arithmetic
 
 /// Define addition of any two [Quantities][Quantity] with the same dimension
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Add for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Add for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	/// Dimensioned addition does not change the dimension
 	type Output = Self;
 	fn add(self, rhs: Self) -> Self::Output { Quantity {value_si:self.value_si+rhs.value_si} }
 }
 /// Define subtraction of any two [Quantities][Quantity] with the same dimension
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Sub for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Sub for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	/// Dimensioned subtraction does not change the dimension
 	type Output = Self;
 	fn sub(self, rhs: Self) -> Self::Output { Quantity {value_si:self.value_si-rhs.value_si} }
 }
 
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-Neg for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Neg for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	type Output = Self;
 	fn neg(self) -> Self { Quantity {value_si:-self.value_si} }
 }
+/// Define in-place addition of a [Quantity] with the same dimension, for accumulating in loops
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const AddAssign for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn add_assign(&mut self, rhs: Self) { self.value_si += rhs.value_si }
+}
+/// Define in-place subtraction of a [Quantity] with the same dimension
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const SubAssign for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn sub_assign(&mut self, rhs: Self) { self.value_si -= rhs.value_si }
+}
 
 
 // The true magic - dimension tracking multiplication and division
 
 /// Define unit-aware multiplication of any two [Quantities][Quantity], computing the correct dimensioned type for the result
-impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize,
-		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize>
-const Mul<Quantity<T2,L2,M2,I2,TEMP2>> for Quantity<T1,L1,M1,I1,TEMP1> where
-	Quantity<{T1+T2},{L1+L2},{M1+M2},{I1+I2},{TEMP1+TEMP2}>: Sized
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Mul<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
 {
 	/// Dimensioned multiplication produces a result with the sum of the exponents of each dimension
-	type Output = Quantity<{T1+T2},{L1+L2},{M1+M2},{I1+I2},{TEMP1+TEMP2}>;
-	fn mul(self, rhs: Quantity<T2,L2,M2,I2,TEMP2>) -> Quantity<{T1+T2},{L1+L2},{M1+M2},{I1+I2},{TEMP1+TEMP2}>
+	type Output = Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn mul(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>
 	{
 		Quantity {value_si:self.value_si*rhs.value_si}
 	}
 }
 
 /// Define unit-aware division of any two [Quantities][Quantity], computing the correct dimensioned type for the result
-impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize,
-		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize>
-const Div<Quantity<T2,L2,M2,I2,TEMP2>> for Quantity<T1,L1,M1,I1,TEMP1> where
-	Quantity<{T1-T2},{L1-L2},{M1-M2},{I1-I2},{TEMP1-TEMP2}>: Sized
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Div<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
 {
 	/// Dimensioned division produces a result with the sum of the exponents of each dimension
-	type Output = Quantity<{T1-T2},{L1-L2},{M1-M2},{I1-I2},{TEMP1-TEMP2}>;
-	fn div(self, rhs: Quantity<T2,L2,M2,I2,TEMP2>) -> Quantity<{T1-T2},{L1-L2},{M1-M2},{I1-I2},{TEMP1-TEMP2}>
+	type Output = Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn div(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>
 	{
 		Quantity {value_si:self.value_si/rhs.value_si}
 	}
@@ -173,48 +455,58 @@ const Div<Quantity<T2,L2,M2,I2,TEMP2>> for Quantity<T1,L1,M1,I1,TEMP1> where
 
 
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Mul<f64> for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Mul<Float> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	type Output = Self;
-	fn mul(self, rhs: f64) -> Self::Output { Quantity{value_si:self.value_si*rhs} }
+	fn mul(self, rhs: Float) -> Self::Output { Quantity{value_si:self.value_si*rhs} }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Div<f64> for Quantity<T,L,M,I,TEMP> {
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Div<Float> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	type Output = Self;
-	fn div(self, rhs: f64) -> Self::Output { Quantity{value_si:self.value_si/rhs}  }
+	fn div(self, rhs: Float) -> Self::Output { Quantity{value_si:self.value_si/rhs}  }
+}
+/// Define in-place scaling by floats as unitless values
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const MulAssign<Float> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn mul_assign(&mut self, rhs: Float) { self.value_si *= rhs }
+}
+/// Define in-place scaling by floats as unitless values
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const DivAssign<Float> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn div_assign(&mut self, rhs: Float) { self.value_si /= rhs }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Mul<Quantity<T,L,M,I,TEMP>> for f64 {
-	type Output = Quantity<T,L,M,I,TEMP>;
-	fn mul(self, rhs: Quantity<T,L,M,I,TEMP>) -> Quantity<T,L,M,I,TEMP> { Quantity{value_si:self*rhs.value_si} }
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Mul<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> for Float {
+	type Output = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn mul(self, rhs: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { Quantity{value_si:self*rhs.value_si} }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-const Div<Quantity<T,L,M,I,TEMP>> for f64 where
-	Quantity<{-T},{-L},{-M},{-I},{-TEMP}>: Sized
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Div<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> for Float where
+	Quantity<{-T},{-L},{-M},{-I},{-TEMP},{-MOL},{-LUM},{-ANG},{-CNT},D>: Sized
 {
-	type Output = Quantity<{-T},{-L},{-M},{-I},{-TEMP}>;
-	fn div(self, rhs: Quantity<T,L,M,I,TEMP>) -> Quantity<{-T},{-L},{-M},{-I},{-TEMP}> { Quantity{value_si:self/rhs.value_si} }
+	type Output = Quantity<{-T},{-L},{-M},{-I},{-TEMP},{-MOL},{-LUM},{-ANG},{-CNT},D>;
+	fn div(self, rhs: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Quantity<{-T},{-L},{-M},{-I},{-TEMP},{-MOL},{-LUM},{-ANG},{-CNT},D> { Quantity{value_si:self/rhs.value_si} }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl const Add<f64> for Unitless {
+impl const Add<Float> for Unitless {
 	type Output = Unitless;
-	fn add(self,rhs: f64) -> Unitless { self+Unitless::from(rhs) }
+	fn add(self,rhs: Float) -> Unitless { self+Unitless::from(rhs) }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl const Sub<f64> for Unitless {
+impl const Sub<Float> for Unitless {
 	type Output = Unitless;
-	fn sub(self,rhs: f64) -> Unitless { self-Unitless::from(rhs) }
+	fn sub(self,rhs: Float) -> Unitless { self-Unitless::from(rhs) }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl const Add<Unitless> for f64 {
+impl const Add<Unitless> for Float {
 	type Output = Unitless;
 	fn add(self,rhs: Unitless) -> Unitless { Unitless::from(self)+rhs }
 }
 /// Define direct operations with floats as unitless values to avoid needing from and into everywhere
-impl const Sub<Unitless> for f64 {
+impl const Sub<Unitless> for Float {
 	type Output = Unitless;
 	fn sub(self,rhs: Unitless) -> Unitless { Unitless::from(self)-rhs }
 }
@@ -222,28 +514,68 @@ impl const Sub<Unitless> for f64 {
 
 
 
+// Generic quantities
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Implemented by every [Quantity] type, so containers and algorithms (ring buffers, caches, interpolators, ...) can be written once
+/// over any dimensioned value as `Q: AnyQuantity` rather than repeating the const generics of [Quantity].  This trait is sealed and
+/// cannot be implemented outside this crate.
+pub trait AnyQuantity: sealed::Sealed + Copy + fmt::Debug + fmt::Display
+	+ Add<Output=Self> + Sub<Output=Self> + Neg<Output=Self> + Mul<Float,Output=Self> + Div<Float,Output=Self>
+{
+	/// The runtime [Dimension] of this quantity type
+	const DIMENSION: Dimension;
+	/// Get the numerical value of this quantity in SI base units, same as [Quantity::as_si]
+	fn as_si(self) -> Float;
+	/// Create a quantity from a numerical value in SI base units, same as [Quantity::from_si]
+	fn from_si(val: Float) -> Self;
+	/// The runtime [Dimension] of this quantity
+	fn dimension(&self) -> Dimension { Self::DIMENSION }
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+sealed::Sealed for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+AnyQuantity for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	const DIMENSION: Dimension = Dimension::new(T,L,M,I,TEMP,MOL,LUM,ANG,CNT).over(D);
+	fn as_si(self) -> Float { self.value_si }
+	fn from_si(val: Float) -> Self { Quantity { value_si:val } }
+}
+
 
 
 // Unit definitions
 
 
-/// A implementation of [Unit] represents a means to turn some physical quantity of type [Self::Dimen] into a numerical value ([f64]).
+/// A implementation of [Unit] represents a means to turn some physical quantity of type [Self::Dimen] into a numerical value ([Float]).
 pub trait Unit : Copy {
 	/// The dimensioned value to convert to/from its float value in whatever unit this represents.  This is normally some specialization of [Quantity] from [crate::dimens]
 	type Dimen;
 	/// Produce a physical quantity from a numerical value in this unit.  Should generally be inverse to [Self::val_to_qty()]
-	fn qty_to_val(&self, value: Self::Dimen) -> f64;
+	fn qty_to_val(&self, value: Self::Dimen) -> Float;
 	/// Produce the numerical value of this unit corresponding to the provided physical value.  Should generally be inverse to [Self::qty_to_val()]
-	fn val_to_qty(&self, value: f64) -> Self::Dimen;
+	fn val_to_qty(&self, value: Float) -> Self::Dimen;
+}
+
+/// A [Unit] with a display symbol, such as a [NamedUnit] or a unit declared by [define_unit!][crate::define_unit]
+pub trait UnitSymbol : Unit {
+	/// Symbol labelling values in this unit, e.g. `"fur"`
+	fn symbol(&self) -> &str;
+	/// Symbol labelling the numerical value `value`, which may differ from [UnitSymbol::symbol] for units with plurals
+	fn symbol_for(&self, _value: Float) -> &str { self.symbol() }
 }
 
 /// Any [Quantity] can also act as a unit of that type of quantity by division.
-/// Most units are implmented in this fashion, except where nonlinear behavior is required (ref [OffsetUnit], [LogUnit])
-impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-Unit for Quantity<T,L,M,I,TEMP> {
+/// Most units are implmented in this fashion, except where nonlinear behavior is required (ref [OffsetUnit], [LogUnit], [ReciprocalUnit])
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Unit for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
 	type Dimen = Self;
-	fn qty_to_val(&self, value: Self) -> f64 { value.value_si/self.value_si }
-	fn val_to_qty(&self, value: f64) -> Self { value*(*self) }
+	fn qty_to_val(&self, value: Self) -> Float { value.value_si/self.value_si }
+	fn val_to_qty(&self, value: Float) -> Self { value*(*self) }
 }
 
 
@@ -256,7 +588,7 @@ pub struct OffsetUnit<Dimen: Copy>{
 impl<Dimen: Copy> OffsetUnit<Dimen> {
 	/// Create an offset unit with the same scale as `baseunit` but offset with 0 corresponding with the physical quantity `zero`
 	pub const fn new(baseunit: Dimen, zero: Dimen) -> OffsetUnit<Dimen> {
-		OffsetUnit{ unit:baseunit, zero:zero }
+		OffsetUnit{ unit:baseunit, zero }
 	}
 	/// Get the zero quantity for this unit
 	pub const fn zero_qty(&self) -> Dimen { self.zero }
@@ -268,7 +600,7 @@ impl<Dimen: Copy> OffsetUnit<Dimen> where
 	pub const fn as_abs_unit(&self) -> impl Unit<Dimen=Dimen> { *self }	
 
 	/// Get an absolute quantity from a numeric value of this unit.  Equivalent to `value*self`
-	pub fn abs_qty_of(&self, value: f64) -> Dimen { self.val_to_qty(value) }
+	pub fn abs_qty_of(&self, value: Float) -> Dimen { self.val_to_qty(value) }
 }
 impl<Dimen: Copy> OffsetUnit<Dimen> where
 	Dimen: Unit<Dimen=Dimen>
@@ -280,18 +612,18 @@ impl<Dimen: Copy> OffsetUnit<Dimen> where
 	
 	/// Get a relative quantity in this unit.  This should be used when adding an offset to an existing value.  For example given a [Temperature][crate::dimens::Temperature] `temp`, to add 5 deg F you would
 	/// write <code>temp+[FAHRENHEIT][crate::units::FAHRENHEIT].rel_qty_of(5.0)</code> and not `temp+5.0*FAHRENHEIT` since the latter would interpret `5.0*FAHRENHEIT` as an absolute temperature (258.15 K).
-	pub fn rel_qty_of(&self, value: f64) -> Dimen { self.unit.val_to_qty(value) }
+	pub fn rel_qty_of(&self, value: Float) -> Dimen { self.unit.val_to_qty(value) }
 }
 impl<Dimen,O> Unit for OffsetUnit<Dimen> where
 	Dimen: Copy + Add<Dimen,Output=Dimen> + Sub<Dimen,Output=Dimen> + Div<Dimen,Output=O>,
-	O: Into<f64>,
-	f64: Mul<Dimen,Output=Dimen>
+	O: Into<Float>,
+	Float: Mul<Dimen,Output=Dimen>
 {
 	type Dimen = Dimen;
-	fn qty_to_val(&self, value: Dimen) -> f64 {
+	fn qty_to_val(&self, value: Dimen) -> Float {
 		((value - self.zero)/self.unit).into()
 	}
-	fn val_to_qty(&self, value: f64) -> Dimen {
+	fn val_to_qty(&self, value: Float) -> Dimen {
 		value*self.unit + self.zero
 	}
 }
@@ -299,69 +631,381 @@ impl<Dimen,O> Unit for OffsetUnit<Dimen> where
 /// Represents a logarthmically scaled [Unit] of `Dimen`
 #[derive(Clone, Copy, Debug)]
 pub struct LogUnit<Dimen: Copy> {
-	scale: f64,
+	scale: Float,
 	reference: Dimen
 }
 impl<Dimen: Copy> LogUnit<Dimen> {
 	/// Construct a logarithmic unit with base `base` and `scale` units per factor of `base` relative to the `reference` quantity
-	pub fn new(base:f64,scale:f64,reference:Dimen) -> LogUnit<Dimen> {
-		LogUnit { scale:scale/f64::log2(base), reference:reference }
+	pub fn new(base:Float,scale:Float,reference:Dimen) -> LogUnit<Dimen> {
+		LogUnit { scale:scale/Float::log2(base), reference }
 	}
 	/// Construct a logarithmic unit with `scale` units/octave relative to the `reference` quantity
-	pub const fn base2(scale:f64,reference:Dimen) -> LogUnit<Dimen> {
-		LogUnit { scale:scale, reference:reference }
+	pub const fn base2(scale:Float,reference:Dimen) -> LogUnit<Dimen> {
+		LogUnit { scale, reference }
 	}
 	/// Construct a logarithmic unit with `scale` units/decade relative to the `reference` quantity
-	pub const fn base10(scale:f64,reference:Dimen) -> LogUnit<Dimen> { LogUnit::base2(scale/std::f64::consts::LOG2_10,reference) }
+	pub const fn base10(scale:Float,reference:Dimen) -> LogUnit<Dimen> { LogUnit::base2(scale/crate::float_consts::LOG2_10,reference) }
 	/// Construct a logarithmic unit with `scale` units per factor of e relative to the `reference` quantity
-	pub const fn basee(scale:f64,reference:Dimen) -> LogUnit<Dimen> { LogUnit::base2(scale/std::f64::consts::LOG2_E,reference) }
+	pub const fn basee(scale:Float,reference:Dimen) -> LogUnit<Dimen> { LogUnit::base2(scale/crate::float_consts::LOG2_E,reference) }
 }
 impl<Dimen: Copy> LogUnit<Dimen> where
 	LogUnit<Dimen>: Unit<Dimen=Dimen>
 {
 	/// Get the physical quantity corresponding to the value `val` in this unit
-	pub fn qty_of(&self, val: f64) -> Dimen { self.val_to_qty(val) }
+	pub fn qty_of(&self, val: Float) -> Dimen { self.val_to_qty(val) }
 }
 impl<Dimen,O> Unit for LogUnit<Dimen> where
 	Dimen: Copy + Div<Dimen,Output=O>,
-	O: Into<f64>,
-	f64: Mul<Dimen,Output=Dimen> + Mul<f64>
+	O: Into<Float>,
+	Float: Mul<Dimen,Output=Dimen> + Mul<Float>
 {
 	type Dimen = Dimen;
-	fn qty_to_val(&self, value: Dimen) -> f64 {
-		let ratio: f64 = (value/self.reference).into();
-		self.scale * f64::log2(ratio)
+	fn qty_to_val(&self, value: Dimen) -> Float {
+		let ratio: Float = (value/self.reference).into();
+		self.scale * Float::log2(ratio)
 	}
-	fn val_to_qty(&self, value: f64) -> Dimen {
-		let ratio =f64::exp2(value/self.scale);
+	fn val_to_qty(&self, value: Float) -> Dimen {
+		let ratio =Float::exp2(value/self.scale);
 		ratio * self.reference
 	}
 }
 
+/// Represents a [Unit] of `Dimen` whose numerical value is inversely proportional to the quantity, such as [MIRED][crate::units::MIRED]
+/// (10<sup>6</sup> K divided by the temperature).  Since these units are nonlinear, differences between values in them do not correspond to
+/// differences between quantities.
+#[derive(Clone, Copy, Debug)]
+pub struct ReciprocalUnit<Dimen: Copy> {
+	numerator: Dimen
+}
+impl<Dimen: Copy> ReciprocalUnit<Dimen> {
+	/// Construct a reciprocal unit where a quantity `q` has the numerical value `numerator/q`
+	pub const fn new(numerator: Dimen) -> ReciprocalUnit<Dimen> {
+		ReciprocalUnit { numerator }
+	}
+	/// Get the quantity which has the value 1 in this unit
+	pub const fn numerator(&self) -> Dimen { self.numerator }
+}
+impl<Dimen,O> Unit for ReciprocalUnit<Dimen> where
+	Dimen: Copy + Div<Dimen,Output=O> + Div<Float,Output=Dimen>,
+	O: Into<Float>
+{
+	type Dimen = Dimen;
+	fn qty_to_val(&self, value: Dimen) -> Float {
+		(self.numerator/value).into()
+	}
+	fn val_to_qty(&self, value: Float) -> Dimen {
+		self.numerator/value
+	}
+}
+
+
+/// A [Unit] together with the symbol it is written with, such as [NEWTON][crate::units::NEWTON] and "N".  The constants in
+/// [units][crate::units] are named units, so output code can label values with their symbols and input code can recognize them in text.
+/// A named unit of a [Quantity] combines with quantities, floats and other units just as the bare quantity does (`KILO*GRAM*METER/SECOND`
+/// is a plain [Quantity]), and the methods of the underlying unit are available through [Deref], e.g. `HOUR.as_si()` or
+/// `CELSIUS.as_rel_unit()`.
+#[derive(Clone, Copy, Debug)]
+pub struct NamedUnit<U: Copy> {
+	unit: U,
+	symbol: &'static str,
+	plural: Option<&'static str>,
+	aliases: &'static [&'static str],
+}
+impl<U: Copy> NamedUnit<U> {
+	/// Name `unit` by `symbol`
+	pub const fn new(unit: U, symbol: &'static str) -> NamedUnit<U> {
+		NamedUnit { unit, symbol, plural: None, aliases: &[] }
+	}
+	/// Give the symbol a plural for values other than one, for symbols which are words such as "day"
+	pub const fn with_plural(self, plural: &'static str) -> NamedUnit<U> {
+		NamedUnit { plural: Some(plural), ..self }
+	}
+	/// Recognize other spellings of this unit when parsing, e.g. "metre" and "meters"
+	pub const fn with_aliases(self, aliases: &'static [&'static str]) -> NamedUnit<U> {
+		NamedUnit { aliases, ..self }
+	}
+
+	/// The underlying unit
+	pub const fn unit(&self) -> U { self.unit }
+	pub const fn symbol(&self) -> &'static str { self.symbol }
+	pub const fn plural(&self) -> Option<&'static str> { self.plural }
+	pub const fn aliases(&self) -> &'static [&'static str] { self.aliases }
+
+	/// Symbol labelling the numerical value `value`, the plural if there is one and `value` is not 1
+	pub fn symbol_for(&self, value: Float) -> &'static str {
+		match self.plural {
+			Some(plural) if value != 1.0 => plural,
+			_ => self.symbol,
+		}
+	}
+	/// Whether `s` is the symbol, plural or an alias of this unit
+	pub fn recognizes(&self, s: &str) -> bool {
+		s == self.symbol || self.plural == Some(s) || self.aliases.contains(&s)
+	}
+}
+#[cfg(feature = "std")]
+impl<U: Unit> NamedUnit<U> {
+	/// Parse a number written in this unit, e.g. `"3 ft"` or `"2 feet"` for [FOOT][crate::units::FOOT].  For strings in arbitrary units
+	/// see [parse_value][crate::parse::parse_value]
+	pub fn parse(&self, s: &str) -> Result<U::Dimen, UnitParseError> {
+		let (number, symbol) = split_value(s);
+		let value: Float = number.parse().map_err(|_| UnitParseError::InvalidNumber(number.to_string()))?;
+		if symbol.is_empty() {
+			Err(UnitParseError::Empty)
+		} else if self.recognizes(symbol) {
+			Ok(self.unit.val_to_qty(value))
+		} else {
+			Err(UnitParseError::UnknownSymbol(symbol.to_string()))
+		}
+	}
+}
+impl<U: Copy> const Deref for NamedUnit<U> {
+	type Target = U;
+	fn deref(&self) -> &U { &self.unit }
+}
+impl<U: Unit> Unit for NamedUnit<U> {
+	type Dimen = U::Dimen;
+	fn qty_to_val(&self, value: U::Dimen) -> Float { self.unit.qty_to_val(value) }
+	fn val_to_qty(&self, value: Float) -> U::Dimen { self.unit.val_to_qty(value) }
+}
+impl<U: Unit> UnitSymbol for NamedUnit<U> {
+	fn symbol(&self) -> &str { self.symbol }
+	fn symbol_for(&self, value: Float) -> &str { NamedUnit::symbol_for(self, value) }
+}
+/// Formats as the symbol
+impl<U: Copy> fmt::Display for NamedUnit<U> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.symbol) }
+}
+
+/// Define unit-aware multiplication of a named unit by a [Quantity], as for the unit's quantity
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Mul<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for NamedUnit<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn mul(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Self::Output { self.unit*rhs }
+}
+/// Define unit-aware multiplication of a [Quantity] by a named unit, as for the unit's quantity
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Mul<NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn mul(self, rhs: NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output { self*rhs.unit }
+}
+/// Define unit-aware multiplication of two named units, as for their quantities
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Mul<NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for NamedUnit<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn mul(self, rhs: NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output { self.unit*rhs.unit }
+}
+/// Define unit-aware division of a named unit by a [Quantity], as for the unit's quantity
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Div<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for NamedUnit<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn div(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Self::Output { self.unit/rhs }
+}
+/// Define unit-aware division of a [Quantity] by a named unit, as for the unit's quantity
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Div<NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn div(self, rhs: NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output { self/rhs.unit }
+}
+/// Define unit-aware division of two named units, as for their quantities
+impl<	const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+		const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+const Div<NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for NamedUnit<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>;
+	fn div(self, rhs: NamedUnit<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output { self.unit/rhs.unit }
+}
+/// Define scaling of a named unit by a float, giving a [Quantity]
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Mul<Float> for NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn mul(self, rhs: Float) -> Self::Output { self.unit*rhs }
+}
+/// Define scaling of a named unit by a float, giving a [Quantity]
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Div<Float> for NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn div(self, rhs: Float) -> Self::Output { self.unit/rhs }
+}
+/// Construct a [Quantity] from a float and a named unit, e.g. `3.0*METER`
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Mul<NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>> for Float {
+	type Output = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn mul(self, rhs: NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Self::Output { self*rhs.unit }
+}
+/// Define division of a float by a named unit, e.g. `1.0/SECOND`
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+const Div<NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>> for Float where
+	Quantity<{-T},{-L},{-M},{-I},{-TEMP},{-MOL},{-LUM},{-ANG},{-CNT},D>: Sized
+{
+	type Output = Quantity<{-T},{-L},{-M},{-I},{-TEMP},{-MOL},{-LUM},{-ANG},{-CNT},D>;
+	fn div(self, rhs: NamedUnit<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Self::Output { self/rhs.unit }
+}
+
 
 // Multiplication Constructors
 /**
-Generates an implementation body to go in an `impl Mul<type> for f64`  on a type `type` implementing [Unit].
-This implementation of [Mul] creates a quantity from the multiplying [f64] via [Unit::val_to_qty()]
+Generates an implementation body to go in an `impl Mul<type> for Float`  on a type `type` implementing [Unit].
+This implementation of [Mul] creates a quantity from the multiplying [Float] via [Unit::val_to_qty()]
 
-Cannot do this generically since it is implemented on [f64] and it would implement [Mul] specializations for unowned types.
+Cannot do this generically since it is implemented on [Float] and it would implement [Mul] specializations for unowned types.
 Generates only the body of the implmentation so the caller can add customized generics/generic bounds around the implmentation as needed.
 */
 #[macro_export]
 macro_rules! unit_mul_constructor_impl
 {
 	($type:ty) => {
-		type Output = <$type as Unit>::Dimen;
-		fn mul(self, rhs: $type) -> Self::Output { rhs.val_to_qty(self) }
+		type Output = <$type as $crate::Unit>::Dimen;
+		fn mul(self, rhs: $type) -> Self::Output { $crate::Unit::val_to_qty(&rhs, self) }
 	}
 }
-impl<Dimen: Copy> Mul<OffsetUnit<Dimen>> for f64 where
+impl<Dimen: Copy> Mul<OffsetUnit<Dimen>> for Float where
 	OffsetUnit<Dimen>: Unit
 {
 	unit_mul_constructor_impl!(OffsetUnit<Dimen>);
 }
-impl<Dimen: Copy> Mul<LogUnit<Dimen>> for f64 where
+impl<Dimen: Copy> Mul<LogUnit<Dimen>> for Float where
 	LogUnit<Dimen>: Unit
 {
 	unit_mul_constructor_impl!(LogUnit<Dimen>);
-}
\ No newline at end of file
+}
+impl<Dimen: Copy> Mul<ReciprocalUnit<Dimen>> for Float where
+	ReciprocalUnit<Dimen>: Unit
+{
+	unit_mul_constructor_impl!(ReciprocalUnit<Dimen>);
+}
+impl<Dimen: Copy> Mul<NamedUnit<OffsetUnit<Dimen>>> for Float where
+	OffsetUnit<Dimen>: Unit
+{
+	unit_mul_constructor_impl!(NamedUnit<OffsetUnit<Dimen>>);
+}
+impl<Dimen: Copy> Mul<NamedUnit<LogUnit<Dimen>>> for Float where
+	LogUnit<Dimen>: Unit
+{
+	unit_mul_constructor_impl!(NamedUnit<LogUnit<Dimen>>);
+}
+impl<Dimen: Copy> Mul<NamedUnit<ReciprocalUnit<Dimen>>> for Float where
+	ReciprocalUnit<Dimen>: Unit
+{
+	unit_mul_constructor_impl!(NamedUnit<ReciprocalUnit<Dimen>>);
+}
+
+/**
+Declares named units for use in crates building on this one.  Each unit becomes a unit struct implementing [Unit] and [UnitSymbol],
+with the `Float * UNIT` constructor, so it is used like the built in units, e.g. `3.0*FURLONG` or `d.as_unit(FURLONG)`.
+Each declaration gives the dimension, the size of the unit as a quantity, the symbol, and optionally the quantity at which values
+in the unit are zero for offset units like [CELSIUS][crate::units::CELSIUS]:
+
+```
+use dimtypes::define_unit;
+use dimtypes::dimens::*;
+use dimtypes::units::*;
+
+define_unit! {
+    /// Furlong, an eighth of a mile
+    pub FURLONG: Length = 201.168*METER, "fur";
+    /// Degree Rømer
+    pub ROMER: Temperature = 40.0/21.0*KELVIN, "°Rø", offset 273.15*KELVIN - 7.5*40.0/21.0*KELVIN;
+}
+
+let race = 8.0*FURLONG;
+assert!((race.as_unit(MILE) - 1.0).abs() < 1e-3);
+assert!(((7.5*ROMER).as_unit(CELSIUS)).abs() < 1e-3);
+assert!(((60.0*ROMER).as_unit(CELSIUS) - 100.0).abs() < 1e-3);
+```
+
+The size and offset are evaluated when converting, so they may use the operators on [Quantity] without the calling crate enabling
+const trait features.
+*/
+#[macro_export]
+macro_rules! define_unit {
+	($($(#[$attr:meta])* $vis:vis $name:ident: $dimen:ty = $scale:expr, $symbol:literal $(, offset $offset:expr)?;)*) => {
+		$(
+			$(#[$attr])*
+			#[allow(non_camel_case_types)]
+			#[derive(Clone, Copy, Debug)]
+			$vis struct $name;
+
+			impl $crate::Unit for $name {
+				type Dimen = $dimen;
+				fn qty_to_val(&self, value: $dimen) -> $crate::Float {
+					let scale: $dimen = $scale;
+					$crate::Unit::qty_to_val(&scale, value $(- $offset)?)
+				}
+				fn val_to_qty(&self, value: $crate::Float) -> $dimen {
+					let scale: $dimen = $scale;
+					$crate::Unit::val_to_qty(&scale, value) $(+ $offset)?
+				}
+			}
+
+			impl $crate::UnitSymbol for $name {
+				fn symbol(&self) -> &str { $symbol }
+			}
+
+			impl ::core::ops::Mul<$name> for $crate::Float {
+				$crate::unit_mul_constructor_impl!($name);
+			}
+		)*
+	};
+}
+
+/**
+Declares named [Quantity] types for dimensions not covered by [dimens][crate::dimens], by the power of each base dimension with the
+field names of [Dimension].  Unlisted powers are zero, and fractional powers are given as numerators over a `denominator`, which
+defaults to 1 and is reduced to lowest terms as for [Dimension::over]:
+
+```
+use dimtypes::define_dimension;
+use dimtypes::dimens::*;
+use dimtypes::units::*;
+
+define_dimension! {
+    /// Rate of change of acceleration
+    pub Jerk { time: -3, length: 1 }
+    pub SurfaceChargeDensity { time: 1, length: -2, current: 1 }
+    /// Spectral noise density of a voltage, in V/√Hz
+    pub VoltageNoiseDensity { time: -5, length: 4, mass: 2, current: -2, denominator: 2 }
+}
+
+let jerk: Jerk = 2.0*METER/SECOND/SECOND/SECOND;
+let noise: VoltageNoiseDensity = 1.0*VOLT/(1.0*HERTZ).root::<2>();
+assert_eq!(jerk.as_si(), 2.0);
+assert_eq!(noise.as_si(), 1.0);
+```
+*/
+#[macro_export]
+macro_rules! define_dimension {
+	($($(#[$attr:meta])* $vis:vis $name:ident { $($base:ident: $power:expr),* $(,)? })*) => {
+		$(
+			$(#[$attr])*
+			$vis type $name = $crate::Quantity<
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).time},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).length},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).mass},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).current},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).temperature},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).amount},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).luminous_intensity},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).angle},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).count},
+				{$crate::Dimension { $($base: $power,)* ..$crate::Dimension::NONE }.over(1).denominator},
+			>;
+		)*
+	};
+}
@@ -0,0 +1,55 @@
+//! Exponential decay by half-life, for radioactive sources, radiocarbon dating and other first-order processes
+//!
+//! A process is described either by its half-life t<sub>½</sub> or its [DecayConstant] λ = ln 2 / t<sub>½</sub>.  Activities are
+//! [Activity] quantities, entered in [BECQUEREL][crate::units::BECQUEREL] or [CURIE][crate::units::CURIE].
+
+use crate::float_consts::LN_2;
+use crate::dimens::*;
+use crate::math::{exp,ln};
+use crate::units::YEAR;
+
+/// Libby half-life of carbon-14, which conventional radiocarbon ages are quoted against
+pub const LIBBY_HALF_LIFE: Time = 5568.0*YEAR;
+/// Cambridge (best measured) half-life of carbon-14
+pub const CARBON_14_HALF_LIFE: Time = 5730.0*YEAR;
+
+/// Decay constant λ of a process with half-life `half_life`
+pub fn decay_constant(half_life: Time) -> DecayConstant {
+	LN_2/half_life
+}
+
+/// Half-life of a process with decay constant `lambda`
+pub fn half_life(lambda: DecayConstant) -> Time {
+	LN_2/lambda
+}
+
+/// Mean lifetime τ = 1/λ of a process with half-life `half_life`
+pub fn mean_lifetime(half_life: Time) -> Time {
+	half_life/LN_2
+}
+
+/// Fraction of the original amount remaining after time `t`, 2<sup>-t/t<sub>½</sub></sup>
+pub fn remaining_fraction(t: Time, half_life: Time) -> Unitless {
+	exp(-decay_constant(half_life)*t)
+}
+
+/// Activity after time `t` of a source with initial activity `initial` and half-life `half_life`
+pub fn activity_after(initial: Activity, t: Time, half_life: Time) -> Activity {
+	initial*remaining_fraction(t, half_life)
+}
+
+/// Time for the amount to fall to `fraction` of its original value
+pub fn time_to_fraction(fraction: Unitless, half_life: Time) -> Time {
+	-ln(fraction)/decay_constant(half_life)
+}
+
+/// Activity of `atoms` undecayed nuclei with half-life `half_life`, A = λN
+pub fn activity_of(atoms: Unitless, half_life: Time) -> Activity {
+	atoms*decay_constant(half_life)
+}
+
+/// Age of a sample whose carbon-14 has fallen to `fraction` of the modern level, using `half_life` (usually [LIBBY_HALF_LIFE] for a
+/// conventional radiocarbon age or [CARBON_14_HALF_LIFE] for a physical age)
+pub fn radiocarbon_age(fraction: Unitless, half_life: Time) -> Time {
+	time_to_fraction(fraction, half_life)
+}
@@ -4,31 +4,174 @@ pub mod dimens {
 	use crate::Quantity;
 
 	/// Unitless quantity  
-	/// Unitless quantities have the special ability to convert directly to/from [f64] ([Unitless] implements [`From<f64>`] and [f64] implements [`From<Unitless>`])
-	pub type Unitless =		Quantity<0,0,0,0,0>;
-
-	pub type Time =			Quantity<1,0,0,0,0>;
-	pub type Length =		Quantity<0,1,0,0,0>;
-	pub type Area =			Quantity<0,2,0,0,0>;
-	pub type Volume =		Quantity<0,3,0,0,0>;
-	pub type Mass =			Quantity<0,0,1,0,0>;
-	pub type Density =		Quantity<0,-3,1,0,0>;
-	pub type Current =		Quantity<0,0,0,1,0>;
-	pub type Temperature =	Quantity<0,0,0,0,1>;
-	pub type Force =		Quantity<-2,1,1,0,0>;
-	pub type Pressure =		Quantity<-2,-1,1,0,0>;
-	pub type Momentum =		Quantity<-1,1,1,0,0>;
-	pub type Velocity =		Quantity<-1,1,0,0,0>;
-	pub type Acceleration =	Quantity<-2,1,0,0,0>;
-	pub type Energy =		Quantity<-2,2,1,0,0>;
-	pub type Power =		Quantity<-3,2,1,0,0>;
-	pub type Voltage =		Quantity<-3,2,1,-1,0>;
-	pub type Charge =		Quantity<1,0,0,1,0>;
-	pub type Resistance =	Quantity<-3,2,1,-2,0>;
-	pub type Capacitance =	Quantity<4,-2,-1,2,0>;
-	pub type Inductance =	Quantity<-2,2,1,-2,0>;
-	pub type MagneticFlux =	Quantity<-2,2,1,-1,0>;
-	pub type Frequency =	Quantity<-1,0,0,0,0>;
+	/// Unitless quantities have the special ability to convert directly to/from [Float][crate::Float] ([Unitless] implements [`From<Float>`][From] and [Float][crate::Float] implements [`From<Unitless>`])
+	pub type Unitless =		Quantity<0,0,0,0,0,0,0,0,0>;
+	/// Plane angle  
+	/// Angles are tracked as a dimension so angular and plain rates cannot be mixed.  The trigonometric functions in [math][crate::math]
+	/// take and return [Angle]s, and multiplying or dividing by [RADIAN][crate::units::RADIAN] erases or adds the dimension where a formula
+	/// treats radians as pure numbers (e.g. arc length s = rθ)
+	pub type Angle =		Quantity<0,0,0,0,0,0,0,1,0>;
+	pub type SolidAngle =	Quantity<0,0,0,0,0,0,0,2,0>;
+	/// Number of discrete items or events (parts, packets, failures, cycles)  
+	/// Counts are tracked as a dimension so items per second are not a [Frequency] and items per kilogram are not a plain
+	/// per-mass quantity.  Multiplying or dividing by [COUNT][crate::units::COUNT] adds or erases the dimension where a formula
+	/// treats a count as a pure number
+	pub type Count =		Quantity<0,0,0,0,0,0,0,0,1>;
+	/// Items or events per unit time, e.g. throughput or failure rate.  Distinct from [Frequency]
+	pub type CountRate =	Quantity<-1,0,0,0,0,0,0,0,1>;
+	/// Failures per unit time of a component or system.  Same dimension as [CountRate]
+	pub type FailureRate =	CountRate;
+	/// Items per unit mass, e.g. particles per kilogram
+	pub type CountPerMass =	Quantity<0,0,-1,0,0,0,0,0,1>;
+	/// Items per unit volume, e.g. particles per cubic meter.  Distinct from [Concentration] in moles
+	pub type NumberDensity =	Quantity<0,-3,0,0,0,0,0,0,1>;
+
+	pub type Time =			Quantity<1,0,0,0,0,0,0,0,0>;
+	pub type Length =		Quantity<0,1,0,0,0,0,0,0,0>;
+	pub type Area =			Quantity<0,2,0,0,0,0,0,0,0>;
+	pub type Volume =		Quantity<0,3,0,0,0,0,0,0,0>;
+	/// Second moment of area (area moment of inertia) of a cross-section
+	pub type SecondMomentOfArea =	Quantity<0,4,0,0,0,0,0,0,0>;
+	/// Elastic section modulus of a cross-section.  Same dimension as [Volume]
+	pub type SectionModulus =	Volume;
+	pub type Mass =			Quantity<0,0,1,0,0,0,0,0,0>;
+	pub type Density =		Quantity<0,-3,1,0,0,0,0,0,0>;
+	/// Mass moment of inertia, kg·m² per radian² so that [Torque] is inertia times angular acceleration and rotational kinetic energy
+	/// ½Iω² is an [Energy]
+	pub type MomentOfInertia =	Quantity<0,2,1,0,0,0,0,-2,0>;
+	pub type MassFlowRate =	Quantity<-1,0,1,0,0,0,0,0,0>;
+	pub type VolumetricFlowRate =	Quantity<-1,3,0,0,0,0,0,0,0>;
+	/// Mass of substance per unit volume of solution.  Same dimension as [Density]
+	pub type MassConcentration =	Density;
+	pub type AmountOfSubstance =	Quantity<0,0,0,0,0,1,0,0,0>;
+	/// Mass per amount of substance
+	pub type MolarMass =	Quantity<0,0,1,0,0,-1,0,0,0>;
+	/// Amount of substance per unit volume of solution (molarity)
+	pub type Concentration =	Quantity<0,-3,0,0,0,1,0,0,0>;
+	/// Amount of substance per unit mass of solvent
+	pub type Molality =		Quantity<0,0,-1,0,0,1,0,0,0>;
+	/// Rate of conversion of a catalyzed reaction, or any other amount of substance per unit time
+	pub type CatalyticActivity =	Quantity<-1,0,0,0,0,1,0,0,0>;
+	/// Energy per amount of substance, e.g. an enthalpy of reaction
+	pub type MolarEnergy =	Quantity<-2,2,1,0,0,-1,0,0,0>;
+	pub type LinearMassDensity =	Quantity<0,-1,1,0,0,0,0,0,0>;
+	pub type ArealDensity =	Quantity<0,-2,1,0,0,0,0,0,0>;
+	pub type Current =		Quantity<0,0,0,1,0,0,0,0,0>;
+	pub type Temperature =	Quantity<0,0,0,0,1,0,0,0,0>;
+	pub type Force =		Quantity<-2,1,1,0,0,0,0,0,0>;
+	pub type Pressure =		Quantity<-2,-1,1,0,0,0,0,0,0>;
+	pub type SpringConstant =	Quantity<-2,0,1,0,0,0,0,0,0>;
+	pub type DampingCoefficient =	Quantity<-1,0,1,0,0,0,0,0,0>;
+	/// Mechanical stress.  An alias of [Pressure], not a distinct kind: it documents intent in signatures, but the compiler accepts
+	/// any pressure where a stress is expected
+	pub type Stress =		Pressure;
+	/// Aircraft weight per unit wing area.  Same dimension as [Pressure]
+	pub type WingLoading =	Pressure;
+	/// Elastic (Young's) modulus, relating [Stress] to [Strain].  An alias of [Pressure], like [Stress]
+	pub type YoungsModulus =	Pressure;
+	/// Shear modulus, relating shear [Stress] to shear [Strain]
+	pub type ShearModulus =	Pressure;
+	/// Mechanical strain (relative deformation).  An alias of [Unitless], so any ratio is accepted where a strain is expected
+	pub type Strain =		Unitless;
+	/// Speed reduction of a gear train, input speed over output speed
+	pub type GearRatio =	Unitless;
+	pub type Momentum =		Quantity<-1,1,1,0,0,0,0,0,0>;
+	pub type Velocity =		Quantity<-1,1,0,0,0,0,0,0,0>;
+	pub type Acceleration =	Quantity<-2,1,0,0,0,0,0,0,0>;
+	/// Rate of change of [Acceleration]
+	pub type Jerk =			Quantity<-3,1,0,0,0,0,0,0,0>;
+	/// Rate of change of [Jerk]
+	pub type Snap =			Quantity<-4,1,0,0,0,0,0,0,0>;
+	pub type Energy =		Quantity<-2,2,1,0,0,0,0,0,0>;
+	/// Torque, as energy per radian of rotation so that [Torque] times [Angle] is work and [Torque] times [AngularVelocity] is power
+	pub type Torque =		Quantity<-2,2,1,0,0,0,0,-1,0>;
+	/// Angular momentum Iω, kg·m²/s per radian to match [MomentOfInertia]
+	pub type AngularMomentum =	Quantity<-1,2,1,0,0,0,0,-1,0>;
+	/// Torsional stiffness of a shaft or spring, [Torque] per [Angle] of twist
+	pub type RotationalStiffness =	Quantity<-2,2,1,0,0,0,0,-2,0>;
+	/// Rotational damping coefficient, [Torque] per [AngularVelocity]
+	pub type RotationalDamping =	Quantity<-1,2,1,0,0,0,0,-2,0>;
+	/// Bending moment of a beam cross-section (force times lever arm).  Same dimension as [Energy]
+	pub type BendingMoment =	Energy;
+	/// Flexural rigidity E·I of a beam
+	pub type FlexuralRigidity =	Quantity<-2,3,1,0,0,0,0,0,0>;
+	pub type Power =		Quantity<-3,2,1,0,0,0,0,0,0>;
+	pub type Voltage =		Quantity<-3,2,1,-1,0,0,0,0,0>;
+	pub type Charge =		Quantity<1,0,0,1,0,0,0,0,0>;
+	pub type Resistance =	Quantity<-3,2,1,-2,0,0,0,0,0>;
+	pub type Capacitance =	Quantity<4,-2,-1,2,0,0,0,0,0>;
+	pub type Inductance =	Quantity<-2,2,1,-2,0,0,0,0,0>;
+	pub type MagneticFlux =	Quantity<-2,2,1,-1,0,0,0,0,0>;
+	/// Motor torque per unit current (N·m/A), equal to the back-EMF per unit speed in V·s/rad
+	pub type TorqueConstant =	Quantity<-2,2,1,-1,0,0,0,-1,0>;
+	/// Motor speed per volt of back-EMF (K<sub>v</sub>), the reciprocal of [TorqueConstant]
+	pub type MotorVelocityConstant =	Quantity<2,-2,-1,1,0,0,0,1,0>;
+	pub type Frequency =	Quantity<-1,0,0,0,0,0,0,0,0>;
+	/// Square root of a [Frequency], the denominator of noise densities quoted per √Hz
+	pub type RootFrequency =	Quantity<-1,0,0,0,0,0,0,0,0,2>;
+	/// Radioactive activity (decays per unit time).  Same dimension as [Frequency]
+	pub type Activity =		Frequency;
+	/// Probability per unit time of a decay or other first-order process.  Same dimension as [Frequency]
+	pub type DecayConstant =	Frequency;
+	/// Rotational speed in radians per second.  Distinct from [Frequency] in cycles per second
+	pub type AngularVelocity =	Quantity<-1,0,0,0,0,0,0,1,0>;
+	pub type AngularAcceleration =	Quantity<-2,0,0,0,0,0,0,1,0>;
+	pub type ElectricFieldStrength =	Quantity<-3,1,1,-1,0,0,0,0,0>;
+	/// Absorbed or equivalent radiation dose (energy per unit mass)
+	pub type Dose =			Quantity<-2,2,0,0,0,0,0,0,0>;
+	pub type DoseRate =		Quantity<-3,2,0,0,0,0,0,0,0>;
+	pub type Irradiance =	Quantity<-3,0,1,0,0,0,0,0,0>;
+	/// Radiant power per unit area, solid angle and wavelength, e.g. of a black body (W/(m²·sr·m))
+	pub type SpectralRadiance =	Quantity<-3,-1,1,0,0,0,0,-2,0>;
+	/// Radiant energy received per unit area over some period (radiant exposure)
+	pub type Insolation =	Quantity<-2,0,1,0,0,0,0,0,0>;
+	pub type LuminousIntensity =	Quantity<0,0,0,0,0,0,1,0,0>;
+	/// Luminous flux (luminous intensity times solid angle)
+	pub type LuminousFlux =	Quantity<0,0,0,0,0,0,1,2,0>;
+	/// Luminous flux per unit area
+	pub type Illuminance =	Quantity<0,-2,0,0,0,0,1,2,0>;
+	/// Luminous intensity per unit area of a source
+	pub type Luminance =	Quantity<0,-2,0,0,0,0,1,0,0>;
+	/// Illuminance integrated over an exposure time (lux seconds)
+	pub type LuminousExposure =	Quantity<1,-2,0,0,0,0,1,2,0>;
+	/// Luminous flux produced per unit of power
+	pub type LuminousEfficacy =	Quantity<3,-2,-1,0,0,0,1,2,0>;
+	/// Heat flow per unit area.  Same dimension as [Irradiance]
+	pub type HeatFlux =		Irradiance;
+	pub type ThermalConductivity =	Quantity<-3,1,1,0,-1,0,0,0,0>;
+	/// Thermal effusivity √(kρc<sub>p</sub>), which sets the contact temperature between two bodies (W·s<sup>1/2</sup>/(m²·K))
+	pub type ThermalEffusivity =	Quantity<-5,0,2,0,-2,0,0,0,0,2>;
+	/// Thermal conductivity over volumetric heat capacity (k/ρc<sub>p</sub>).  Same dimension as [KinematicViscosity]
+	pub type ThermalDiffusivity =	KinematicViscosity;
+	/// Convective (film) heat transfer coefficient, or overall U-value
+	pub type HeatTransferCoefficient =	Quantity<-3,0,1,0,-1,0,0,0,0>;
+	/// Surface emissivity relative to a black body (0 to 1)
+	pub type Emissivity =	Unitless;
+	/// Power flux density, e.g. of a radiated field.  Same dimension as [Irradiance]
+	pub type PowerFlux =	Irradiance;
+	/// Enthalpy or energy per unit mass.  Same dimension as [Dose]
+	pub type SpecificEnthalpy =	Quantity<-2,2,0,0,0,0,0,0,0>;
+	/// Specific heat capacity, also used for specific gas constants
+	pub type SpecificHeat =	Quantity<-2,2,0,0,-1,0,0,0,0>;
+	/// Heat flow per unit temperature difference, e.g. the UA value of a heat exchanger
+	pub type ThermalConductance =	Quantity<-3,2,1,0,-1,0,0,0,0>;
+	/// Temperature rise per unit heat flow, e.g. junction-to-case resistance of a semiconductor.  The reciprocal of [ThermalConductance]
+	pub type ThermalResistance =	Quantity<3,-2,-1,0,1,0,0,0,0>;
+	/// Heat capacity of a body, the heat needed per unit temperature rise
+	pub type ThermalCapacitance =	Quantity<-2,2,1,0,-1,0,0,0,0>;
+	/// Mass flow rate times specific heat of a fluid stream.  Same dimension as [ThermalConductance]
+	pub type HeatCapacityRate =	ThermalConductance;
+	pub type DynamicViscosity =	Quantity<-1,-1,1,0,0,0,0,0,0>;
+	pub type KinematicViscosity =	Quantity<-1,2,0,0,0,0,0,0,0>;
+
+	/// Amount of `Q` per unit length, e.g. `PerLength<Force>` for a distributed load
+	pub type PerLength<Q> =	<Q as core::ops::Div<Length>>::Output;
+	/// Amount of `Q` per unit area, e.g. `PerArea<Power>` for a heat flux
+	pub type PerArea<Q> =	<Q as core::ops::Div<Area>>::Output;
+	/// Amount of `Q` per unit volume, e.g. `PerVolume<Energy>` for an energy density
+	pub type PerVolume<Q> =	<Q as core::ops::Div<Volume>>::Output;
+	/// Rate of change of `Q`, e.g. `PerTime<Mass>` for a mass flow rate
+	pub type PerTime<Q> =	<Q as core::ops::Div<Time>>::Output;
 }
 
 pub mod consts {
@@ -38,26 +181,39 @@ pub mod consts {
 	use crate::units::*;
 	use crate::dimens::*;
 
-	pub const PLANK_CONSTANT: Quantity<-1,2,1,0,0> = Quantity::from_si(6.62607015e-34);
+	pub const PLANK_CONSTANT: Quantity<-1,2,1,0,0,0,0,0,0> = Quantity::from_si(6.62607015e-34);
+	pub const REDUCED_PLANCK_CONSTANT: Quantity<-1,2,1,0,0,0,0,0,0> = PLANK_CONSTANT/(2.0*crate::float_consts::PI);
 	pub const SPEED_OF_LIGHT: Velocity = 299792458.0 * METER/SECOND;
 	pub const ELEMENTARY_CHARGE: Charge = 1.602176634e-19 * COULOMB;
-	pub const BOLTZMANN_CONSTANT: Quantity<-2,2,1,0,-1> = Quantity::from_si(1.380649e-23);
+	pub const BOLTZMANN_CONSTANT: Quantity<-2,2,1,0,-1,0,0,0,0> = Quantity::from_si(1.380649e-23);
+	/// Avogadro constant (particles per mole)
+	pub const AVOGADRO_CONSTANT: Quantity<0,0,0,0,0,-1,0,0,0> = 6.02214076e23/MOLE;
+	/// Faraday constant (charge per mole of elementary charges)
+	pub const FARADAY_CONSTANT: Quantity<1,0,0,1,0,-1,0,0,0> = ELEMENTARY_CHARGE*AVOGADRO_CONSTANT;
+	/// Molar gas constant, the [BOLTZMANN_CONSTANT] per mole
+	pub const MOLAR_GAS_CONSTANT: Quantity<-2,2,1,0,-1,-1,0,0,0> = BOLTZMANN_CONSTANT*AVOGADRO_CONSTANT;
+	pub const ELECTRON_MASS: Mass = 9.1093837015e-31 * KILO*GRAM;
 	pub const CAESIUM_HYPERFINE: Frequency = 9192631770.0 * HERTZ;
+	/// Luminous efficacy of 540 THz monochromatic light, which defines the candela
+	pub const LUMINOUS_EFFICACY: LuminousEfficacy = 683.0 * LUMEN/WATT;
 
 	pub const STANDARD_GRAVITY: Acceleration =  9.80665 * METER/SECOND/SECOND;
 	pub const STANDARD_ATMOSPHERE: Pressure = 101325.0 * PASCAL;
-	pub const GRAVITIONAL_CONSTANT: Quantity<-2,3,-1,0,0> = Quantity::from_si(6.67430e-11);
+	pub const GRAVITIONAL_CONSTANT: Quantity<-2,3,-1,0,0,0,0,0,0> = Quantity::from_si(6.67430e-11);
+	pub const STEFAN_BOLTZMANN_CONSTANT: Quantity<-3,0,1,0,-4,0,0,0,0> = Quantity::from_si(5.670374419e-8);
 	pub const FINE_STRUCTURE_CONSTANT: Unitless = Unitless::from(0.0072973525643);
 
-	pub const VACUUM_PERMITTIVITY: Quantity<4,-3,-1,2,0> = 0.5*ELEMENTARY_CHARGE*ELEMENTARY_CHARGE/FINE_STRUCTURE_CONSTANT/PLANK_CONSTANT/SPEED_OF_LIGHT;
-	pub const VACUUM_PERMEABILITY: Quantity<-2,1,1,-2,0> = 2.0*FINE_STRUCTURE_CONSTANT*PLANK_CONSTANT/ELEMENTARY_CHARGE/ELEMENTARY_CHARGE/SPEED_OF_LIGHT;
+	pub const VACUUM_PERMITTIVITY: Quantity<4,-3,-1,2,0,0,0,0,0> = 0.5*ELEMENTARY_CHARGE*ELEMENTARY_CHARGE/FINE_STRUCTURE_CONSTANT/PLANK_CONSTANT/SPEED_OF_LIGHT;
+	pub const VACUUM_PERMEABILITY: Quantity<-2,1,1,-2,0,0,0,0,0> = 2.0*FINE_STRUCTURE_CONSTANT*PLANK_CONSTANT/ELEMENTARY_CHARGE/ELEMENTARY_CHARGE/SPEED_OF_LIGHT;
 }
 
 pub mod units {
 	//! Const definitions for many common units  
 	//! SI prefixes are implmented as unitless scaling factors and so can be applied to any linear unit through multiplication (e.g. `KILO*GRAM`, `MICRO*FARAD`)
+	//! Units are [NamedUnit]s carrying their symbol (e.g. `NEWTON.symbol()` is `"N"`), and combine with quantities and each other as plain
+	//! quantities do
 
-	use crate::{LogUnit,OffsetUnit};
+	use crate::{LogUnit,NamedUnit,OffsetUnit,ReciprocalUnit};
 	use crate::consts;
 	use crate::dimens::*;
 
@@ -89,79 +245,281 @@ pub mod units {
 	pub const QUETTA: Unitless = Unitless::from(1.0e30);
 
 	pub const NONE: Unitless = Unitless::from(1.0);
-	pub const DOZEN: Unitless = Unitless::from(12.0);
-	pub const RADIAN: Unitless = Unitless::from(1.0);
-	pub const DEGREE: Unitless = Unitless::from(std::f64::consts::PI/180.0);
-	pub const MOLE: Unitless = Unitless::from(6.02214076e23);
+	pub const PERCENT: NamedUnit<Unitless> = NamedUnit::new(Unitless::from(0.01), "%").with_aliases(&["percent"]);
+	pub const MICROSTRAIN: NamedUnit<Strain> = NamedUnit::new(Strain::from(1.0e-6), "µε");
+	pub const DOZEN: NamedUnit<Unitless> = NamedUnit::new(Unitless::from(12.0), "doz");
+
+	// Angle Units
+	pub const RADIAN: NamedUnit<Angle> = NamedUnit::new(Angle::from_si(1.0), "rad").with_aliases(&["radian", "radians"]);
+	pub const DEGREE: NamedUnit<Angle> = NamedUnit::new(Angle::from_si(crate::float_consts::PI/180.0), "°").with_aliases(&["deg", "degree", "degrees"]);
+	pub const ARCMIN: NamedUnit<Angle> = NamedUnit::new(DEGREE/60.0, "′").with_aliases(&["arcmin"]);
+	pub const ARCSEC: NamedUnit<Angle> = NamedUnit::new(ARCMIN/60.0, "″").with_aliases(&["arcsec"]);
+	pub const TURN: NamedUnit<Angle> = NamedUnit::new(Angle::from_si(crate::float_consts::TAU), "rev").with_plural("revs").with_aliases(&["turn", "turns"]);
+	pub const GRADIAN: NamedUnit<Angle> = NamedUnit::new(TURN/400.0, "gon");
+	/// True milliradian (1/1000 [RADIAN], ~6283 per [TURN]).  For the NATO angular mil used on military sights see [NATO_MIL]
+	pub const MRAD: NamedUnit<Angle> = NamedUnit::new(MILLI*RADIAN, "mrad");
+	/// NATO angular mil, defined as exactly 1/6400 [TURN].  This is close to, but not the same as, a true milliradian ([MRAD])
+	pub const NATO_MIL: NamedUnit<Angle> = NamedUnit::new(TURN/6400.0, "mil");
+	pub const STERADIAN: NamedUnit<SolidAngle> = NamedUnit::new(RADIAN*RADIAN, "sr").with_aliases(&["steradian", "steradians"]);
+	pub const SQUARE_DEGREE: NamedUnit<SolidAngle> = NamedUnit::new(DEGREE*DEGREE, "deg²");
+
+	// Count Units
+	pub const COUNT: NamedUnit<Count> = NamedUnit::new(Count::from_si(1.0), "count").with_plural("counts")
+		.with_aliases(&["item", "items", "event", "events", "cycle", "cycles"]);
+	/// Failures in time, one failure per 10<sup>9</sup> device hours, the usual unit of component failure rates
+	pub const FIT: NamedUnit<FailureRate> = NamedUnit::new(COUNT/(1.0e9*HOUR), "FIT");
 
 	// Time Units
-	pub const SECOND: Time = Time::from_si(1.0);
-	pub const MINUTE: Time = 60.0*SECOND;
-	pub const HOUR: Time = 60.0*MINUTE;
-	pub const DAY: Time = 24.0*HOUR;
-	pub const YEAR: Time = 365.25*DAY;
+	pub const SECOND: NamedUnit<Time> = NamedUnit::new(Time::from_si(1.0), "s").with_aliases(&["sec", "second", "seconds"]);
+	pub const MINUTE: NamedUnit<Time> = NamedUnit::new(60.0*SECOND, "min").with_aliases(&["minute", "minutes"]);
+	pub const HOUR: NamedUnit<Time> = NamedUnit::new(60.0*MINUTE, "h").with_aliases(&["hr", "hour", "hours"]);
+	pub const DAY: NamedUnit<Time> = NamedUnit::new(24.0*HOUR, "d").with_aliases(&["day", "days"]);
+	pub const YEAR: NamedUnit<Time> = NamedUnit::new(365.25*DAY, "yr").with_aliases(&["year", "years"]);
 
-	pub const HERTZ: Frequency = 1.0/SECOND;
+	pub const HERTZ: NamedUnit<Frequency> = NamedUnit::new(1.0/SECOND, "Hz").with_aliases(&["hertz"]);
+	pub const ROOT_HERTZ: NamedUnit<RootFrequency> = NamedUnit::new(RootFrequency::from_si(1.0), "√Hz");
+	/// Revolutions per minute
+	pub const RPM: NamedUnit<AngularVelocity> = NamedUnit::new(TURN/MINUTE, "rpm");
+	/// Musical tempo in beats per minute
+	pub const BPM: NamedUnit<Frequency> = NamedUnit::new(1.0/MINUTE, "bpm");
 
 	// Length Units
-	pub const METER: Length = Length::from_si(1.0);
-	pub const INCH: Length = 2.54*CENTI*METER;
-	pub const FOOT: Length = 12.0*INCH;
-	pub const YARD: Length = 3.0*FOOT;
-	pub const MILE: Length = 5280.0*FOOT;
-	pub const FURLONG: Length = 660.0*FOOT;
+	pub const METER: NamedUnit<Length> = NamedUnit::new(Length::from_si(1.0), "m").with_aliases(&["meter", "metre", "meters", "metres"]);
+	pub const INCH: NamedUnit<Length> = NamedUnit::new(2.54*CENTI*METER, "in").with_aliases(&["inch", "inches"]);
+	pub const FOOT: NamedUnit<Length> = NamedUnit::new(12.0*INCH, "ft").with_aliases(&["foot", "feet"]);
+	pub const YARD: NamedUnit<Length> = NamedUnit::new(3.0*FOOT, "yd").with_aliases(&["yard", "yards"]);
+	pub const MILE: NamedUnit<Length> = NamedUnit::new(5280.0*FOOT, "mi").with_aliases(&["mile", "miles"]);
+	pub const FURLONG: NamedUnit<Length> = NamedUnit::new(660.0*FOOT, "fur");
+	/// International nautical mile, originally one [ARCMIN] of latitude
+	pub const NAUTICAL_MILE: NamedUnit<Length> = NamedUnit::new(1852.0*METER, "nmi");
+
+	// Speed Units
+	/// Nautical miles per hour, used for air and sea speeds
+	pub const KNOT: NamedUnit<Velocity> = NamedUnit::new(NAUTICAL_MILE/HOUR, "kn").with_aliases(&["kt", "knot", "knots"]);
+	pub const KPH: NamedUnit<Velocity> = NamedUnit::new(KILO*METER/HOUR, "km/h").with_aliases(&["kph"]);
+	pub const MPH: NamedUnit<Velocity> = NamedUnit::new(MILE/HOUR, "mph");
+	/// Feet per minute, used for aircraft vertical speeds
+	pub const FPM: NamedUnit<Velocity> = NamedUnit::new(FOOT/MINUTE, "fpm");
 
 	// Area Units
-	pub const ACRE: Area = 66.0*FOOT*FURLONG;
-	pub const HECTARE: Area = 10000.0*METER*METER;
-	pub const BARN: Area = 1e-28*METER*METER;
+	pub const ACRE: NamedUnit<Area> = NamedUnit::new(66.0*FOOT*FURLONG, "ac").with_aliases(&["acre", "acres"]);
+	pub const HECTARE: NamedUnit<Area> = NamedUnit::new(10000.0*METER*METER, "ha");
+	pub const BARN: NamedUnit<Area> = NamedUnit::new(1e-28*METER*METER, "b");
 
 	// Volume Units
-	pub const LITER: Volume = 0.001*METER*METER*METER;
-	pub const US_BUSHEL: Volume = 2150.42*INCH*INCH*INCH;
-	pub const US_GAL: Volume = 231.0*INCH*INCH*INCH;
-	pub const US_QUART: Volume = US_GAL/4.0;
-	pub const US_PINT: Volume = US_QUART/2.0;
-	pub const CUP: Volume = US_PINT/2.0;
-	pub const US_FL_OZ: Volume = CUP/8.0;
-	pub const US_TBSP: Volume = US_FL_OZ/2.0;
-	pub const US_TSP: Volume = US_TBSP/3.0;
+	pub const LITER: NamedUnit<Volume> = NamedUnit::new(0.001*METER*METER*METER, "L").with_aliases(&["l", "liter", "litre", "liters", "litres"]);
+	pub const US_BUSHEL: NamedUnit<Volume> = NamedUnit::new(2150.42*INCH*INCH*INCH, "bu");
+	pub const US_GAL: NamedUnit<Volume> = NamedUnit::new(231.0*INCH*INCH*INCH, "gal");
+	pub const US_QUART: NamedUnit<Volume> = NamedUnit::new(US_GAL/4.0, "qt");
+	pub const US_PINT: NamedUnit<Volume> = NamedUnit::new(US_QUART/2.0, "pt");
+	pub const US_CUP: NamedUnit<Volume> = NamedUnit::new(US_PINT/2.0, "cup").with_plural("cups");
+	/// US customary cup, same as [US_CUP].  For other cooking conventions see [CookingLocale]
+	pub const CUP: NamedUnit<Volume> = US_CUP;
+	pub const US_FL_OZ: NamedUnit<Volume> = NamedUnit::new(US_CUP/8.0, "fl oz");
+	pub const US_TBSP: NamedUnit<Volume> = NamedUnit::new(US_FL_OZ/2.0, "tbsp");
+	pub const US_TSP: NamedUnit<Volume> = NamedUnit::new(US_TBSP/3.0, "tsp");
+	pub const IMP_GAL: NamedUnit<Volume> = NamedUnit::new(4.54609*LITER, "imp gal");
+	pub const IMP_PINT: NamedUnit<Volume> = NamedUnit::new(IMP_GAL/8.0, "imp pt");
+	pub const IMP_FL_OZ: NamedUnit<Volume> = NamedUnit::new(IMP_PINT/20.0, "imp fl oz");
+
+	// Cooking measures, with symbols naming their convention except for the US customary [US_CUP], [US_TBSP] and [US_TSP]
+	pub const METRIC_CUP: NamedUnit<Volume> = NamedUnit::new(250.0*MILLI*LITER, "metric cup").with_plural("metric cups");
+	pub const METRIC_TBSP: NamedUnit<Volume> = NamedUnit::new(15.0*MILLI*LITER, "metric tbsp");
+	pub const METRIC_TSP: NamedUnit<Volume> = NamedUnit::new(5.0*MILLI*LITER, "metric tsp");
+	pub const DESSERTSPOON: NamedUnit<Volume> = NamedUnit::new(2.0*METRIC_TSP, "dsp");
+	/// Australian tablespoon of 20 mL (4 teaspoons rather than the usual 3)
+	pub const AU_TBSP: NamedUnit<Volume> = NamedUnit::new(20.0*MILLI*LITER, "AU tbsp");
+	/// Traditional imperial cup of half an imperial pint
+	pub const UK_CUP: NamedUnit<Volume> = NamedUnit::new(IMP_PINT/2.0, "imp cup").with_plural("imp cups");
+	/// Traditional imperial tablespoon of 5/8 [imperial fluid ounce][IMP_FL_OZ].  Modern UK recipes generally use [METRIC_TBSP]
+	pub const UK_TBSP: NamedUnit<Volume> = NamedUnit::new(0.625*IMP_FL_OZ, "imp tbsp");
+	pub const UK_TSP: NamedUnit<Volume> = NamedUnit::new(UK_TBSP/3.0, "imp tsp");
+
+	/// Regional convention for the size of cooking measures (cups, spoons), since the same name refers to different volumes by locale.
+	/// [parse_unit_with_locale][crate::parse::parse_unit_with_locale] resolves `cup`, `tbsp`, `tsp` and `dsp` in a chosen locale
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	pub enum CookingLocale {
+		/// US customary measures ([US_CUP], [US_TBSP], [US_TSP])
+		Us,
+		/// Metric measures used in Canada, New Zealand and modern UK recipes ([METRIC_CUP], [METRIC_TBSP], [METRIC_TSP])
+		Metric,
+		/// Australian measures ([METRIC_CUP], [AU_TBSP], [METRIC_TSP])
+		Australia,
+		/// Traditional imperial measures found in older British recipes ([UK_CUP], [UK_TBSP], [UK_TSP])
+		UkImperial,
+	}
+	impl CookingLocale {
+		/// Pick the locale profile for a two-letter ISO 3166 region code (e.g. `"US"`, `"AU"`, `"GB"`), or [None] if the region is not recognized.
+		/// Regions that have adopted metric cooking measures (including the UK) map to [CookingLocale::Metric]
+		pub fn from_region(region: &str) -> Option<CookingLocale> {
+			let code = match *region.as_bytes() {
+				[a, b] => [a.to_ascii_uppercase(), b.to_ascii_uppercase()],
+				_ => return None,
+			};
+			match &code {
+				b"US" | b"LR" | b"MM" => Some(CookingLocale::Us),
+				b"AU" => Some(CookingLocale::Australia),
+				b"GB" | b"UK" | b"IE" | b"CA" | b"NZ" | b"ZA" | b"IN" => Some(CookingLocale::Metric),
+				_ => None
+			}
+		}
+		pub const fn cup(self) -> NamedUnit<Volume> {
+			match self {
+				CookingLocale::Us => US_CUP,
+				CookingLocale::Metric | CookingLocale::Australia => METRIC_CUP,
+				CookingLocale::UkImperial => UK_CUP,
+			}
+		}
+		pub const fn tablespoon(self) -> NamedUnit<Volume> {
+			match self {
+				CookingLocale::Us => US_TBSP,
+				CookingLocale::Metric => METRIC_TBSP,
+				CookingLocale::Australia => AU_TBSP,
+				CookingLocale::UkImperial => UK_TBSP,
+			}
+		}
+		pub const fn teaspoon(self) -> NamedUnit<Volume> {
+			match self {
+				CookingLocale::Us => US_TSP,
+				CookingLocale::Metric | CookingLocale::Australia => METRIC_TSP,
+				CookingLocale::UkImperial => UK_TSP,
+			}
+		}
+		/// A dessertspoon is two teaspoons in every locale
+		pub const fn dessertspoon(self) -> NamedUnit<Volume> {
+			match self {
+				CookingLocale::Us => NamedUnit::new(2.0*US_TSP, "US dsp"),
+				CookingLocale::Metric | CookingLocale::Australia => DESSERTSPOON,
+				CookingLocale::UkImperial => NamedUnit::new(2.0*UK_TSP, "imp dsp"),
+			}
+		}
+	}
+
+	// Concentration units
+	pub const MG_PER_DL: NamedUnit<MassConcentration> = NamedUnit::new(MILLI*GRAM/(DECI*LITER), "mg/dL");
+	pub const MOLAR: NamedUnit<Concentration> = NamedUnit::new(MOLE/LITER, "M");
+	pub const MMOL_PER_L: NamedUnit<Concentration> = NamedUnit::new(MILLI*MOLE/LITER, "mmol/L");
 
 	// Mass units
-	pub const GRAM: Mass = Mass::from_si(0.001);
-	pub const POUND_MASS: Mass = 0.45359237*KILO*GRAM;
-	pub const OUNCE_MASS: Mass = POUND_MASS/16.0;
-	pub const SLUG: Mass = POUND_FORCE*SECOND*SECOND/FOOT;
+	pub const GRAM: NamedUnit<Mass> = NamedUnit::new(Mass::from_si(0.001), "g").with_aliases(&["gram", "grams"]);
+	pub const POUND_MASS: NamedUnit<Mass> = NamedUnit::new(0.45359237*KILO*GRAM, "lb").with_aliases(&["lbm", "lbs", "pound", "pounds"]);
+	pub const OUNCE_MASS: NamedUnit<Mass> = NamedUnit::new(POUND_MASS/16.0, "oz");
+	/// Troy/avoirdupois grain, used for bullet and powder weights (1/7000 [POUND_MASS])
+	pub const GRAIN: NamedUnit<Mass> = NamedUnit::new(POUND_MASS/7000.0, "gr");
+	pub const SLUG: NamedUnit<Mass> = NamedUnit::new(POUND_FORCE*SECOND*SECOND/FOOT, "slug");
+
+	// Linear and areal mass density units
+	pub const TEX: NamedUnit<LinearMassDensity> = NamedUnit::new(GRAM/(KILO*METER), "tex");
+	pub const DECITEX: NamedUnit<LinearMassDensity> = NamedUnit::new(DECI*TEX, "dtex");
+	pub const DENIER: NamedUnit<LinearMassDensity> = NamedUnit::new(GRAM/(9.0*KILO*METER), "den");
+	/// Grams per square meter, the usual paper and fabric weight
+	pub const GSM: NamedUnit<ArealDensity> = NamedUnit::new(GRAM/METER/METER, "g/m²").with_aliases(&["gsm"]);
 
 	// Force units
-	pub const NEWTON: Force = KILO*GRAM*METER/SECOND/SECOND;
-	pub const POUNDAL: Force = POUND_MASS*FOOT/SECOND/SECOND;
-	pub const POUND_FORCE: Force = consts::STANDARD_GRAVITY*POUND_MASS;
+	pub const NEWTON: NamedUnit<Force> = NamedUnit::new(KILO*GRAM*METER/SECOND/SECOND, "N").with_aliases(&["newton", "newtons"]);
+	pub const POUNDAL: NamedUnit<Force> = NamedUnit::new(POUND_MASS*FOOT/SECOND/SECOND, "pdl");
+	pub const POUND_FORCE: NamedUnit<Force> = NamedUnit::new(consts::STANDARD_GRAVITY*POUND_MASS, "lbf");
+
+	// Stiffness units
+	pub const LBF_PER_INCH: NamedUnit<SpringConstant> = NamedUnit::new(POUND_FORCE/INCH, "lbf/in");
 
 	// Pressure units
-	pub const PASCAL: Pressure = NEWTON/METER/METER;
-	pub const PSI: Pressure = POUND_FORCE/INCH/INCH;
-	pub const BAR: Pressure = 1e5*PASCAL;
-	pub const TORR: Pressure = consts::STANDARD_ATMOSPHERE/760.0;
+	pub const PASCAL: NamedUnit<Pressure> = NamedUnit::new(NEWTON/METER/METER, "Pa").with_aliases(&["pascal", "pascals"]);
+	pub const PSI: NamedUnit<Pressure> = NamedUnit::new(POUND_FORCE/INCH/INCH, "psi");
+	pub const MEGAPASCAL: NamedUnit<Pressure> = NamedUnit::new(MEGA*PASCAL, "MPa");
+	pub const GIGAPASCAL: NamedUnit<Pressure> = NamedUnit::new(GIGA*PASCAL, "GPa");
+	pub const KSI: NamedUnit<Pressure> = NamedUnit::new(KILO*PSI, "ksi");
+	pub const BAR: NamedUnit<Pressure> = NamedUnit::new(1e5*PASCAL, "bar");
+	pub const TORR: NamedUnit<Pressure> = NamedUnit::new(consts::STANDARD_ATMOSPHERE/760.0, "Torr");
 	const DENSITY_HG: Density = 13595.1 * KILO*GRAM/METER/METER/METER;
-	pub const IN_HG: Pressure = consts::STANDARD_GRAVITY*DENSITY_HG*INCH;
-	pub const MM_HG: Pressure = consts::STANDARD_GRAVITY*DENSITY_HG*MILLI*METER;
+	pub const IN_HG: NamedUnit<Pressure> = NamedUnit::new(consts::STANDARD_GRAVITY*DENSITY_HG*INCH, "inHg");
+	pub const MM_HG: NamedUnit<Pressure> = NamedUnit::new(consts::STANDARD_GRAVITY*DENSITY_HG*MILLI*METER, "mmHg");
+
+	// Viscosity units
+	pub const POISE: NamedUnit<DynamicViscosity> = NamedUnit::new(0.1*PASCAL*SECOND, "P");
+	pub const CENTIPOISE: NamedUnit<DynamicViscosity> = NamedUnit::new(CENTI*POISE, "cP");
+	pub const STOKES: NamedUnit<KinematicViscosity> = NamedUnit::new(CENTI*METER*CENTI*METER/SECOND, "St");
+	pub const CENTISTOKES: NamedUnit<KinematicViscosity> = NamedUnit::new(CENTI*STOKES, "cSt");
 
 	//Energy/power units
-	pub const JOULE: Energy = NEWTON*METER;
-	pub const WATT: Power = JOULE/SECOND;
+	pub const JOULE: NamedUnit<Energy> = NamedUnit::new(NEWTON*METER, "J").with_aliases(&["joule", "joules"]);
+	pub const WATT: NamedUnit<Power> = NamedUnit::new(JOULE/SECOND, "W").with_aliases(&["watt", "watts"]);
+	pub const WATT_HOUR: NamedUnit<Energy> = NamedUnit::new(WATT*HOUR, "Wh");
+	pub const ELECTRONVOLT: NamedUnit<Energy> = NamedUnit::new(consts::ELEMENTARY_CHARGE*VOLT, "eV");
+	/// Energy equivalent of one ton of TNT
+	pub const TON_TNT: NamedUnit<Energy> = NamedUnit::new(4.184e9*JOULE, "t TNT");
+	/// International Table British thermal unit
+	pub const BTU: NamedUnit<Energy> = NamedUnit::new(1055.05585262*JOULE, "BTU").with_aliases(&["Btu"]);
+	pub const FOOT_POUND: NamedUnit<Energy> = NamedUnit::new(FOOT*POUND_FORCE, "ft·lbf");
+
+	// Torque units
+	pub const NEWTON_METER: NamedUnit<Torque> = NamedUnit::new(NEWTON*METER/RADIAN, "N·m");
+	/// Pound-foot, the customary unit of torque (as opposed to the foot-pound of work, [FOOT_POUND])
+	pub const POUND_FOOT: NamedUnit<Torque> = NamedUnit::new(POUND_FORCE*FOOT/RADIAN, "lbf·ft");
 
 	//Electrical Units
-	pub const AMPERE: Current = Current::from_si(1.0);
-	pub const COULOMB: Charge = AMPERE*SECOND;
-	pub const WEBER: MagneticFlux = VOLT*SECOND;
-	pub const VOLT: Voltage = JOULE/COULOMB;
-	pub const OHM: Resistance = VOLT/AMPERE;
-	pub const FARAD: Capacitance = COULOMB/VOLT;
-	pub const HENRY: Inductance = WEBER/AMPERE;
+	pub const AMPERE: NamedUnit<Current> = NamedUnit::new(Current::from_si(1.0), "A").with_aliases(&["ampere", "amperes"]);
+	pub const COULOMB: NamedUnit<Charge> = NamedUnit::new(AMPERE*SECOND, "C").with_aliases(&["coulomb", "coulombs"]);
+	pub const AMPERE_HOUR: NamedUnit<Charge> = NamedUnit::new(AMPERE*HOUR, "Ah");
+	pub const WEBER: NamedUnit<MagneticFlux> = NamedUnit::new(VOLT*SECOND, "Wb");
+	pub const VOLT: NamedUnit<Voltage> = NamedUnit::new(JOULE/COULOMB, "V").with_aliases(&["volt", "volts"]);
+	pub const OHM: NamedUnit<Resistance> = NamedUnit::new(VOLT/AMPERE, "Ω").with_aliases(&["ohm", "ohms", "Ohm"]);
+	pub const FARAD: NamedUnit<Capacitance> = NamedUnit::new(COULOMB/VOLT, "F");
+	pub const HENRY: NamedUnit<Inductance> = NamedUnit::new(WEBER/AMPERE, "H");
+
+	// Radiated field units
+	pub const VOLT_PER_METER: NamedUnit<ElectricFieldStrength> = NamedUnit::new(VOLT/METER, "V/m");
+	pub const WATT_PER_SQUARE_METER: NamedUnit<PowerFlux> = NamedUnit::new(WATT/METER/METER, "W/m²");
+	/// Power flux density in mW/cm², as in RF exposure limits.  Equal to 10 W/m²
+	pub const MILLIWATT_PER_SQUARE_CENTIMETER: NamedUnit<PowerFlux> = NamedUnit::new(MILLI*WATT/(CENTI*METER)/(CENTI*METER), "mW/cm²");
+
+	// Motor constant units
+	/// K<sub>v</sub> in the hobby convention of revolutions per minute per volt
+	pub const RPM_PER_VOLT: NamedUnit<MotorVelocityConstant> = NamedUnit::new(RPM/VOLT, "rpm/V");
+	/// K<sub>v</sub> in the SI convention of radians per second per volt
+	pub const RAD_PER_SECOND_PER_VOLT: NamedUnit<MotorVelocityConstant> = NamedUnit::new(RADIAN/SECOND/VOLT, "rad/(s·V)");
+	pub const NEWTON_METER_PER_AMPERE: NamedUnit<TorqueConstant> = NamedUnit::new(NEWTON_METER/AMPERE, "N·m/A");
+
+	// Solar units
+	/// Daily solar insolation in kWh/m²/day.  Dimensionally this is an average [Irradiance]
+	pub const KWH_PER_M2_PER_DAY: NamedUnit<Irradiance> = NamedUnit::new(KILO*WATT*HOUR/METER/METER/DAY, "kWh/m²/day");
+	/// One peak sun hour: an hour of the 1000 W/m² standard test irradiance
+	pub const PEAK_SUN_HOUR: NamedUnit<Insolation> = NamedUnit::new(KILO*WATT*HOUR/METER/METER, "PSH");
+
+	// Photometric units
+	pub const CANDELA: NamedUnit<LuminousIntensity> = NamedUnit::new(LuminousIntensity::from_si(1.0), "cd").with_aliases(&["candela", "candelas"]);
+	/// Lumen, one candela over one steradian
+	pub const LUMEN: NamedUnit<LuminousFlux> = NamedUnit::new(CANDELA*STERADIAN, "lm").with_aliases(&["lumen", "lumens"]);
+	pub const LUX: NamedUnit<Illuminance> = NamedUnit::new(LUMEN/METER/METER, "lx").with_aliases(&["lux"]);
+	pub const FOOT_CANDLE: NamedUnit<Illuminance> = NamedUnit::new(LUMEN/FOOT/FOOT, "fc");
+	/// Candela per square meter, the usual unit of display luminance
+	pub const NIT: NamedUnit<Luminance> = NamedUnit::new(CANDELA/METER/METER, "nit").with_plural("nits");
+	pub const LUX_SECOND: NamedUnit<LuminousExposure> = NamedUnit::new(LUX*SECOND, "lx·s");
 
-	pub const KELVIN: Temperature = Temperature::from_si(1.0);
-	pub const RANKINE: Temperature = KELVIN/1.8;
+	// Radiation dose units
+	pub const GRAY: NamedUnit<Dose> = NamedUnit::new(JOULE/(KILO*GRAM), "Gy");
+	pub const SIEVERT: NamedUnit<Dose> = NamedUnit::new(JOULE/(KILO*GRAM), "Sv");
+	pub const RAD: NamedUnit<Dose> = NamedUnit::new(CENTI*GRAY, "rad");
+	pub const REM: NamedUnit<Dose> = NamedUnit::new(CENTI*SIEVERT, "rem");
+	pub const USV_PER_HOUR: NamedUnit<DoseRate> = NamedUnit::new(MICRO*SIEVERT/HOUR, "µSv/h");
+	pub const MREM_PER_HOUR: NamedUnit<DoseRate> = NamedUnit::new(MILLI*REM/HOUR, "mrem/h");
+
+	// Activity units
+	pub const BECQUEREL: NamedUnit<Activity> = NamedUnit::new(HERTZ.unit(), "Bq");
+	pub const CURIE: NamedUnit<Activity> = NamedUnit::new(3.7e10*BECQUEREL, "Ci");
+
+	pub const KELVIN: NamedUnit<Temperature> = NamedUnit::new(Temperature::from_si(1.0), "K").with_aliases(&["kelvin", "kelvins"]);
+	pub const RANKINE: NamedUnit<Temperature> = NamedUnit::new(KELVIN/1.8, "°R").with_aliases(&["degR"]);
+
+	// Amount of substance units
+	pub const MOLE: NamedUnit<AmountOfSubstance> = NamedUnit::new(AmountOfSubstance::from_si(1.0), "mol").with_aliases(&["mole", "moles"]);
+	pub const KATAL: NamedUnit<CatalyticActivity> = NamedUnit::new(MOLE/SECOND, "kat");
+	/// Enzyme unit, one micromole of substrate converted per minute
+	pub const ENZYME_UNIT: NamedUnit<CatalyticActivity> = NamedUnit::new(MICRO*MOLE/MINUTE, "U");
+
+	// Thermal network units
+	pub const KELVIN_PER_WATT: NamedUnit<ThermalResistance> = NamedUnit::new(KELVIN/WATT, "K/W");
+	pub const JOULE_PER_KELVIN: NamedUnit<ThermalCapacitance> = NamedUnit::new(JOULE/KELVIN, "J/K");
 
 	// Offset and Log systems
 
@@ -170,8 +528,11 @@ pub mod units {
 		OffsetUnit::new(unit,consts::STANDARD_ATMOSPHERE)
 	}
 
-	pub const CELSIUS: OffsetUnit<Temperature> = OffsetUnit::new(KELVIN,273.15*KELVIN);
-	pub const FAHRENHEIT: OffsetUnit<Temperature> = OffsetUnit::new(RANKINE,CELSIUS.zero_qty()-32.0*RANKINE);
+	pub const CELSIUS: NamedUnit<OffsetUnit<Temperature>> = NamedUnit::new(OffsetUnit::new(KELVIN.unit(),273.15*KELVIN), "°C").with_aliases(&["degC", "celsius"]);
+	pub const FAHRENHEIT: NamedUnit<OffsetUnit<Temperature>> = NamedUnit::new(OffsetUnit::new(RANKINE.unit(),CELSIUS.zero_qty()-32.0*RANKINE), "°F").with_aliases(&["degF", "fahrenheit"]);
+
+	/// Micro reciprocal degree, used for color temperature (1 000 000 / T in kelvin)
+	pub const MIRED: NamedUnit<ReciprocalUnit<Temperature>> = NamedUnit::new(ReciprocalUnit::new(MEGA*KELVIN), "mired");
 	
 	/// Creates a [LogUnit] of decibels relative to the `reference` value using the power convention of 10 dB/decade.
 	pub const fn power_decibels_vs<Dimen: Copy>(reference: Dimen) -> LogUnit<Dimen> {
@@ -181,7 +542,26 @@ pub mod units {
 	pub const fn amplitude_decibels_vs<Dimen: Copy>(reference: Dimen) -> LogUnit<Dimen> {
 		LogUnit::base10(20.0, reference)
 	}
-	pub const DBM: LogUnit<Power> = power_decibels_vs(MILLI*WATT);
-	pub const DECIBEL: LogUnit<Unitless> = power_decibels_vs((1.0).into());
-	pub const SPL: LogUnit<Pressure> = amplitude_decibels_vs(20.0*MICRO*PASCAL);
+	pub const DBM: NamedUnit<LogUnit<Power>> = NamedUnit::new(power_decibels_vs(MILLI*WATT), "dBm");
+	pub const DECIBEL: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(power_decibels_vs((1.0).into()), "dB");
+	pub const SPL: NamedUnit<LogUnit<Pressure>> = NamedUnit::new(amplitude_decibels_vs(20.0*MICRO*PASCAL), "dB SPL");
+	/// Photographic stops, one per factor of two in exposure
+	pub const STOP: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(LogUnit::base2(1.0, Unitless::from(1.0)), "stop").with_plural("stops");
+	/// Musical octave, one per factor of two in frequency
+	pub const OCTAVE: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(LogUnit::base2(1.0, Unitless::from(1.0)), "oct").with_aliases(&["octave", "octaves"]);
+	/// Equal-tempered semitone, twelve per octave
+	pub const SEMITONE: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(LogUnit::base2(12.0, Unitless::from(1.0)), "st").with_aliases(&["semitone", "semitones"]);
+	/// Cent, a hundredth of a semitone, for tuning deviations
+	pub const CENT: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(LogUnit::base2(1200.0, Unitless::from(1.0)), "cent").with_plural("cents");
+	/// MIDI note number of a pitch in equal temperament with A4 = 440 Hz as note 69.  Note 0 is C-1 at 8.18 Hz; see
+	/// [pitch][crate::pitch] for other tunings and note names
+	pub const MIDI_NOTE: NamedUnit<LogUnit<Frequency>> = NamedUnit::new(LogUnit::base2(12.0, Frequency::from_si(8.175798915643707)), "note");
+	/// Photographic exposure value of a scene luminance L at ISO 100, EV = log<sub>2</sub>(L·S/K) with S = 100 and the reflected
+	/// light meter calibration constant K = 12.5 cd·s/m² (ISO 2720), so EV 0 is 0.125 cd/m².  The EV of camera settings N²/t is
+	/// given by [exposure_value][crate::photography::exposure_value]
+	pub const EXPOSURE_VALUE: NamedUnit<LogUnit<Luminance>> = NamedUnit::new(LogUnit::base2(1.0, Luminance::from_si(12.5/100.0)), "EV");
+	/// Decibels relative to an isotropic radiator, for antenna gains
+	pub const DBI: NamedUnit<LogUnit<Unitless>> = NamedUnit::new(DECIBEL.unit(), "dBi");
+	/// Field strength in decibels relative to 1 µV/m, as used in EMC emissions limits
+	pub const DBUV_PER_M: NamedUnit<LogUnit<ElectricFieldStrength>> = NamedUnit::new(amplitude_decibels_vs(MICRO*VOLT/METER), "dBµV/m");
 } 
\ No newline at end of file
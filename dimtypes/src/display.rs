@@ -0,0 +1,212 @@
+//! Display adapters for quantities
+//!
+//! [Quantity]'s own [Display][fmt::Display] shows the value in SI base units.  The adapters here format it for people instead:
+//! [Quantity::auto_prefix] picks the SI prefix which keeps the number between 1 and 1000, as an engineer would write it, and
+//! [Quantity::engineering] writes the value in engineering notation, with an exponent which is always a multiple of 3, e.g.
+//! `123.4e3 m`.  Unlike [LowerExp][fmt::LowerExp] output (`1.234e5 m`) this reads directly as a prefix, and it works for every
+//! dimension, including those with no single unit symbol.  [Quantity::display_as] shows the value in a chosen unit with its symbol,
+//! e.g. `88.5 km/h`.  [Time::human][Quantity::human] shows durations as people read them, e.g. `340 ms` or `1 h 02 min 05 s`.
+
+use std::fmt;
+use crate::{Dimension,Float,Quantity,UnitSymbol};
+use crate::dimens::Time;
+
+/// Prefixes for each power of 1000 from 10<sup>-30</sup> to 10<sup>30</sup>
+const PREFIXES: [&str; 21] = ["q", "r", "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+/// Symbol of a single unit for quantities of `dimension`, and the size of that unit in SI base units.  Mass uses grams so prefixes apply
+/// as usual
+fn unit_symbol(dimension: Dimension) -> Option<(&'static str, Float)> {
+	if let Some(symbol) = dimension.derived_symbol() {
+		return Some((symbol, 1.0));
+	}
+	let base = [
+		(Dimension::new(1,0,0,0,0,0,0,0,0), "s", 1.0),
+		(Dimension::new(0,1,0,0,0,0,0,0,0), "m", 1.0),
+		(Dimension::new(0,0,1,0,0,0,0,0,0), "g", 1e-3),
+		(Dimension::new(0,0,0,1,0,0,0,0,0), "A", 1.0),
+		(Dimension::new(0,0,0,0,1,0,0,0,0), "K", 1.0),
+		(Dimension::new(0,0,0,0,0,1,0,0,0), "mol", 1.0),
+		(Dimension::new(0,0,0,0,0,0,1,0,0), "cd", 1.0),
+		(Dimension::new(0,0,0,0,0,0,0,1,0), "rad", 1.0),
+	];
+	base.iter().find(|(d, _, _)| *d == dimension).map(|(_, symbol, scale)| (*symbol, *scale))
+}
+
+/// `value` divided by 1000 to the power `power`.  Negative powers multiply instead, as 1000<sup>-n</sup> is inexact
+fn scale_thousands(value: Float, power: isize) -> Float {
+	let factor = (1000.0 as Float).powi(power.unsigned_abs() as i32);
+	if power < 0 { value*factor } else { value/factor }
+}
+
+/// Power of 1000 which puts `value` between 1 and 1000 in magnitude once rounded to `precision` decimal places, if given, and `value`
+/// scaled by it.  Zero and non-finite values are not scaled
+fn thousands(value: Float, precision: Option<usize>) -> (Float, isize) {
+	if value == 0.0 || !value.is_finite() {
+		return (value, 0);
+	}
+	let mut power = (value.abs().log10()/3.0).floor() as isize;
+	let mut scaled = scale_thousands(value, power);
+	// Rounding may carry into the next power, e.g. 999.96 to one decimal place
+	if let Some(digits) = precision {
+		let factor = (10.0 as Float).powi(digits as i32);
+		if ((scaled*factor).round()/factor).abs() >= 1000.0 {
+			power += 1;
+			scaled /= 1000.0;
+		}
+	}
+	(scaled, power)
+}
+
+/// Quantity formatted with an automatically chosen SI prefix, from [Quantity::auto_prefix]
+#[derive(Clone, Copy, Debug)]
+pub struct AutoPrefix<Q>(Q);
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Display this quantity with the SI prefix which puts its value between 1 and 1000, e.g. `12 µA` for 0.000012 A or `4.7 MΩ`.
+	/// Quantities whose dimension has no single unit symbol, such as velocities, display in SI base units as usual
+	pub fn auto_prefix(self) -> AutoPrefix<Self> {
+		AutoPrefix(self)
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Display for AutoPrefix<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Some((symbol, scale)) = unit_symbol(Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION) else {
+			return fmt::Display::fmt(&self.0, f);
+		};
+		let (_, power) = thousands(self.0.as_si()/scale, f.precision());
+		let power = power.clamp(-10, 10);
+		let value = scale_thousands(self.0.as_si()/scale, power);
+		let prefix = PREFIXES[(power + 10) as usize];
+		match f.precision() {
+			Some(digits) => write!(f, "{:.*} {}{}", digits, value, prefix, symbol),
+			None => write!(f, "{} {}{}", value, prefix, symbol),
+		}
+	}
+}
+
+/// Quantity formatted in engineering notation, from [Quantity::engineering]
+#[derive(Clone, Copy, Debug)]
+pub struct Engineering<Q>(Q);
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Display this quantity in SI base units in engineering notation, with an exponent which is a multiple of 3 and a value between 1
+	/// and 1000, e.g. `123.4e3 m` or `4.7e-6 kg m^2 s^-2`.  Precision sets the decimal places of the value and the alternate form
+	/// (`{:#}`) names derived units, as for [Quantity]'s own [Display][fmt::Display]
+	pub fn engineering(self) -> Engineering<Self> {
+		Engineering(self)
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Display for Engineering<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (value, power) = thousands(self.0.as_si(), f.precision());
+		match f.precision() {
+			Some(digits) => write!(f, "{:.*}e{}", digits, value, power*3)?,
+			None => write!(f, "{}e{}", value, power*3)?,
+		}
+		let dimension = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		if !dimension.is_dimensionless() {
+			if f.alternate() {
+				write!(f, " {:#}", dimension)?;
+			} else {
+				write!(f, " {}", dimension)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Quantity formatted in a chosen unit, from [Quantity::display_as]
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayAs<Q, U> {
+	quantity: Q,
+	unit: U,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Display this quantity as its value in `unit` followed by the unit's symbol, e.g. `speed.display_as(KPH)` shows
+	/// `88.5 km/h`.  Precision applies to the value, and units with plurals use them, e.g. `3 cups`
+	pub fn display_as<U: UnitSymbol<Dimen = Self>>(self, unit: U) -> DisplayAs<Self, U> {
+		DisplayAs { quantity: self, unit }
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize,
+	U: UnitSymbol<Dimen = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>>
+fmt::Display for DisplayAs<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, U> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let value = self.unit.qty_to_val(self.quantity);
+		match f.precision() {
+			Some(digits) => write!(f, "{:.*}", digits, value)?,
+			None => write!(f, "{}", value)?,
+		}
+		let symbol = self.unit.symbol_for(value);
+		if !symbol.is_empty() {
+			write!(f, " {}", symbol)?;
+		}
+		Ok(())
+	}
+}
+
+/// Duration formatted for people, from [Quantity::human]
+#[derive(Clone, Copy, Debug)]
+pub struct HumanDuration(Time);
+
+impl Time {
+	/// Display this duration as people read them: with an SI prefix and 3 significant digits below a minute (e.g. `340 ms` or
+	/// `12.5 s`), and in days, hours, minutes and seconds from a minute up (e.g. `1 h 02 min 05 s`).  Precision sets the decimal
+	/// places of the seconds
+	pub fn human(self) -> HumanDuration {
+		HumanDuration(self)
+	}
+}
+
+impl fmt::Display for HumanDuration {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let seconds = self.0.as_si();
+		if !seconds.is_finite() {
+			return write!(f, "{} s", seconds);
+		}
+		if seconds < 0.0 {
+			f.write_str("-")?;
+		}
+		let seconds = seconds.abs();
+		// Without a precision, round to 3 significant digits first so the prefix and decimal places suit the rounded value
+		let (shown, short_digits) = match f.precision() {
+			Some(digits) => (seconds, digits),
+			None if seconds == 0.0 => (seconds, 0),
+			None => {
+				let step = (10.0 as Float).powf(seconds.log10().floor() - 2.0);
+				let rounded = (seconds/step).round()*step;
+				let (value, _) = thousands(rounded, None);
+				(rounded, (2 - value.log10().floor() as isize).max(0) as usize)
+			},
+		};
+		// Durations which round up to a minute are shown in minutes
+		if shown < 60.0 - 0.5*(10.0 as Float).powi(-(short_digits as i32)) {
+			return write!(f, "{:.*}", short_digits, Time::from_si(shown).auto_prefix());
+		}
+		let digits = f.precision().unwrap_or(0);
+		// Round before splitting so that e.g. 59.96 s carries into the minutes
+		let factor = (10.0 as Float).powi(digits as i32);
+		let seconds = (seconds*factor).round()/factor;
+		let whole = seconds.trunc() as u64;
+		let (days, hours, minutes) = (whole/86400, whole/3600 % 24, whole/60 % 60);
+		let seconds = seconds - (whole - whole % 60) as Float;
+		let width = if digits == 0 { 2 } else { digits + 3 };
+		if days > 0 {
+			write!(f, "{} d {:02} h {:02} min {:0w$.*} s", days, hours, minutes, digits, seconds, w = width)
+		} else if hours > 0 {
+			write!(f, "{} h {:02} min {:0w$.*} s", hours, minutes, digits, seconds, w = width)
+		} else {
+			write!(f, "{} min {:0w$.*} s", minutes, digits, seconds, w = width)
+		}
+	}
+}
@@ -0,0 +1,79 @@
+//! Torque and speed through gear trains, and the link between wheel rotation and vehicle motion
+//!
+//! A [GearRatio] is input speed over output speed, so a reduction has a ratio above one.  Torque is multiplied by the ratio and by the
+//! mechanical efficiency of the stage, while speed is divided by the ratio.  Rolling without slip covers one radius of distance per
+//! [RADIAN] of wheel rotation, which is how wheel rotation converts to and from vehicle motion.
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::RADIAN;
+
+/// Ratio of a gear pair where the driving gear has `driving_teeth` teeth and the driven gear `driven_teeth`
+pub fn gear_ratio(driving_teeth: u32, driven_teeth: u32) -> GearRatio {
+	GearRatio::from(driven_teeth as Float/driving_teeth as Float)
+}
+
+/// A single reduction stage: a gear pair, belt, chain or final drive
+#[derive(Clone, Copy, Debug)]
+pub struct Stage {
+	pub ratio: GearRatio,
+	/// Fraction of input power delivered to the output (0 to 1)
+	pub efficiency: Unitless,
+}
+
+impl Stage {
+	pub const fn new(ratio: GearRatio, efficiency: Unitless) -> Stage {
+		Stage { ratio, efficiency }
+	}
+	/// A lossless stage
+	pub const fn ideal(ratio: GearRatio) -> Stage {
+		Stage { ratio, efficiency: Unitless::from(1.0) }
+	}
+
+	/// Equivalent single stage of `stages` in series
+	pub fn compound(stages: &[Stage]) -> Stage {
+		stages.iter().fold(Stage::ideal(GearRatio::from(1.0)), |acc, s| Stage::new(acc.ratio*s.ratio, acc.efficiency*s.efficiency))
+	}
+
+	/// Output speed for an input speed `input`
+	pub fn output_speed(&self, input: AngularVelocity) -> AngularVelocity {
+		input/self.ratio
+	}
+	/// Input speed needed for an output speed `output`
+	pub fn input_speed(&self, output: AngularVelocity) -> AngularVelocity {
+		output*self.ratio
+	}
+	/// Output torque for an input torque `input`, after losses
+	pub fn output_torque(&self, input: Torque) -> Torque {
+		input*self.ratio*self.efficiency
+	}
+	/// Input torque needed to deliver an output torque `output`, including losses
+	pub fn input_torque(&self, output: Torque) -> Torque {
+		output/(self.ratio*self.efficiency)
+	}
+}
+
+/// Mechanical power of a shaft carrying `torque` at `speed`
+pub fn shaft_power(torque: Torque, speed: AngularVelocity) -> Power {
+	torque*speed
+}
+
+/// Speed of a vehicle whose wheels of rolling radius `radius` turn at `wheel_speed`, assuming no slip
+pub fn vehicle_speed(wheel_speed: AngularVelocity, radius: Length) -> Velocity {
+	wheel_speed*radius/RADIAN
+}
+
+/// Rotational speed of wheels of rolling radius `radius` on a vehicle moving at `vehicle_speed`, assuming no slip
+pub fn wheel_speed(vehicle_speed: Velocity, radius: Length) -> AngularVelocity {
+	vehicle_speed/radius*RADIAN
+}
+
+/// Tractive force at the contact patch of wheels of rolling radius `radius` driven by `wheel_torque`
+pub fn tractive_force(wheel_torque: Torque, radius: Length) -> Force {
+	wheel_torque*RADIAN/radius
+}
+
+/// Road speed at engine or motor speed `shaft_speed` through `drivetrain` to wheels of rolling radius `radius`
+pub fn road_speed(shaft_speed: AngularVelocity, drivetrain: Stage, radius: Length) -> Velocity {
+	vehicle_speed(drivetrain.output_speed(shaft_speed), radius)
+}
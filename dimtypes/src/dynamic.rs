@@ -0,0 +1,182 @@
+//! Quantities whose dimension is only known at runtime
+//!
+//! A [DynQuantity] carries its [Dimension] alongside its SI value, for user input, scripting and collections of heterogeneous
+//! quantities where the static [Quantity] types cannot be named.  Multiplication and division compose dimensions as the static types
+//! do, while addition, subtraction and comparison check the dimensions match.  Values convert to and from the static types with
+//! [From] and [TryFrom], failing if the dimension differs.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Mul,Div,Neg};
+use std::str::FromStr;
+use crate::{AnyQuantity,Dimension,Float,Quantity};
+use crate::parse::{parse_value,DimensionMismatch,ParsedUnit,UnitParseError};
+
+/// A value in SI base units along with its runtime [Dimension]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynQuantity {
+	value_si: Float,
+	dimension: Dimension,
+}
+
+impl DynQuantity {
+	/// Create a quantity of `dimension` from a numerical value in SI base units
+	pub const fn from_si(value: Float, dimension: Dimension) -> DynQuantity {
+		DynQuantity { value_si: value, dimension }
+	}
+	/// Create a quantity from a numerical `value` in `unit`
+	pub fn from_unit(value: Float, unit: &ParsedUnit) -> DynQuantity {
+		DynQuantity::from_si(unit.to_si(value), unit.dimension())
+	}
+	/// Create a dimensionless quantity
+	pub const fn unitless(value: Float) -> DynQuantity {
+		DynQuantity::from_si(value, Dimension::NONE)
+	}
+
+	pub const fn as_si(&self) -> Float { self.value_si }
+	pub const fn dimension(&self) -> Dimension { self.dimension }
+	pub const fn is_dimensionless(&self) -> bool { self.dimension.is_dimensionless() }
+
+	/// Numerical value in `unit`, failing if its dimension differs
+	pub fn value_in(&self, unit: &ParsedUnit) -> Result<Float, DimensionMismatch> {
+		self.check(unit.dimension())?;
+		Ok(unit.from_si(self.value_si))
+	}
+
+	/// Convert to the static quantity type `Q`, failing if its dimension differs.  Same as [TryFrom] for [Quantity] types, but usable
+	/// with any [AnyQuantity] bound
+	pub fn to_static<Q: AnyQuantity>(self) -> Result<Q, DimensionMismatch> {
+		self.check(Q::DIMENSION)?;
+		Ok(Q::from_si(self.value_si))
+	}
+
+	fn check(&self, expected: Dimension) -> Result<(), DimensionMismatch> {
+		if self.dimension == expected {
+			Ok(())
+		} else {
+			Err(DimensionMismatch { expected, found: self.dimension })
+		}
+	}
+
+	/// Sum of `self` and `rhs`, failing if their dimensions differ
+	pub fn checked_add(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionMismatch> {
+		rhs.check(self.dimension)?;
+		Ok(DynQuantity::from_si(self.value_si + rhs.value_si, self.dimension))
+	}
+	/// Difference of `self` and `rhs`, failing if their dimensions differ
+	pub fn checked_sub(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionMismatch> {
+		rhs.check(self.dimension)?;
+		Ok(DynQuantity::from_si(self.value_si - rhs.value_si, self.dimension))
+	}
+	/// Compare `self` and `rhs`, failing if their dimensions differ.  The result is [None] if either value is NaN
+	pub fn checked_cmp(&self, rhs: &DynQuantity) -> Result<Option<Ordering>, DimensionMismatch> {
+		rhs.check(self.dimension)?;
+		Ok(self.value_si.partial_cmp(&rhs.value_si))
+	}
+
+	/// Raise `self` to an integer power `p`.  [None] if the powers of the dimension overflow
+	pub fn powi(self, p: isize) -> Option<DynQuantity> {
+		let dimension = self.dimension.checked_powi(p)?;
+		Some(DynQuantity::from_si(self.value_si.powf(p as Float), dimension))
+	}
+	/// Take the `r`th root of `self`.  [None] if `r` is zero or the powers of the dimension overflow
+	pub fn root(self, r: isize) -> Option<DynQuantity> {
+		let dimension = self.dimension.checked_root(r)?;
+		Some(DynQuantity::from_si(self.value_si.powf(1.0/r as Float), dimension))
+	}
+	pub fn abs(self) -> DynQuantity {
+		DynQuantity::from_si(self.value_si.abs(), self.dimension)
+	}
+}
+
+/// Parses a number followed by a unit as in [parse_value], e.g. `9.81 m/s^2` or `20 degC`
+impl FromStr for DynQuantity {
+	type Err = UnitParseError;
+	fn from_str(s: &str) -> Result<DynQuantity, UnitParseError> {
+		let (value, unit) = parse_value(s)?;
+		Ok(DynQuantity::from_unit(value, &unit))
+	}
+}
+
+/// Formats in SI base units, the same as [Quantity]
+impl fmt::Display for DynQuantity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match f.precision() {
+			Some(digits) => write!(f, "{:.*}", digits, self.value_si)?,
+			None => write!(f, "{}", self.value_si)?,
+		}
+		if !self.dimension.is_dimensionless() {
+			write!(f, " {}", self.dimension)?;
+		}
+		Ok(())
+	}
+}
+
+/// Values of different dimensions are unordered
+impl PartialOrd for DynQuantity {
+	fn partial_cmp(&self, rhs: &DynQuantity) -> Option<Ordering> {
+		self.checked_cmp(rhs).ok().flatten()
+	}
+}
+
+impl Mul for DynQuantity {
+	type Output = DynQuantity;
+	fn mul(self, rhs: DynQuantity) -> DynQuantity { DynQuantity::from_si(self.value_si*rhs.value_si, self.dimension*rhs.dimension) }
+}
+impl Div for DynQuantity {
+	type Output = DynQuantity;
+	fn div(self, rhs: DynQuantity) -> DynQuantity { DynQuantity::from_si(self.value_si/rhs.value_si, self.dimension/rhs.dimension) }
+}
+impl Mul<Float> for DynQuantity {
+	type Output = DynQuantity;
+	fn mul(self, rhs: Float) -> DynQuantity { DynQuantity::from_si(self.value_si*rhs, self.dimension) }
+}
+impl Div<Float> for DynQuantity {
+	type Output = DynQuantity;
+	fn div(self, rhs: Float) -> DynQuantity { DynQuantity::from_si(self.value_si/rhs, self.dimension) }
+}
+impl Mul<DynQuantity> for Float {
+	type Output = DynQuantity;
+	fn mul(self, rhs: DynQuantity) -> DynQuantity { rhs*self }
+}
+impl Div<DynQuantity> for Float {
+	type Output = DynQuantity;
+	fn div(self, rhs: DynQuantity) -> DynQuantity { DynQuantity::unitless(self)/rhs }
+}
+impl Neg for DynQuantity {
+	type Output = DynQuantity;
+	fn neg(self) -> DynQuantity { DynQuantity::from_si(-self.value_si, self.dimension) }
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+From<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> for DynQuantity {
+	fn from(qty: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> DynQuantity {
+		DynQuantity::from_si(qty.as_si(), qty.dimension())
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+TryFrom<DynQuantity> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Error = DimensionMismatch;
+	fn try_from(qty: DynQuantity) -> Result<Self, DimensionMismatch> {
+		qty.to_static()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn powers_and_roots() {
+		let area: DynQuantity = "9 m^2".parse().unwrap();
+		let side = area.root(2).unwrap();
+		assert_eq!(side.as_si(), 3.0);
+		assert_eq!(side.dimension(), Dimension::new(0, 1, 0, 0, 0, 0, 0, 0, 0));
+		assert_eq!(side.powi(3).unwrap().dimension(), Dimension::new(0, 3, 0, 0, 0, 0, 0, 0, 0));
+		assert_eq!(area.powi(-1).unwrap().as_si(), 1.0/9.0);
+		assert_eq!(area.root(0), None);
+		assert_eq!(area.powi(isize::MAX), None);
+		assert_eq!(side.root(isize::MIN), None);
+	}
+}
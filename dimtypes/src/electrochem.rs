@@ -0,0 +1,36 @@
+//! Electrochemistry and battery helpers based on the [Faraday constant][FARADAY_CONSTANT]
+
+use crate::Float;
+use crate::consts::FARADAY_CONSTANT;
+use crate::dimens::*;
+use crate::units::HOUR;
+
+/// Charge transferred by reacting `amount` of a species with `z` electrons per formula unit (Q = n·z·F)
+pub fn charge_of_moles(amount: AmountOfSubstance, z: i32) -> Charge {
+	amount*(z as Float)*FARADAY_CONSTANT
+}
+
+/// Amount of a species with `z` electrons per formula unit reacted by passing `charge` (n = Q/(z·F))
+pub fn moles_of_charge(charge: Charge, z: i32) -> AmountOfSubstance {
+	charge/(z as Float)/FARADAY_CONSTANT
+}
+
+/// Battery charge or discharge rate expressed as a multiple of capacity per hour.
+/// A 1C rate fully charges or discharges the cell in one hour, 0.5C in two hours, and so on.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct CRate(pub Float);
+
+impl CRate {
+	/// The C-rate corresponding to drawing `current` from a cell of the given `capacity`
+	pub fn of(current: Current, capacity: Charge) -> CRate {
+		CRate((current*HOUR/capacity).into())
+	}
+	/// The current drawn at this rate from a cell of the given `capacity`
+	pub fn current(self, capacity: Charge) -> Current {
+		self.0*capacity/HOUR
+	}
+	/// Nominal time to fully charge or discharge at this rate
+	pub fn duration(self) -> Time {
+		HOUR/self.0
+	}
+}
@@ -0,0 +1,219 @@
+//! Conversion between raw converter codes and typed quantities for firmware
+//!
+//! An [Adc] or [Dac] describes a converter by its resolution and reference voltage, mapping codes to [Voltage]s and back.  A
+//! [AdcSensor] adds the transfer function of the sensor or signal conditioning in front of an ADC (e.g. a shunt and amplifier, or
+//! an analog temperature sensor), so a raw reading yields a [Current] or [Temperature] directly, and [AdcSensor::code] goes the
+//! other way for setting comparator thresholds.  [Pwm] converts between an average output voltage and a duty cycle.
+//!
+//! With the `embedded-hal` feature, [Pwm::set] drives any [SetDutyCycle][embedded_hal::pwm::SetDutyCycle] output.  embedded-hal 1.0
+//! has no ADC trait, so readings are passed in as raw codes from whichever HAL the firmware uses.
+
+use core::marker::PhantomData;
+use crate::{AnyQuantity,Float};
+#[cfg(not(feature = "std"))]
+use crate::real::Real;
+use crate::dimens::*;
+use crate::units::VOLT;
+
+/// Resolution and reference shared by [Adc] and [Dac]
+#[derive(Clone, Copy, Debug)]
+struct Converter {
+	bits: u32,
+	reference: Voltage,
+}
+
+impl Converter {
+	const fn new(bits: u32, reference: Voltage) -> Converter {
+		assert!(bits >= 1 && bits <= 32, "converter resolution must be between 1 and 32 bits");
+		Converter { bits, reference }
+	}
+
+	const fn max_code(&self) -> u32 {
+		u32::MAX >> (32 - self.bits)
+	}
+	fn lsb(&self) -> Voltage {
+		self.reference/(1u64 << self.bits) as Float
+	}
+	fn voltage(&self, code: u32) -> Voltage {
+		self.lsb()*code as Float
+	}
+	/// Code nearest to `voltage`, clamped to the converter's range
+	fn code(&self, voltage: Voltage) -> u32 {
+		let steps: Float = (voltage/self.lsb()).into();
+		steps.round().clamp(0.0, self.max_code() as Float) as u32
+	}
+}
+
+/// Analog-to-digital converter with `bits` of resolution, whose full scale is its reference voltage
+#[derive(Clone, Copy, Debug)]
+pub struct Adc(Converter);
+
+impl Adc {
+	/// Panics unless `bits` is between 1 and 32, the width of the codes
+	pub const fn new(bits: u32, reference: Voltage) -> Adc {
+		Adc(Converter::new(bits, reference))
+	}
+
+	/// Largest code the converter produces
+	pub const fn max_code(&self) -> u32 {
+		self.0.max_code()
+	}
+	/// Voltage of one code step
+	pub fn lsb(&self) -> Voltage {
+		self.0.lsb()
+	}
+
+	/// Input voltage corresponding to `code`
+	pub fn voltage(&self, code: u32) -> Voltage {
+		self.0.voltage(code)
+	}
+	/// Code the converter would read for `voltage`, rounded to the nearest step and clamped to the converter's range
+	pub fn code(&self, voltage: Voltage) -> u32 {
+		self.0.code(voltage)
+	}
+}
+
+/// Digital-to-analog converter with `bits` of resolution, whose full scale is its reference voltage
+#[derive(Clone, Copy, Debug)]
+pub struct Dac(Converter);
+
+impl Dac {
+	/// Panics unless `bits` is between 1 and 32, the width of the codes
+	pub const fn new(bits: u32, reference: Voltage) -> Dac {
+		Dac(Converter::new(bits, reference))
+	}
+
+	/// Largest code the converter accepts
+	pub const fn max_code(&self) -> u32 {
+		self.0.max_code()
+	}
+	/// Voltage of one code step
+	pub fn lsb(&self) -> Voltage {
+		self.0.lsb()
+	}
+
+	/// Code producing the output closest to `voltage`, clamped to the converter's range
+	pub fn code(&self, voltage: Voltage) -> u32 {
+		self.0.code(voltage)
+	}
+	/// Output voltage produced by `code`
+	pub fn voltage(&self, code: u32) -> Voltage {
+		self.0.voltage(code)
+	}
+}
+
+/// Sensor producing a voltage linear in a quantity of type `Q`, read through an [Adc]
+#[derive(Clone, Copy, Debug)]
+pub struct AdcSensor<Q> {
+	adc: Adc,
+	/// Voltage at the ADC input when the quantity is zero
+	zero: Voltage,
+	/// Change in voltage per unit change of the quantity in SI base units, in volts
+	sensitivity: Float,
+	kind: PhantomData<Q>,
+}
+
+impl<Q: AnyQuantity> AdcSensor<Q> {
+	/// Sensor calibrated by two points, reading voltage `v0` at `q0` and `v1` at `q1`.  For sensors specified by an offset and a
+	/// sensitivity, such as a TMP36 (0.75 V at 25 °C, 10 mV/K), pick any two points on the line
+	pub fn two_point(adc: Adc, (v0, q0): (Voltage, Q), (v1, q1): (Voltage, Q)) -> AdcSensor<Q> {
+		let sensitivity = (v1 - v0).as_si()/(q1.as_si() - q0.as_si());
+		AdcSensor { adc, zero: v0 - VOLT*sensitivity*q0.as_si(), sensitivity, kind: PhantomData }
+	}
+
+	/// Quantity measured when the ADC reads `code`
+	pub fn read(&self, code: u32) -> Q {
+		self.quantity(self.adc.voltage(code))
+	}
+	/// Quantity corresponding to a voltage at the ADC input
+	pub fn quantity(&self, voltage: Voltage) -> Q {
+		Q::from_si((voltage - self.zero).as_si()/self.sensitivity)
+	}
+	/// Code the ADC reads at `value`, e.g. for setting a hardware comparator or watchdog threshold
+	pub fn code(&self, value: Q) -> u32 {
+		self.adc.code(self.zero + VOLT*self.sensitivity*value.as_si())
+	}
+	/// Smallest change in the quantity the ADC resolves
+	pub fn resolution(&self) -> Q {
+		Q::from_si(self.adc.lsb().as_si()/self.sensitivity.abs())
+	}
+}
+
+impl AdcSensor<Current> {
+	/// Current through a `shunt` resistor measured by an amplifier of voltage `gain`, whose output sits at `bias` at zero current
+	/// (half the reference for bidirectional sensing, zero otherwise)
+	pub fn shunt(adc: Adc, shunt: Resistance, gain: Float, bias: Voltage) -> AdcSensor<Current> {
+		AdcSensor { adc, zero: bias, sensitivity: (shunt*gain).as_si(), kind: PhantomData }
+	}
+}
+
+impl AdcSensor<Voltage> {
+	/// Voltage measured through a divider of `top` and `bottom` resistors into the ADC
+	pub fn divider(adc: Adc, top: Resistance, bottom: Resistance) -> AdcSensor<Voltage> {
+		let ratio: Float = (bottom/(top + bottom)).into();
+		AdcSensor { adc, zero: 0.0*VOLT, sensitivity: ratio, kind: PhantomData }
+	}
+}
+
+/// PWM output switching between zero and a `supply` voltage, whose average voltage is set by its duty cycle
+#[derive(Clone, Copy, Debug)]
+pub struct Pwm {
+	supply: Voltage,
+}
+
+impl Pwm {
+	pub const fn new(supply: Voltage) -> Pwm {
+		Pwm { supply }
+	}
+
+	/// Duty cycle between 0 and 1 giving an average output of `voltage`, clamped to the achievable range
+	pub fn duty_fraction(&self, voltage: Voltage) -> Float {
+		let fraction: Float = (voltage/self.supply).into();
+		fraction.clamp(0.0, 1.0)
+	}
+	/// Duty cycle out of `max_duty` giving an average output of `voltage`, rounded to the nearest step and clamped to the achievable range
+	pub fn duty(&self, voltage: Voltage, max_duty: u16) -> u16 {
+		(self.duty_fraction(voltage)*max_duty as Float).round() as u16
+	}
+	/// Average output voltage at `duty` out of `max_duty`
+	pub fn voltage(&self, duty: u16, max_duty: u16) -> Voltage {
+		self.supply*(duty as Float/max_duty as Float)
+	}
+
+	/// Set the duty cycle of `pin` to give an average output of `voltage`
+	#[cfg(feature = "embedded-hal")]
+	pub fn set<P: embedded_hal::pwm::SetDutyCycle>(&self, pin: &mut P, voltage: Voltage) -> Result<(), P::Error> {
+		let duty = self.duty(voltage, pin.max_duty_cycle());
+		pin.set_duty_cycle(duty)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn converter_codes() {
+		let adc = Adc::new(12, 3.3*VOLT);
+		assert_eq!(adc.max_code(), 4095);
+		assert_eq!(adc.code(1.65*VOLT), 2048);
+		assert_eq!(adc.code(5.0*VOLT), 4095);
+		assert_eq!(adc.code(-1.0*VOLT), 0);
+		let dac = Dac::new(32, 1.0*VOLT);
+		assert_eq!(dac.max_code(), u32::MAX);
+		assert_eq!(dac.code(2.0*VOLT), u32::MAX);
+		assert_eq!(Dac::new(1, 1.0*VOLT).max_code(), 1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn too_many_bits() {
+		Adc::new(64, 3.3*VOLT);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_bits() {
+		Dac::new(0, 3.3*VOLT);
+	}
+}
@@ -0,0 +1,285 @@
+//! Unit codes of the Excel `CONVERT()` function
+//!
+//! Spreadsheets name units with the case-sensitive codes of `CONVERT()`, which differ from the symbols [parse_unit] reads in places:
+//! `C` and `F` are degrees Celsius and Fahrenheit rather than coulombs and farads, `Nmi` is a nautical mile, `mn` a minute and `ozm` an
+//! ounce of mass, while `oz` is a fluid ounce.  [excel_unit] resolves a code, with an Excel prefix where `CONVERT()` allows one (`km`,
+//! `kPa`, `cm2`, ...), to a [ParsedUnit], and [excel_code] finds the code for a unit so values written for Excel users convert back to
+//! the same unit.  Information units (`bit`, `byte`) have no counterpart in this crate and are not recognized.
+
+use crate::{AnyQuantity,Float};
+use crate::consts;
+use crate::parse::{ParsedUnit,QuantityParseError,UnitParseError,DimensionMismatch};
+use crate::units::*;
+
+struct Code {
+	code: &'static str,
+	unit: ParsedUnit,
+	/// Power the prefix is raised to, e.g. 2 for `m2`, or 0 if `CONVERT()` takes no prefix
+	prefix_power: i32,
+}
+
+macro_rules! codes {
+	(@power) => { 0 };
+	(@power $power:literal) => { $power };
+	($($code:literal => $unit:expr $(, prefix $power:literal)?;)*) => {
+		&[$(Code { code: $code, unit: ParsedUnit::new($unit.as_si(), $unit.dimension()), prefix_power: codes!(@power $($power)?) },)*]
+	};
+}
+
+/// Prefixes `CONVERT()` accepts, in the order tried when looking up a code for a unit
+const PREFIXES: &[(&str, Float)] = &[
+	("Y", 1e24), ("Z", 1e21), ("E", 1e18), ("P", 1e15), ("T", 1e12), ("G", 1e9), ("M", 1e6), ("k", 1e3), ("h", 1e2), ("da", 1e1),
+	("e", 1e1), ("d", 1e-1), ("c", 1e-2), ("m", 1e-3), ("u", 1e-6), ("n", 1e-9), ("p", 1e-12), ("f", 1e-15), ("a", 1e-18),
+	("z", 1e-21), ("y", 1e-24),
+];
+
+const LIGHT_YEAR: Float = 9.4607304725808e15;
+const PICA_POINT: Float = 0.0254/72.0;
+
+/// Codes of linear units.  The first code listed for a unit is the one [excel_code] returns
+const CODES: &[Code] = codes! {
+	// Weight and mass
+	"g" => GRAM, prefix 1;
+	"sg" => SLUG;
+	"lbm" => POUND_MASS;
+	"u" => 1.66053906660e-27*KILO*GRAM, prefix 1;
+	"ozm" => OUNCE_MASS;
+	"grain" => GRAIN;
+	"cwt" => 100.0*POUND_MASS;
+	"shweight" => 100.0*POUND_MASS;
+	"uk_cwt" => 112.0*POUND_MASS;
+	"lcwt" => 112.0*POUND_MASS;
+	"hweight" => 112.0*POUND_MASS;
+	"stone" => 14.0*POUND_MASS;
+	"ton" => 2000.0*POUND_MASS;
+	"uk_ton" => 2240.0*POUND_MASS;
+	"LTON" => 2240.0*POUND_MASS;
+	"brton" => 2240.0*POUND_MASS;
+
+	// Distance
+	"m" => METER, prefix 1;
+	"mi" => MILE;
+	"Nmi" => NAUTICAL_MILE;
+	"in" => INCH;
+	"ft" => FOOT;
+	"yd" => YARD;
+	"ang" => 1.0e-10*METER, prefix 1;
+	"ell" => 45.0*INCH;
+	"ly" => LIGHT_YEAR*METER, prefix 1;
+	"parsec" => 3.0856775814914e16*METER, prefix 1;
+	"pc" => 3.0856775814914e16*METER, prefix 1;
+	"Picapt" => PICA_POINT*METER;
+	"Pica" => PICA_POINT*METER;
+	"pica" => 12.0*PICA_POINT*METER;
+	"survey_mi" => (6336000.0/3937.0)*METER;
+
+	// Time
+	"yr" => YEAR;
+	"day" => DAY;
+	"d" => DAY;
+	"hr" => HOUR;
+	"mn" => MINUTE;
+	"min" => MINUTE;
+	"s" => SECOND, prefix 1;
+	"sec" => SECOND, prefix 1;
+
+	// Pressure
+	"Pa" => PASCAL, prefix 1;
+	"p" => PASCAL, prefix 1;
+	"atm" => consts::STANDARD_ATMOSPHERE, prefix 1;
+	"at" => consts::STANDARD_ATMOSPHERE, prefix 1;
+	"mmHg" => MM_HG, prefix 1;
+	"psi" => PSI;
+	"Torr" => TORR;
+
+	// Force
+	"N" => NEWTON, prefix 1;
+	"dyn" => 1.0e-5*NEWTON, prefix 1;
+	"dy" => 1.0e-5*NEWTON, prefix 1;
+	"lbf" => POUND_FORCE;
+	"pond" => 9.80665e-3*NEWTON, prefix 1;
+
+	// Energy
+	"J" => JOULE, prefix 1;
+	"e" => 1.0e-7*JOULE, prefix 1;
+	"c" => 4.184*JOULE, prefix 1;
+	"cal" => 4.1868*JOULE, prefix 1;
+	"eV" => ELECTRONVOLT, prefix 1;
+	"ev" => ELECTRONVOLT, prefix 1;
+	"HPh" => 550.0*FOOT_POUND/SECOND*HOUR;
+	"hh" => 550.0*FOOT_POUND/SECOND*HOUR;
+	"Wh" => WATT_HOUR, prefix 1;
+	"wh" => WATT_HOUR, prefix 1;
+	"flb" => FOOT_POUND;
+	"BTU" => BTU;
+	"btu" => BTU;
+
+	// Power
+	"HP" => 550.0*FOOT_POUND/SECOND;
+	"h" => 550.0*FOOT_POUND/SECOND;
+	"PS" => 735.49875*WATT;
+	"W" => WATT, prefix 1;
+	"w" => WATT, prefix 1;
+
+	// Magnetism
+	"T" => WEBER/(METER*METER), prefix 1;
+	"ga" => 1.0e-4*WEBER/(METER*METER), prefix 1;
+
+	// Temperature, besides the offset scales below
+	"K" => KELVIN, prefix 1;
+	"kel" => KELVIN, prefix 1;
+	"Rank" => RANKINE;
+
+	// Volume
+	"tsp" => US_TSP;
+	"tspm" => 5.0*MILLI*LITER;
+	"tbs" => US_TBSP;
+	"oz" => US_FL_OZ;
+	"cup" => US_CUP;
+	"pt" => US_PINT;
+	"us_pt" => US_PINT;
+	"uk_pt" => IMP_PINT;
+	"qt" => US_QUART;
+	"uk_qt" => 2.0*IMP_PINT;
+	"gal" => US_GAL;
+	"uk_gal" => IMP_GAL;
+	"l" => LITER, prefix 1;
+	"L" => LITER, prefix 1;
+	"lt" => LITER, prefix 1;
+	"ang3" => 1.0e-30*METER*METER*METER, prefix 3;
+	"ang^3" => 1.0e-30*METER*METER*METER, prefix 3;
+	"barrel" => 42.0*US_GAL;
+	"bushel" => US_BUSHEL;
+	"ft3" => FOOT*FOOT*FOOT;
+	"ft^3" => FOOT*FOOT*FOOT;
+	"in3" => INCH*INCH*INCH;
+	"in^3" => INCH*INCH*INCH;
+	"ly3" => LIGHT_YEAR*LIGHT_YEAR*LIGHT_YEAR*METER*METER*METER;
+	"ly^3" => LIGHT_YEAR*LIGHT_YEAR*LIGHT_YEAR*METER*METER*METER;
+	"m3" => METER*METER*METER, prefix 3;
+	"m^3" => METER*METER*METER, prefix 3;
+	"mi3" => MILE*MILE*MILE;
+	"mi^3" => MILE*MILE*MILE;
+	"yd3" => YARD*YARD*YARD;
+	"yd^3" => YARD*YARD*YARD;
+	"Nmi3" => NAUTICAL_MILE*NAUTICAL_MILE*NAUTICAL_MILE;
+	"Nmi^3" => NAUTICAL_MILE*NAUTICAL_MILE*NAUTICAL_MILE;
+	"Pica3" => PICA_POINT*PICA_POINT*PICA_POINT*METER*METER*METER;
+	"Pica^3" => PICA_POINT*PICA_POINT*PICA_POINT*METER*METER*METER;
+	"Picapt3" => PICA_POINT*PICA_POINT*PICA_POINT*METER*METER*METER;
+	"Picapt^3" => PICA_POINT*PICA_POINT*PICA_POINT*METER*METER*METER;
+	"GRT" => 100.0*FOOT*FOOT*FOOT;
+	"regton" => 100.0*FOOT*FOOT*FOOT;
+	"MTON" => 40.0*FOOT*FOOT*FOOT;
+
+	// Area
+	"uk_acre" => ACRE;
+	"us_acre" => (6336000.0/3937.0)*(6336000.0/3937.0)/640.0*METER*METER;
+	"ang2" => 1.0e-20*METER*METER, prefix 2;
+	"ang^2" => 1.0e-20*METER*METER, prefix 2;
+	"ar" => 100.0*METER*METER, prefix 1;
+	"ft2" => FOOT*FOOT;
+	"ft^2" => FOOT*FOOT;
+	"ha" => HECTARE;
+	"in2" => INCH*INCH;
+	"in^2" => INCH*INCH;
+	"ly2" => LIGHT_YEAR*LIGHT_YEAR*METER*METER;
+	"ly^2" => LIGHT_YEAR*LIGHT_YEAR*METER*METER;
+	"m2" => METER*METER, prefix 2;
+	"m^2" => METER*METER, prefix 2;
+	"Morgen" => 2500.0*METER*METER;
+	"mi2" => MILE*MILE;
+	"mi^2" => MILE*MILE;
+	"Nmi2" => NAUTICAL_MILE*NAUTICAL_MILE;
+	"Nmi^2" => NAUTICAL_MILE*NAUTICAL_MILE;
+	"Pica2" => PICA_POINT*PICA_POINT*METER*METER;
+	"Pica^2" => PICA_POINT*PICA_POINT*METER*METER;
+	"Picapt2" => PICA_POINT*PICA_POINT*METER*METER;
+	"Picapt^2" => PICA_POINT*PICA_POINT*METER*METER;
+	"yd2" => YARD*YARD;
+	"yd^2" => YARD*YARD;
+
+	// Speed
+	"admkn" => 6080.0*FOOT/HOUR;
+	"kn" => KNOT;
+	"m/h" => METER/HOUR, prefix 1;
+	"m/hr" => METER/HOUR, prefix 1;
+	"m/s" => METER/SECOND, prefix 1;
+	"m/sec" => METER/SECOND, prefix 1;
+	"mph" => MPH;
+};
+
+/// Offset temperature scales, which take no prefix
+const OFFSET_CODES: &[(&str, Float, Float)] = &[
+	("C", KELVIN.as_si(), CELSIUS.zero_qty().as_si()),
+	("cel", KELVIN.as_si(), CELSIUS.zero_qty().as_si()),
+	("F", RANKINE.as_si(), FAHRENHEIT.zero_qty().as_si()),
+	("fah", RANKINE.as_si(), FAHRENHEIT.zero_qty().as_si()),
+	("Reau", 1.25, 273.15),
+];
+
+/// Look up an Excel `CONVERT()` unit code such as `mi`, `Nmi`, `kPa` or `F`
+pub fn excel_unit(code: &str) -> Result<ParsedUnit, UnitParseError> {
+	if let Some(&(_, scale, offset)) = OFFSET_CODES.iter().find(|(c,_,_)| *c == code) {
+		return Ok(ParsedUnit::with_offset(scale, offset, KELVIN.dimension()));
+	}
+	if let Some(entry) = CODES.iter().find(|e| e.code == code) {
+		return Ok(entry.unit);
+	}
+	PREFIXES.iter()
+		.filter_map(|(prefix, factor)| code.strip_prefix(prefix).map(|rest| (rest, *factor)))
+		.find_map(|(rest, factor)| {
+			CODES.iter()
+				.find(|e| e.prefix_power != 0 && e.code == rest)
+				.map(|e| ParsedUnit::new(factor.powi(e.prefix_power)*e.unit.scale(), e.unit.dimension()))
+		})
+		.ok_or_else(|| UnitParseError::UnknownSymbol(code.to_string()))
+}
+
+fn same_unit(a: &ParsedUnit, b: &ParsedUnit) -> bool {
+	const TOLERANCE: Float = 1e-9;
+	a.dimension() == b.dimension()
+		&& (a.scale() - b.scale()).abs() <= TOLERANCE*b.scale().abs()
+		&& (a.offset() - b.offset()).abs() <= TOLERANCE*b.scale().abs().max(b.offset().abs())
+}
+
+/// Excel `CONVERT()` code for `unit`, e.g. `Nmi` for the unit parsed from `nmi` or `kPa` for 1000 Pa, or `None` if Excel has no
+/// code for it.  Unprefixed codes are preferred, so a thousandth of a cubic meter is `l` rather than `dm3`, then the smallest prefix
+pub fn excel_code(unit: &ParsedUnit) -> Option<String> {
+	if let Some((code,_,_)) = OFFSET_CODES.iter()
+		.find(|&&(_, scale, offset)| same_unit(unit, &ParsedUnit::with_offset(scale, offset, KELVIN.dimension())))
+	{
+		return Some(code.to_string());
+	}
+	if let Some(entry) = CODES.iter().find(|e| same_unit(unit, &e.unit)) {
+		return Some(entry.code.to_string());
+	}
+	// Of the prefixed codes matching, take the one with the smallest prefix, e.g. `km3` rather than `Tl`
+	PREFIXES.iter()
+		.flat_map(|(prefix, factor)| CODES.iter()
+			.filter(|e| e.prefix_power != 0)
+			.map(move |e| (*prefix, factor.powi(e.prefix_power), e)))
+		.filter(|(_, factor, e)| same_unit(unit, &ParsedUnit::new(factor*e.unit.scale(), e.unit.dimension())))
+		.min_by(|(_, a, _), (_, b, _)| a.log10().abs().total_cmp(&b.log10().abs()))
+		.map(|(prefix, _, e)| format!("{}{}", prefix, e.code))
+}
+
+/// Quantity from a value in the unit of an Excel code, e.g. a spreadsheet column headed `mph`, failing if the code is unknown or of
+/// the wrong dimension
+pub fn from_excel<Q: AnyQuantity>(value: Float, code: &str) -> Result<Q, QuantityParseError> {
+	let unit = excel_unit(code)?;
+	if unit.dimension() != Q::DIMENSION {
+		return Err(DimensionMismatch { expected: Q::DIMENSION, found: unit.dimension() }.into());
+	}
+	Ok(Q::from_si(unit.to_si(value)))
+}
+
+/// Value of `qty` in the unit of an Excel code, for writing a spreadsheet cell, failing if the code is unknown or of the wrong
+/// dimension
+pub fn to_excel<Q: AnyQuantity>(qty: Q, code: &str) -> Result<Float, QuantityParseError> {
+	let unit = excel_unit(code)?;
+	if unit.dimension() != Q::DIMENSION {
+		return Err(DimensionMismatch { expected: Q::DIMENSION, found: unit.dimension() }.into());
+	}
+	Ok(unit.from_si(qty.as_si()))
+}
@@ -0,0 +1,117 @@
+//! Heat exchanger rating by the effectiveness-NTU and log mean temperature difference methods
+
+use crate::Float;
+use crate::dimens::*;
+
+/// Flow arrangement of a heat exchanger, which determines its effectiveness for a given NTU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arrangement {
+	CounterFlow,
+	ParallelFlow,
+	/// Single pass cross flow with both streams unmixed (correlation accurate to a few percent)
+	CrossFlowUnmixed,
+	/// One shell pass with any even number of tube passes
+	ShellAndTube,
+}
+
+/// A fluid stream entering a heat exchanger
+#[derive(Clone, Copy, Debug)]
+pub struct Stream {
+	pub mass_flow: MassFlowRate,
+	pub specific_heat: SpecificHeat,
+	pub inlet: Temperature,
+}
+
+impl Stream {
+	pub const fn new(mass_flow: MassFlowRate, specific_heat: SpecificHeat, inlet: Temperature) -> Stream {
+		Stream { mass_flow, specific_heat, inlet }
+	}
+	/// Heat capacity rate ṁ·c<sub>p</sub> of the stream
+	pub fn capacity_rate(&self) -> HeatCapacityRate {
+		self.mass_flow*self.specific_heat
+	}
+}
+
+/// Result of rating a heat exchanger
+#[derive(Clone, Copy, Debug)]
+pub struct Performance {
+	/// Heat transferred from the hot to the cold stream
+	pub duty: Power,
+	pub hot_outlet: Temperature,
+	pub cold_outlet: Temperature,
+	pub effectiveness: Unitless,
+	pub ntu: Unitless,
+}
+
+/// Number of transfer units UA/C<sub>min</sub>
+pub fn ntu(ua: ThermalConductance, c_min: HeatCapacityRate) -> Unitless {
+	ua/c_min
+}
+
+/// Effectiveness of an exchanger with the given `arrangement`, number of transfer units `ntu` and capacity ratio C<sub>min</sub>/C<sub>max</sub>
+/// `cr`.  For `cr` of zero (a condensing or boiling stream) all arrangements reduce to 1 - e<sup>-NTU</sup>
+pub fn effectiveness(arrangement: Arrangement, ntu: Unitless, cr: Unitless) -> Unitless {
+	let n = Float::from(ntu);
+	let c = Float::from(cr);
+	let e = if c == 0.0 {
+		1.0 - Float::exp(-n)
+	} else {
+		match arrangement {
+			Arrangement::CounterFlow if (1.0 - c).abs() < 1e-9 => n/(1.0 + n),
+			Arrangement::CounterFlow => {
+				let x = Float::exp(-n*(1.0 - c));
+				(1.0 - x)/(1.0 - c*x)
+			},
+			Arrangement::ParallelFlow => (1.0 - Float::exp(-n*(1.0 + c)))/(1.0 + c),
+			Arrangement::CrossFlowUnmixed => 1.0 - Float::exp(n.powf(0.22)/c*(Float::exp(-c*n.powf(0.78)) - 1.0)),
+			Arrangement::ShellAndTube => {
+				let r = Float::sqrt(1.0 + c*c);
+				let x = Float::exp(-n*r);
+				2.0/(1.0 + c + r*(1.0 + x)/(1.0 - x))
+			},
+		}
+	};
+	Unitless::from(e)
+}
+
+/// Rate an exchanger of overall heat transfer coefficient `u` and area `area` between the `hot` and `cold` streams by the ε-NTU method
+pub fn rate(arrangement: Arrangement, hot: Stream, cold: Stream, u: HeatTransferCoefficient, area: Area) -> Performance {
+	let c_hot = hot.capacity_rate();
+	let c_cold = cold.capacity_rate();
+	let (c_min, c_max) = if c_hot.as_si() < c_cold.as_si() { (c_hot, c_cold) } else { (c_cold, c_hot) };
+	let ntu = ntu(u*area, c_min);
+	let effectiveness = effectiveness(arrangement, ntu, c_min/c_max);
+	let duty = effectiveness*c_min*(hot.inlet - cold.inlet);
+	Performance {
+		duty,
+		hot_outlet: hot.inlet - duty/c_hot,
+		cold_outlet: cold.inlet + duty/c_cold,
+		effectiveness,
+		ntu,
+	}
+}
+
+/// Log mean temperature difference between the streams of a counter-flow (`counter_flow == true`) or parallel-flow exchanger
+pub fn lmtd(hot_in: Temperature, hot_out: Temperature, cold_in: Temperature, cold_out: Temperature, counter_flow: bool) -> Temperature {
+	let (dt1, dt2) = if counter_flow {
+		(hot_in - cold_out, hot_out - cold_in)
+	} else {
+		(hot_in - cold_in, hot_out - cold_out)
+	};
+	let ratio = Float::from(dt1/dt2);
+	if (ratio - 1.0).abs() < 1e-9 {
+		dt1
+	} else {
+		(dt1 - dt2)/ratio.ln()
+	}
+}
+
+/// Duty U·A·F·LMTD of an exchanger with LMTD correction factor `correction` (1 for pure counter or parallel flow)
+pub fn lmtd_duty(u: HeatTransferCoefficient, area: Area, lmtd: Temperature, correction: Unitless) -> Power {
+	correction*u*area*lmtd
+}
+
+/// Area needed to transfer `duty` with overall coefficient `u` across the temperature difference `lmtd`, with correction factor `correction`
+pub fn required_area(duty: Power, u: HeatTransferCoefficient, lmtd: Temperature, correction: Unitless) -> Area {
+	duty/(correction*u*lmtd)
+}
@@ -0,0 +1,147 @@
+//! Thermophysical fluid properties behind a common typed interface
+//!
+//! Thermal-hydraulics code is written against the [FluidProperties] trait, evaluated at a temperature and pressure.  [IdealGas] provides
+//! a self-contained implementation, and with the `coolprop` feature [CoolPropFluid] evaluates real-fluid properties (steam tables,
+//! refrigerants, ...) through the CoolProp library.
+
+use std::fmt;
+use crate::Float;
+use crate::dimens::*;
+use crate::units::*;
+
+/// Error evaluating a fluid property, such as a state outside the range of a property model
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyError(pub String);
+
+impl fmt::Display for PropertyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "fluid property evaluation failed: {}", self.0)
+	}
+}
+impl std::error::Error for PropertyError {}
+
+/// Properties of a fluid as functions of temperature and pressure
+pub trait FluidProperties {
+	fn density(&self, temperature: Temperature, pressure: Pressure) -> Result<Density, PropertyError>;
+	/// Specific enthalpy, relative to a reference state chosen by the implementation
+	fn specific_enthalpy(&self, temperature: Temperature, pressure: Pressure) -> Result<SpecificEnthalpy, PropertyError>;
+	/// Isobaric specific heat capacity
+	fn specific_heat(&self, temperature: Temperature, pressure: Pressure) -> Result<SpecificHeat, PropertyError>;
+	fn dynamic_viscosity(&self, temperature: Temperature, pressure: Pressure) -> Result<DynamicViscosity, PropertyError>;
+
+	/// Kinematic viscosity, the dynamic viscosity divided by density
+	fn kinematic_viscosity(&self, temperature: Temperature, pressure: Pressure) -> Result<KinematicViscosity, PropertyError> {
+		Ok(self.dynamic_viscosity(temperature, pressure)?/self.density(temperature, pressure)?)
+	}
+}
+
+/// Viscosity model of an [IdealGas]
+#[derive(Clone, Copy, Debug)]
+pub enum Viscosity {
+	Constant(DynamicViscosity),
+	/// Sutherland's law, μ = μ<sub>0</sub> (T/T<sub>0</sub>)<sup>3/2</sup> (T<sub>0</sub>+S)/(T+S)
+	Sutherland { reference: DynamicViscosity, reference_temperature: Temperature, constant: Temperature },
+}
+
+/// Calorically perfect ideal gas, with density from p = ρ R T and enthalpy c<sub>p</sub> T relative to 0 K
+#[derive(Clone, Copy, Debug)]
+pub struct IdealGas {
+	pub gas_constant: SpecificHeat,
+	pub specific_heat: SpecificHeat,
+	pub viscosity: Viscosity,
+}
+
+impl IdealGas {
+	/// Dry air near room temperature
+	pub const AIR: IdealGas = IdealGas {
+		gas_constant: SpecificHeat::from_si(287.05),
+		specific_heat: SpecificHeat::from_si(1005.0),
+		viscosity: Viscosity::Sutherland {
+			reference: DynamicViscosity::from_si(1.716e-5),
+			reference_temperature: KELVIN*273.15,
+			constant: KELVIN*110.4,
+		},
+	};
+
+	pub const fn new(gas_constant: SpecificHeat, specific_heat: SpecificHeat, viscosity: Viscosity) -> IdealGas {
+		IdealGas { gas_constant, specific_heat, viscosity }
+	}
+
+	/// Ratio of specific heats c<sub>p</sub>/c<sub>v</sub>
+	pub fn heat_capacity_ratio(&self) -> Unitless {
+		self.specific_heat/(self.specific_heat - self.gas_constant)
+	}
+}
+
+impl FluidProperties for IdealGas {
+	fn density(&self, temperature: Temperature, pressure: Pressure) -> Result<Density, PropertyError> {
+		Ok(pressure/(self.gas_constant*temperature))
+	}
+	fn specific_enthalpy(&self, temperature: Temperature, _pressure: Pressure) -> Result<SpecificEnthalpy, PropertyError> {
+		Ok(self.specific_heat*temperature)
+	}
+	fn specific_heat(&self, _temperature: Temperature, _pressure: Pressure) -> Result<SpecificHeat, PropertyError> {
+		Ok(self.specific_heat)
+	}
+	fn dynamic_viscosity(&self, temperature: Temperature, _pressure: Pressure) -> Result<DynamicViscosity, PropertyError> {
+		Ok(match self.viscosity {
+			Viscosity::Constant(mu) => mu,
+			Viscosity::Sutherland { reference, reference_temperature, constant } => {
+				let ratio = Float::from(temperature/reference_temperature);
+				reference * ratio.powf(1.5) * Float::from((reference_temperature + constant)/(temperature + constant))
+			},
+		})
+	}
+}
+
+#[cfg(feature = "coolprop")]
+pub use self::coolprop::CoolPropFluid;
+/// Substances available to [CoolPropFluid], re-exported from `rfluids`
+#[cfg(feature = "coolprop")]
+pub use rfluids::substance;
+
+#[cfg(feature = "coolprop")]
+mod coolprop {
+	use rfluids::prelude::*;
+	use super::{FluidProperties,PropertyError};
+	use crate::{Float,Quantity};
+	use crate::dimens::*;
+
+	/// A real fluid evaluated through CoolProp, enabled with the `coolprop` feature
+	#[derive(Clone, Debug)]
+	pub struct CoolPropFluid {
+		substance: Substance,
+	}
+
+	impl CoolPropFluid {
+		pub fn new(substance: impl Into<Substance>) -> CoolPropFluid {
+			CoolPropFluid { substance: substance.into() }
+		}
+
+		// CoolProp works in f64 whatever the crate's Float type
+		#[allow(clippy::unnecessary_cast)]
+		fn eval(&self, temperature: Temperature, pressure: Pressure,
+			output: impl FnOnce(&mut Fluid) -> Result<f64, rfluids::fluid::FluidOutputError>) -> Result<Float, PropertyError> {
+			let error = |e: &dyn std::fmt::Display| PropertyError(e.to_string());
+			let fluid = Fluid::try_from(self.substance.clone()).map_err(|e| error(&e))?;
+			let mut state = fluid.in_state(FluidInput::pressure(pressure.as_si() as f64), FluidInput::temperature(temperature.as_si() as f64))
+				.map_err(|e| error(&e))?;
+			output(&mut state).map(|v| v as Float).map_err(|e| error(&e))
+		}
+	}
+
+	impl FluidProperties for CoolPropFluid {
+		fn density(&self, temperature: Temperature, pressure: Pressure) -> Result<Density, PropertyError> {
+			self.eval(temperature, pressure, |f| f.density()).map(Quantity::from_si)
+		}
+		fn specific_enthalpy(&self, temperature: Temperature, pressure: Pressure) -> Result<SpecificEnthalpy, PropertyError> {
+			self.eval(temperature, pressure, |f| f.enthalpy()).map(Quantity::from_si)
+		}
+		fn specific_heat(&self, temperature: Temperature, pressure: Pressure) -> Result<SpecificHeat, PropertyError> {
+			self.eval(temperature, pressure, |f| f.specific_heat()).map(Quantity::from_si)
+		}
+		fn dynamic_viscosity(&self, temperature: Temperature, pressure: Pressure) -> Result<DynamicViscosity, PropertyError> {
+			self.eval(temperature, pressure, |f| f.dynamic_viscosity()).map(Quantity::from_si)
+		}
+	}
+}
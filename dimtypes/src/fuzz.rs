@@ -0,0 +1,170 @@
+//! Property-based checks that formulas over typed quantities are dimensionally sound at runtime
+//!
+//! The type system catches dimension errors in expressions, but not code which escapes it through raw values, e.g.
+//! `Length::from_si(x.as_si() + 1.0)` or a bare `9.81` standing in for a gravitational acceleration.  [Fuzzer] evaluates a formula
+//! on random inputs and checks scale covariance, which such leaks break: rescaling the unit of one base dimension (as if the meter
+//! were twice as long) scales every input and the result by the power of that factor in their dimension.  Factors are powers of two,
+//! so a sound formula matches exactly unless it involves fractional powers.
+//!
+//! Constructing the inputs from different but equivalent units (feet rather than meters) is not checked, as quantities store their
+//! value in SI base units whatever unit they were constructed from, so the formula sees the same values up to rounding.
+//!
+//! Inputs are drawn inside the formula closure with [Inputs::input], in the same style as [MonteCarlo][crate::uncertainty::MonteCarlo]
+//! (e.g. `|f| f.input(1.0*METER, 1e3*METER)/f.input(1.0*SECOND, 60.0*SECOND)`).  Dimensioned constants the formula depends on must be
+//! passed through [Inputs::constant] so they are rescaled along with the inputs.
+
+use std::fmt;
+use crate::{AnyQuantity,Dimension,Float};
+use crate::uncertainty::Rng;
+
+/// Property of a formula checked by [Fuzzer]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Property {
+	/// Rescaling the unit of the named base dimension did not scale the result by its dimension
+	Scale(&'static str),
+}
+
+/// A case where a formula failed a [Property]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+	pub property: Property,
+	/// Inputs of the failing case in SI base units, in the order they were drawn
+	pub inputs: Vec<(Float, Dimension)>,
+	/// Result expected from the unperturbed case, in SI base units
+	pub expected: Float,
+	/// Result of the perturbed case, in SI base units
+	pub found: Float,
+}
+
+impl fmt::Display for Violation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.property {
+			Property::Scale(base) => write!(f, "result does not scale with the unit of {}", base)?,
+		}
+		write!(f, ": expected {}, found {} (inputs:", self.expected, self.found)?;
+		for (value, dimension) in &self.inputs {
+			write!(f, " [{} {}]", value, dimension)?;
+		}
+		write!(f, ")")
+	}
+}
+
+impl std::error::Error for Violation {}
+
+const BASES: [&str; 9] = ["time", "length", "mass", "current", "temperature", "amount of substance", "luminous intensity", "angle", "count"];
+
+fn power(dimension: Dimension, base: usize) -> Float {
+	dimension.powers()[base]
+}
+
+#[derive(Clone, Copy)]
+enum Perturbation {
+	None,
+	/// Multiply the unit of base dimension `base` by `factor`
+	Scale { base: usize, factor: Float },
+}
+
+/// Source of inputs handed to the formula closure by [Fuzzer::check]
+pub struct Inputs<'a> {
+	rng: &'a mut Rng,
+	values: &'a mut Vec<(Float, Dimension)>,
+	next: usize,
+	perturbation: Perturbation,
+}
+
+impl Inputs<'_> {
+	/// Draw an input between `low` and `high`, spread evenly over orders of magnitude when both have the same sign
+	pub fn input<Q: AnyQuantity>(&mut self, low: Q, high: Q) -> Q {
+		let (low, high) = (low.as_si(), high.as_si());
+		let u = self.rng.next_f64();
+		let value = if low*high > 0.0 {
+			low.signum()*Float::exp(low.abs().ln() + u*(high.abs().ln() - low.abs().ln()))
+		} else {
+			low + u*(high - low)
+		};
+		Q::from_si(self.take(value, Q::DIMENSION))
+	}
+
+	/// Pass a dimensioned constant used by the formula, so it is rescaled along with the inputs
+	pub fn constant<Q: AnyQuantity>(&mut self, value: Q) -> Q {
+		Q::from_si(self.take(value.as_si(), Q::DIMENSION))
+	}
+
+	/// The recorded value of the next input (recording `value` if this is the first evaluation), with the current perturbation applied
+	fn take(&mut self, value: Float, dimension: Dimension) -> Float {
+		if self.next == self.values.len() {
+			self.values.push((value, dimension));
+		}
+		let (value, dimension) = self.values[self.next];
+		self.next += 1;
+		match self.perturbation {
+			Perturbation::None => value,
+			Perturbation::Scale { base, factor } => value*factor.powf(power(dimension, base)),
+		}
+	}
+}
+
+/// Runs a formula over random inputs and checks it for scale covariance
+#[derive(Clone, Copy, Debug)]
+pub struct Fuzzer {
+	cases: usize,
+	seed: u64,
+	tolerance: Float,
+}
+
+impl Fuzzer {
+	/// Create a fuzzer which checks `cases` random sets of inputs
+	pub fn new(cases: usize) -> Fuzzer {
+		Fuzzer { cases, seed: 0xf022, tolerance: Float::EPSILON.sqrt() }
+	}
+
+	/// Use a specific random `seed` (results are reproducible for a given seed)
+	pub fn seed(self, seed: u64) -> Fuzzer {
+		Fuzzer { seed, ..self }
+	}
+
+	/// Relative difference between results tolerated as rounding.  Defaults to the square root of the [Float] epsilon
+	pub fn tolerance(self, tolerance: Float) -> Fuzzer {
+		Fuzzer { tolerance, ..self }
+	}
+
+	/// Check `formula`, returning the first [Violation] found.  Cases where the formula does not produce a finite result are skipped
+	pub fn check<Q: AnyQuantity>(&self, mut formula: impl FnMut(&mut Inputs) -> Q) -> Result<(), Violation> {
+		let mut rng = Rng::new(self.seed);
+		let mut values = Vec::new();
+		for _ in 0..self.cases {
+			values.clear();
+			let mut run = |rng: &mut Rng, values: &mut Vec<(Float, Dimension)>, perturbation| {
+				formula(&mut Inputs { rng, values, next: 0, perturbation }).as_si()
+			};
+			let expected = run(&mut rng, &mut values, Perturbation::None);
+			if !expected.is_finite() {
+				continue;
+			}
+			let violation = |property, expected: Float, found: Float, values: &Vec<(Float, Dimension)>| {
+				let close = (found - expected).abs() <= self.tolerance*expected.abs().max(found.abs());
+				(!close).then(|| Violation { property, inputs: values.clone(), expected, found })
+			};
+			for (base, name) in BASES.iter().enumerate() {
+				if power(Q::DIMENSION, base) == 0.0 && values.iter().all(|&(_, d)| power(d, base) == 0.0) {
+					continue;
+				}
+				let exponent = (rng.next_u64() % 8) as i32 + 1;
+				let factor = Float::powi(2.0, if rng.next_u64().is_multiple_of(2) { exponent } else { -exponent });
+				let found = run(&mut rng, &mut values, Perturbation::Scale { base, factor });
+				let expected = expected*factor.powf(power(Q::DIMENSION, base));
+				if let Some(v) = violation(Property::Scale(name), expected, found, &values) {
+					return Err(v);
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Check `formula` as in [Fuzzer::check], panicking with a description of the first [Violation].  Intended for use in tests
+	pub fn assert<Q: AnyQuantity>(&self, formula: impl FnMut(&mut Inputs) -> Q) {
+		if let Err(violation) = self.check(formula) {
+			panic!("{}", violation);
+		}
+	}
+}
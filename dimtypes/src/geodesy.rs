@@ -0,0 +1,109 @@
+//! Positions and velocities on the Earth for navigation code
+//!
+//! GPS receivers report positions as latitude and longitude and velocities as ground speed and track, while aviation works in knots,
+//! feet and feet per minute ([KNOT][crate::units::KNOT], [FPM][crate::units::FPM]) and physics code in SI.  Keeping every value typed
+//! lets these mix freely.  Local position deltas use the radii of curvature of the WGS 84 ellipsoid at the reference latitude, which
+//! is accurate to well under a meter over the few kilometers a local frame is used for.  Great-circle distances use a sphere of the
+//! mean Earth radius, which is accurate to about 0.5%.
+
+use crate::Float;
+use crate::float_consts::PI;
+use crate::dimens::*;
+use crate::math::{atan2,sin,cos,tan,asin};
+use crate::units::{METER,RADIAN};
+
+/// Semi-major axis of the WGS 84 ellipsoid
+pub const WGS84_SEMI_MAJOR_AXIS: Length = 6378137.0*METER;
+/// Flattening of the WGS 84 ellipsoid
+pub const WGS84_FLATTENING: Unitless = Unitless::from(1.0/298.257223563);
+/// IUGG mean radius of the Earth
+pub const EARTH_MEAN_RADIUS: Length = 6371008.8*METER;
+
+/// Geodetic position, with altitude above the ellipsoid
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+	pub latitude: Angle,
+	pub longitude: Angle,
+	pub altitude: Length,
+}
+
+impl Position {
+	pub fn new(latitude: Angle, longitude: Angle, altitude: Length) -> Position {
+		Position { latitude, longitude, altitude }
+	}
+}
+
+/// 1 - e² sin²φ, common to the radii of curvature at latitude φ, and e²
+fn curvature_terms(latitude: Angle) -> (Float, Float) {
+	let f: Float = WGS84_FLATTENING.into();
+	let e2 = f*(2.0 - f);
+	let s: Float = sin(latitude).into();
+	(1.0 - e2*s*s, e2)
+}
+
+/// Radius of curvature of the WGS 84 ellipsoid in the north-south direction at `latitude`
+pub fn meridional_radius(latitude: Angle) -> Length {
+	let (w, e2) = curvature_terms(latitude);
+	WGS84_SEMI_MAJOR_AXIS*(1.0 - e2)/w.powf(1.5)
+}
+
+/// Radius of curvature of the WGS 84 ellipsoid in the east-west direction at `latitude`
+pub fn prime_vertical_radius(latitude: Angle) -> Length {
+	let (w, _) = curvature_terms(latitude);
+	WGS84_SEMI_MAJOR_AXIS/w.sqrt()
+}
+
+/// North and east distances from `origin` to `position` in a local tangent plane at `origin`
+pub fn local_offset(origin: Position, position: Position) -> (Length, Length) {
+	let north = (meridional_radius(origin.latitude) + origin.altitude)*((position.latitude - origin.latitude)/RADIAN);
+	let mut dlon = (position.longitude - origin.longitude).as_si();
+	dlon = (dlon + PI).rem_euclid(2.0*PI) - PI;
+	let east = (prime_vertical_radius(origin.latitude) + origin.altitude)*cos(origin.latitude)*dlon;
+	(north, east)
+}
+
+/// Position `north` and `east` of `origin` in a local tangent plane at `origin`, at the same altitude.  The inverse of [local_offset]
+pub fn offset_position(origin: Position, north: Length, east: Length) -> Position {
+	let latitude = origin.latitude + RADIAN*(north/(meridional_radius(origin.latitude) + origin.altitude));
+	let longitude = origin.longitude + RADIAN*(east/((prime_vertical_radius(origin.latitude) + origin.altitude)*cos(origin.latitude)));
+	Position { latitude, longitude, altitude: origin.altitude }
+}
+
+/// Great-circle distance between `from` and `to` over a sphere of [EARTH_MEAN_RADIUS], by the haversine formula
+pub fn great_circle_distance(from: Position, to: Position) -> Length {
+	let half_dlat = sin(0.5*(to.latitude - from.latitude));
+	let half_dlon = sin(0.5*(to.longitude - from.longitude));
+	let h: Float = (half_dlat*half_dlat + cos(from.latitude)*cos(to.latitude)*half_dlon*half_dlon).into();
+	2.0*EARTH_MEAN_RADIUS*(asin(h.sqrt().into())/RADIAN)
+}
+
+/// Initial bearing of the great circle from `from` to `to`, clockwise from true north
+pub fn initial_bearing(from: Position, to: Position) -> Angle {
+	let dlon = to.longitude - from.longitude;
+	let y = sin(dlon)*cos(to.latitude);
+	let x = cos(from.latitude)*sin(to.latitude) - sin(from.latitude)*cos(to.latitude)*cos(dlon);
+	let bearing = atan2(y, x);
+	if bearing.as_si() < 0.0 { bearing + 2.0*PI*RADIAN } else { bearing }
+}
+
+/// North and east components of a ground velocity of `ground_speed` along `track` (clockwise from true north)
+pub fn velocity_components(ground_speed: Velocity, track: Angle) -> (Velocity, Velocity) {
+	(ground_speed*cos(track), ground_speed*sin(track))
+}
+
+/// Ground speed and track (clockwise from true north, between 0 and 360°) of a velocity with `north` and `east` components
+pub fn ground_track(north: Velocity, east: Velocity) -> (Velocity, Angle) {
+	let speed = (north*north + east*east).root::<2>();
+	let track = atan2(east, north);
+	(speed, if track.as_si() < 0.0 { track + 2.0*PI*RADIAN } else { track })
+}
+
+/// Flight path angle above the horizontal when climbing at `vertical_speed` with `ground_speed`, e.g. 3° for a 700 fpm descent at 130 kt
+pub fn flight_path_angle(ground_speed: Velocity, vertical_speed: Velocity) -> Angle {
+	atan2(vertical_speed, ground_speed)
+}
+
+/// Vertical speed needed to follow a flight path angle of `angle` at `ground_speed`
+pub fn vertical_speed(ground_speed: Velocity, angle: Angle) -> Velocity {
+	ground_speed*tan(angle)
+}
@@ -0,0 +1,129 @@
+//! Simple solids and plane shapes with typed dimensions
+//!
+//! The shapes compute their areas and volumes and, given a mass or density, the mass moments of inertia of the corresponding uniform
+//! bodies.  Moments of inertia are in kg·m² per radian² ([MomentOfInertia]), so they combine with angular velocities and accelerations
+//! to give energies and torques.  Section properties of beams are in [structures][crate::structures].
+
+use crate::float_consts::PI;
+use crate::dimens::*;
+use crate::structures::{circle_second_moment,rectangle_second_moment};
+use crate::units::RADIAN;
+
+/// Moment of inertia of a point `mass` at `radius` from the axis, m r²
+pub fn point_mass_inertia(mass: Mass, radius: Length) -> MomentOfInertia {
+	mass*radius*radius/RADIAN/RADIAN
+}
+
+/// Moment of inertia about an axis `offset` from, and parallel to, an axis through the center of mass about which the body of `mass` has
+/// moment of inertia `centroidal` (parallel axis theorem)
+pub fn parallel_axis(centroidal: MomentOfInertia, mass: Mass, offset: Length) -> MomentOfInertia {
+	centroidal + point_mass_inertia(mass, offset)
+}
+
+/// Circle of a given radius
+#[derive(Clone, Copy, Debug)]
+pub struct Circle {
+	pub radius: Length,
+}
+
+impl Circle {
+	pub const fn new(radius: Length) -> Circle {
+		Circle { radius }
+	}
+	pub fn from_diameter(diameter: Length) -> Circle {
+		Circle { radius: diameter/2.0 }
+	}
+	pub fn diameter(&self) -> Length {
+		2.0*self.radius
+	}
+	pub fn circumference(&self) -> Length {
+		2.0*PI*self.radius
+	}
+	pub fn area(&self) -> Area {
+		PI*self.radius*self.radius
+	}
+	/// Second moment of area about a diameter
+	pub fn second_moment(&self) -> SecondMomentOfArea {
+		circle_second_moment(self.diameter())
+	}
+	/// Moment of inertia of a thin uniform disc of this shape and `mass` about its axis, ½ m r²
+	pub fn disc_inertia(&self, mass: Mass) -> MomentOfInertia {
+		0.5*point_mass_inertia(mass, self.radius)
+	}
+	/// Moment of inertia of a thin ring of this shape and `mass` about its axis, m r²
+	pub fn ring_inertia(&self, mass: Mass) -> MomentOfInertia {
+		point_mass_inertia(mass, self.radius)
+	}
+}
+
+/// Rectangle of a given width and height
+#[derive(Clone, Copy, Debug)]
+pub struct Rectangle {
+	pub width: Length,
+	pub height: Length,
+}
+
+impl Rectangle {
+	pub const fn new(width: Length, height: Length) -> Rectangle {
+		Rectangle { width, height }
+	}
+	pub fn perimeter(&self) -> Length {
+		2.0*(self.width + self.height)
+	}
+	pub fn diagonal(&self) -> Length {
+		(self.width*self.width + self.height*self.height).root::<2>()
+	}
+	pub fn area(&self) -> Area {
+		self.width*self.height
+	}
+	/// Second moment of area about the centroidal axis parallel to the width
+	pub fn second_moment(&self) -> SecondMomentOfArea {
+		rectangle_second_moment(self.width, self.height)
+	}
+	/// Moment of inertia of a thin uniform plate of this shape and `mass` about the axis through its center normal to the plate,
+	/// m (w² + h²)/12
+	pub fn plate_inertia(&self, mass: Mass) -> MomentOfInertia {
+		point_mass_inertia(mass, self.diagonal())/12.0
+	}
+}
+
+/// Right circular cylinder of a given radius and height
+#[derive(Clone, Copy, Debug)]
+pub struct Cylinder {
+	pub radius: Length,
+	pub height: Length,
+}
+
+impl Cylinder {
+	pub const fn new(radius: Length, height: Length) -> Cylinder {
+		Cylinder { radius, height }
+	}
+	/// Circular cross-section of the cylinder
+	pub fn base(&self) -> Circle {
+		Circle::new(self.radius)
+	}
+	pub fn volume(&self) -> Volume {
+		self.base().area()*self.height
+	}
+	/// Area of the curved surface, excluding the ends
+	pub fn lateral_area(&self) -> Area {
+		self.base().circumference()*self.height
+	}
+	/// Area of the whole surface, including both ends
+	pub fn surface_area(&self) -> Area {
+		self.lateral_area() + 2.0*self.base().area()
+	}
+	/// Mass of a solid cylinder of uniform `density`
+	pub fn mass(&self, density: Density) -> Mass {
+		density*self.volume()
+	}
+	/// Moment of inertia of a solid uniform cylinder of `mass` about its axis, ½ m r²
+	pub fn axial_inertia(&self, mass: Mass) -> MomentOfInertia {
+		self.base().disc_inertia(mass)
+	}
+	/// Moment of inertia of a solid uniform cylinder of `mass` about an axis through its center perpendicular to its axis,
+	/// m (3r² + h²)/12
+	pub fn transverse_inertia(&self, mass: Mass) -> MomentOfInertia {
+		(3.0*point_mass_inertia(mass, self.radius) + point_mass_inertia(mass, self.height))/12.0
+	}
+}
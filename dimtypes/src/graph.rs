@@ -0,0 +1,179 @@
+//! Graphs of how units are defined from one another, written as Graphviz DOT or Mermaid
+//!
+//! A [UnitGraph] records each unit or constant as a node and its definition as edges from the units it is built from, e.g. `W` from
+//! `J` and `s`.  Definitions are products of powers of other units times a scale, and each resolves to a [ParsedUnit] so the graph
+//! also shows what every node is in SI base units.  Terms not yet in the graph are looked up with [parse_unit] and added as leaves,
+//! so a custom registry only needs its own definitions.  [UnitGraph::si] starts from the SI base and derived units.
+
+use std::fmt;
+use std::fmt::Write;
+use crate::{Dimension,Float};
+use crate::parse::{parse_unit,ParsedUnit,UnitParseError};
+
+/// Error adding a unit to a [UnitGraph]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphError {
+	/// A unit of this symbol is already in the graph
+	Duplicate(String),
+	/// A term of a definition is neither in the graph nor a unit [parse_unit] understands
+	UnknownTerm { symbol: String, error: UnitParseError },
+}
+
+impl fmt::Display for GraphError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			GraphError::Duplicate(symbol) => write!(f, "unit '{}' is already defined", symbol),
+			GraphError::UnknownTerm { symbol, error } => write!(f, "term '{}': {}", symbol, error),
+		}
+	}
+}
+
+impl std::error::Error for GraphError {}
+
+/// A unit in a [UnitGraph]
+#[derive(Clone, Debug)]
+pub struct Node {
+	pub symbol: String,
+	pub unit: ParsedUnit,
+	/// Scale applied to the product of the terms of the definition, 1 for leaves
+	pub scale: Float,
+}
+
+/// Use of the unit `from` raised to `power` in the definition of the unit `to`, as indices into [UnitGraph::nodes]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Edge {
+	pub from: usize,
+	pub to: usize,
+	pub power: i32,
+}
+
+/// Units as nodes and their definitions as edges
+#[derive(Clone, Debug, Default)]
+pub struct UnitGraph {
+	nodes: Vec<Node>,
+	edges: Vec<Edge>,
+}
+
+impl UnitGraph {
+	pub fn new() -> UnitGraph {
+		UnitGraph::default()
+	}
+
+	/// Graph of the SI base units and the derived units with special names built from them
+	pub fn si() -> UnitGraph {
+		let mut graph = UnitGraph::new();
+		for base in ["s", "m", "kg", "A", "K", "mol", "cd", "rad", "count"] {
+			graph.leaf(base).expect("SI base units parse");
+		}
+		let derived: &[(&str, &[(&str, i32)])] = &[
+			("Hz", &[("s", -1)]),
+			("N", &[("kg", 1), ("m", 1), ("s", -2)]),
+			("Pa", &[("N", 1), ("m", -2)]),
+			("J", &[("N", 1), ("m", 1)]),
+			("W", &[("J", 1), ("s", -1)]),
+			("C", &[("A", 1), ("s", 1)]),
+			("V", &[("W", 1), ("A", -1)]),
+			("Ohm", &[("V", 1), ("A", -1)]),
+			("S", &[("A", 1), ("V", -1)]),
+			("F", &[("C", 1), ("V", -1)]),
+			("Wb", &[("V", 1), ("s", 1)]),
+			("T", &[("Wb", 1), ("m", -2)]),
+			("H", &[("Wb", 1), ("A", -1)]),
+			("Gy", &[("J", 1), ("kg", -1)]),
+			("kat", &[("mol", 1), ("s", -1)]),
+		];
+		for (symbol, terms) in derived {
+			graph.define(symbol, 1.0, terms).expect("SI derived units are defined from units already in the graph");
+		}
+		graph
+	}
+
+	pub fn nodes(&self) -> &[Node] { &self.nodes }
+	pub fn edges(&self) -> &[Edge] { &self.edges }
+
+	/// The node of `symbol`, if it is in the graph
+	pub fn get(&self, symbol: &str) -> Option<&Node> {
+		self.nodes.iter().find(|n| n.symbol == symbol)
+	}
+
+	/// Add `symbol`, a unit [parse_unit] understands, as a node with no definition, returning its index
+	pub fn leaf(&mut self, symbol: &str) -> Result<usize, GraphError> {
+		if self.get(symbol).is_some() {
+			return Err(GraphError::Duplicate(symbol.to_string()));
+		}
+		let unit = parse_unit(symbol).map_err(|error| GraphError::UnknownTerm { symbol: symbol.to_string(), error })?;
+		self.nodes.push(Node { symbol: symbol.to_string(), unit, scale: 1.0 });
+		Ok(self.nodes.len() - 1)
+	}
+
+	/// Add `symbol` defined as `scale` times the product of `terms`, each a unit symbol and its power, e.g.
+	/// `define("kWh", 1.0, &[("kW", 1), ("h", 1)])`.  Terms not in the graph are added as leaves.  Returns the unit the definition
+	/// resolves to
+	pub fn define(&mut self, symbol: &str, scale: Float, terms: &[(&str, i32)]) -> Result<ParsedUnit, GraphError> {
+		if self.get(symbol).is_some() {
+			return Err(GraphError::Duplicate(symbol.to_string()));
+		}
+		let mut from = Vec::with_capacity(terms.len());
+		for &(term, power) in terms {
+			let index = match self.nodes.iter().position(|n| n.symbol == term) {
+				Some(index) => index,
+				None => self.leaf(term)?,
+			};
+			from.push((index, power));
+		}
+		let (value, dimension) = from.iter().fold((scale, Dimension::NONE), |(value, dimension), &(index, power)| {
+			let unit = self.nodes[index].unit;
+			(value*unit.scale().powi(power), dimension*unit.dimension().powi(power as isize))
+		});
+		let unit = ParsedUnit::new(value, dimension);
+		let to = self.nodes.len();
+		self.nodes.push(Node { symbol: symbol.to_string(), unit, scale });
+		self.edges.extend(from.into_iter().map(|(from, power)| Edge { from, to, power }));
+		Ok(unit)
+	}
+
+	/// Lines labelling a node, its symbol and, unless they are the same, its value in SI base units
+	fn label(node: &Node) -> Vec<String> {
+		let si = if node.unit.dimension().is_dimensionless() || node.unit.scale() != 1.0 {
+			node.unit.to_string()
+		} else {
+			node.unit.dimension().to_string()
+		};
+		if si == node.symbol { vec![si] } else { vec![node.symbol.clone(), si] }
+	}
+
+	/// Graphviz DOT of the graph, with base units at the bottom and each node labelled with its symbol and value in SI base units
+	pub fn to_dot(&self) -> String {
+		let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+		let mut out = String::from("digraph units {\n\trankdir=BT;\n\tnode [shape=box];\n");
+		for (i, node) in self.nodes.iter().enumerate() {
+			let _ = writeln!(out, "\tn{} [label=\"{}\"];", i, UnitGraph::label(node).iter().map(|l| escape(l)).collect::<Vec<_>>().join("\\n"));
+		}
+		for edge in &self.edges {
+			if edge.power == 1 {
+				let _ = writeln!(out, "\tn{} -> n{};", edge.from, edge.to);
+			} else {
+				let _ = writeln!(out, "\tn{} -> n{} [label=\"^{}\"];", edge.from, edge.to, edge.power);
+			}
+		}
+		out.push_str("}\n");
+		out
+	}
+
+	/// Mermaid flowchart of the graph, laid out as [to_dot](UnitGraph::to_dot)
+	pub fn to_mermaid(&self) -> String {
+		let escape = |s: &str| s.replace('"', "#quot;");
+		let mut out = String::from("graph BT\n");
+		for (i, node) in self.nodes.iter().enumerate() {
+			let _ = writeln!(out, "\tn{}[\"{}\"]", i, UnitGraph::label(node).iter().map(|l| escape(l)).collect::<Vec<_>>().join("<br/>"));
+		}
+		for edge in &self.edges {
+			if edge.power == 1 {
+				let _ = writeln!(out, "\tn{} --> n{}", edge.from, edge.to);
+			} else {
+				let _ = writeln!(out, "\tn{} -->|\"^{}\"| n{}", edge.from, edge.power, edge.to);
+			}
+		}
+		out
+	}
+}
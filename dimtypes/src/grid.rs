@@ -0,0 +1,56 @@
+//! Conversion between intensive and extensive quantities on finite-difference and finite-element grids
+//!
+//! Grid solvers typically store densities per cell (e.g. [PerVolume]`<Energy>`) while boundary conditions and conservation checks are
+//! in totals.  These helpers integrate densities over cell measures (lengths, areas or volumes) and recover densities from totals, with the
+//! dimension of the result tracked at compile time.
+//!
+//! [PerVolume]: crate::dimens::PerVolume
+
+use crate::{Float,Product,Quantity,Quotient};
+
+/// Total amount of a uniform `density` over a region of size `measure`
+pub fn integrate_over<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(density: Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>, measure: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2> {
+	density*measure
+}
+
+/// Total over a grid of equally sized cells, each of size `cell`, holding `densities`
+pub fn integrate_uniform<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(densities: &[Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>], cell: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2> {
+	let sum: Float = densities.iter().map(|d| d.as_si()).sum();
+	Quantity::from_si(sum*cell.as_si())
+}
+
+/// Total over a grid of cells with sizes `cells` holding `densities`.  Panics if the slices differ in length
+pub fn integrate_cells<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(densities: &[Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>], cells: &[Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>]) -> Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2> {
+	assert_eq!(densities.len(), cells.len(), "densities and cells must have the same length");
+	let sum: Float = densities.iter().zip(cells).map(|(d, c)| d.as_si()*c.as_si()).sum();
+	Quantity::from_si(sum)
+}
+
+/// Per-cell amounts of `densities` over cells of sizes `cells`.  Panics if the slices differ in length
+pub fn cell_totals<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(densities: &[Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>], cells: &[Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>]) -> Vec<Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> {
+	assert_eq!(densities.len(), cells.len(), "densities and cells must have the same length");
+	densities.iter().zip(cells).map(|(&d, &c)| d*c).collect()
+}
+
+/// Densities of per-cell `totals` over cells of sizes `cells`.  Panics if the slices differ in length
+pub fn cell_densities<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(totals: &[Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>], cells: &[Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>]) -> Vec<Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> {
+	assert_eq!(totals.len(), cells.len(), "totals and cells must have the same length");
+	totals.iter().zip(cells).map(|(&q, &c)| q/c).collect()
+}
+
+/// Mean density of the amount `total` spread over a region of size `measure`
+pub fn average_density<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(total: Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>, measure: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2> {
+	total/measure
+}
@@ -0,0 +1,119 @@
+//! Reading and writing quantities to HDF5 files, enabled with the `hdf5` feature
+//!
+//! Datasets are annotated with a string `units` attribute (e.g. `"km/h"`, `"degC"`), following the convention used by netCDF and most
+//! experimental data files.  On read, the attribute is [parsed][crate::parse] and checked against the dimension of the requested
+//! [Quantity] type before the values are converted to SI.
+
+use std::fmt;
+use ::hdf5::{Dataset,Group};
+use ::hdf5::types::{StringError,VarLenAscii,VarLenUnicode};
+use crate::{Dimension,Float,Quantity};
+use crate::buffer::SampleBuffer;
+use crate::parse::{parse_unit,DimensionMismatch,ParsedUnit,UnitParseError};
+
+/// Name of the attribute holding the unit of a dataset
+pub const UNITS_ATTRIBUTE: &str = "units";
+
+/// Error reading or writing quantities in an HDF5 file
+#[derive(Debug)]
+pub enum Hdf5Error {
+	/// Error from the HDF5 library
+	Hdf5(::hdf5::Error),
+	/// The dataset has no `units` attribute
+	MissingUnits,
+	/// The `units` attribute could not be parsed
+	Units(UnitParseError),
+	/// The unit does not match the dimension of the quantity
+	Dimension(DimensionMismatch),
+	/// The `units` string cannot be stored as an HDF5 string, e.g. as it contains a null character
+	UnitsString(StringError),
+}
+
+impl fmt::Display for Hdf5Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Hdf5Error::Hdf5(e) => write!(f, "{}", e),
+			Hdf5Error::MissingUnits => write!(f, "dataset has no '{}' attribute", UNITS_ATTRIBUTE),
+			Hdf5Error::Units(e) => write!(f, "invalid units: {}", e),
+			Hdf5Error::Dimension(e) => write!(f, "{}", e),
+			Hdf5Error::UnitsString(e) => write!(f, "units cannot be stored as an HDF5 string: {}", e),
+		}
+	}
+}
+impl std::error::Error for Hdf5Error {}
+
+impl From<::hdf5::Error> for Hdf5Error {
+	fn from(e: ::hdf5::Error) -> Self { Hdf5Error::Hdf5(e) }
+}
+impl From<UnitParseError> for Hdf5Error {
+	fn from(e: UnitParseError) -> Self { Hdf5Error::Units(e) }
+}
+impl From<DimensionMismatch> for Hdf5Error {
+	fn from(e: DimensionMismatch) -> Self { Hdf5Error::Dimension(e) }
+}
+impl From<StringError> for Hdf5Error {
+	fn from(e: StringError) -> Self { Hdf5Error::UnitsString(e) }
+}
+
+/// String for the SI base units of `dimension`, as written by [write_quantities_si]
+pub fn si_units(dimension: Dimension) -> String {
+	if dimension.is_dimensionless() { "1".to_string() } else { dimension.to_string() }
+}
+
+/// Write `values` to a new dataset `name` in `group`, stored as numerical values in `units` and tagged with a `units` attribute.
+/// Fails without writing if `units` cannot be parsed or does not match the dimension of the values
+pub fn write_quantities<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	group: &Group, name: &str, values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], units: &str
+) -> Result<Dataset, Hdf5Error> {
+	let unit = parse_unit(units)?;
+	unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+	let data: Vec<Float> = values.iter().map(|v| unit.from_si(v.as_si())).collect();
+	write_raw(group, name, &data, units)
+}
+
+/// Write `values` to a new dataset `name` in `group` in SI base units, tagged with the corresponding `units` attribute (e.g. `kg m^-3`)
+pub fn write_quantities_si<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	group: &Group, name: &str, values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]
+) -> Result<Dataset, Hdf5Error> {
+	let data: Vec<Float> = values.iter().map(|v| v.as_si()).collect();
+	write_raw(group, name, &data, &si_units(Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION))
+}
+
+/// Write every channel of `buffer` to a dataset of the same name in `group`, in SI base units
+pub fn write_buffer(group: &Group, buffer: &SampleBuffer) -> Result<(), Hdf5Error> {
+	for (name, dimension) in buffer.channels() {
+		if let Some((column, _)) = buffer.raw_column(name) {
+			write_raw(group, name, column, &si_units(dimension))?;
+		}
+	}
+	Ok(())
+}
+
+/// Parse the `units` attribute of `dataset`.  Both variable length UTF-8 and ASCII string attributes are accepted
+pub fn units_of(dataset: &Dataset) -> Result<ParsedUnit, Hdf5Error> {
+	if !dataset.attr_names()?.iter().any(|n| n == UNITS_ATTRIBUTE) {
+		return Err(Hdf5Error::MissingUnits);
+	}
+	let attr = dataset.attr(UNITS_ATTRIBUTE)?;
+	let units = match attr.read_scalar::<VarLenUnicode>() {
+		Ok(s) => s.as_str().to_string(),
+		Err(_) => attr.read_scalar::<VarLenAscii>()?.as_str().to_string(),
+	};
+	Ok(parse_unit(&units)?)
+}
+
+/// Read the values of `dataset` as quantities, converting from the unit given by its `units` attribute
+pub fn read_quantities<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	dataset: &Dataset
+) -> Result<Vec<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, Hdf5Error> {
+	let unit = units_of(dataset)?;
+	unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+	Ok(dataset.read_raw::<Float>()?.into_iter().map(|v| Quantity::from_si(unit.to_si(v))).collect())
+}
+
+fn write_raw(group: &Group, name: &str, data: &[Float], units: &str) -> Result<Dataset, Hdf5Error> {
+	let units: VarLenUnicode = units.parse()?;
+	let dataset = group.new_dataset_builder().with_data(data).create(name)?;
+	dataset.new_attr::<VarLenUnicode>().create(UNITS_ATTRIBUTE)?.write_scalar(&units)?;
+	Ok(dataset)
+}
@@ -0,0 +1,115 @@
+//! Pressure drop of incompressible flow in pipes by the Darcy–Weisbach equation
+//!
+//! The Darcy friction factor comes from the Colebrook–White equation, solved numerically with [find_root], or from the explicit
+//! Swamee–Jain approximation.  Fluid properties are taken from a [FluidState], which can be evaluated from any [FluidProperties].
+
+use crate::Float;
+use crate::dimens::*;
+use crate::consts::STANDARD_GRAVITY;
+use crate::fluids::{FluidProperties,PropertyError};
+use crate::math::find_root;
+
+/// Reynolds number below which pipe flow is taken as laminar
+pub const LAMINAR_LIMIT: Float = 2300.0;
+
+/// Density and viscosity of a fluid at the conditions in a pipe
+#[derive(Clone, Copy, Debug)]
+pub struct FluidState {
+	pub density: Density,
+	pub viscosity: DynamicViscosity,
+}
+
+impl FluidState {
+	pub const fn new(density: Density, viscosity: DynamicViscosity) -> FluidState {
+		FluidState { density, viscosity }
+	}
+	/// Evaluate the state of `fluid` at `temperature` and `pressure`
+	pub fn at(fluid: &impl FluidProperties, temperature: Temperature, pressure: Pressure) -> Result<FluidState, PropertyError> {
+		Ok(FluidState { density: fluid.density(temperature, pressure)?, viscosity: fluid.dynamic_viscosity(temperature, pressure)? })
+	}
+}
+
+/// Reynolds number ρvL/μ of a flow with velocity `velocity` and characteristic length `length`
+pub fn reynolds_number(density: Density, velocity: Velocity, length: Length, viscosity: DynamicViscosity) -> Unitless {
+	density*velocity*length/viscosity
+}
+
+/// Roughness `roughness` of a pipe wall relative to the pipe `diameter`, ε/D
+pub fn relative_roughness(roughness: Length, diameter: Length) -> Unitless {
+	roughness/diameter
+}
+
+/// Mean velocity of `flow_rate` through a circular pipe of inside diameter `diameter`
+pub fn mean_velocity(flow_rate: VolumetricFlowRate, diameter: Length) -> Velocity {
+	flow_rate/(crate::float_consts::FRAC_PI_4*diameter*diameter)
+}
+
+/// Explicit Swamee–Jain approximation of the turbulent Darcy friction factor, within about 1% of Colebrook–White for
+/// 5000 ≤ Re ≤ 10<sup>8</sup> and 10<sup>-6</sup> ≤ ε/D ≤ 0.05
+pub fn swamee_jain(reynolds: Unitless, relative_roughness: Unitless) -> Unitless {
+	let (re, rr) = (Float::from(reynolds), Float::from(relative_roughness));
+	let log = Float::log10(rr/3.7 + 5.74/re.powf(0.9));
+	Unitless::from(0.25/(log*log))
+}
+
+/// Turbulent Darcy friction factor from the Colebrook–White equation 1/√f = -2 log<sub>10</sub>(ε/3.7D + 2.51/(Re √f))
+pub fn colebrook(reynolds: Unitless, relative_roughness: Unitless) -> Unitless {
+	let (re, rr) = (Float::from(reynolds), Float::from(relative_roughness));
+	let residual = |x: Unitless| x + Unitless::from(2.0*Float::log10(rr/3.7 + 2.51*Float::from(x)/re));
+	// Solve for x = 1/√f, which is bracketed by friction factors of 1 and 10⁻⁴ for any turbulent flow
+	match find_root(residual, Unitless::from(1.0), Unitless::from(100.0), Unitless::from(1e-12)) {
+		Some(x) => Unitless::from(Float::from(x).powi(-2)),
+		None => swamee_jain(reynolds, relative_roughness),
+	}
+}
+
+/// Darcy friction factor for flow at Reynolds number `reynolds`: 64/Re for laminar flow and [colebrook] otherwise
+pub fn friction_factor(reynolds: Unitless, relative_roughness: Unitless) -> Unitless {
+	if Float::from(reynolds) < LAMINAR_LIMIT {
+		64.0/reynolds
+	} else {
+		colebrook(reynolds, relative_roughness)
+	}
+}
+
+/// Frictional pressure drop of `flow_rate` of `fluid` through `length` of circular pipe with inside diameter `diameter` and wall
+/// roughness `roughness`, by the Darcy–Weisbach equation Δp = f (L/D) ρv²/2.  Laminar flow uses the equivalent Hagen–Poiseuille
+/// form Δp = 32μLv/D², so the drop vanishes at zero flow.  The drop has the sign of `flow_rate`, so reverse flow gives a negative drop
+pub fn pressure_drop(length: Length, diameter: Length, roughness: Length, flow_rate: VolumetricFlowRate, fluid: FluidState) -> Pressure {
+	let velocity = mean_velocity(flow_rate, diameter);
+	let reynolds = Float::from(reynolds_number(fluid.density, velocity, diameter, fluid.viscosity)).abs();
+	if reynolds < LAMINAR_LIMIT {
+		32.0*fluid.viscosity*length*velocity/(diameter*diameter)
+	} else {
+		let f = colebrook(Unitless::from(reynolds), relative_roughness(roughness, diameter));
+		f*(length/diameter)*0.5*fluid.density*velocity*velocity*Float::signum(velocity.as_si())
+	}
+}
+
+/// Head of fluid of density `density` equivalent to `pressure_drop`
+pub fn head_loss(pressure_drop: Pressure, density: Density) -> Length {
+	pressure_drop/(density*STANDARD_GRAVITY)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn pressure_drop_is_odd_in_flow() {
+		let water = FluidState::new(998.0*KILO*GRAM/METER/METER/METER, 1.0e-3*PASCAL*SECOND);
+		let drop = |q: VolumetricFlowRate| pressure_drop(10.0*METER, 0.05*METER, 0.045e-3*METER, q, water).as_unit(PASCAL);
+		assert_eq!(drop(VolumetricFlowRate::from_si(0.0)), 0.0);
+		// Laminar and turbulent flows, forwards and backwards
+		for q in [1e-6, 5e-3] {
+			let forward = drop(VolumetricFlowRate::from_si(q));
+			assert!(forward > 0.0);
+			assert_eq!(drop(VolumetricFlowRate::from_si(-q)), -forward);
+		}
+		// Hagen–Poiseuille: 128μLQ/(πD⁴)
+		let diameter: Float = 0.05;
+		let laminar = 128.0*1.0e-3*10.0*1e-6/(crate::float_consts::PI*diameter.powi(4));
+		assert!((drop(VolumetricFlowRate::from_si(1e-6)) - laminar).abs() < 1e-9*laminar);
+	}
+}
@@ -0,0 +1,382 @@
+//! Global registry of named quantities for live inspection and tuning
+//!
+//! Simulation code [publishes](publish) quantities under a name along with a preferred display unit, and reads [tunable](tune)
+//! parameters back each step.  The registry erases the type of each quantity, keeping its value in SI base units and its
+//! [Dimension], so it can be [dumped](dump) as text or JSON or [served](serve) over HTTP without any per-quantity plumbing.
+//! Values changed through [set] (or a `POST /set` request) are checked against the dimension the quantity was registered with.
+//!
+//! The HTTP interface understands three requests:
+//! * `GET /` lists every quantity as text, one per line
+//! * `GET /json` lists every quantity as a JSON object in the same `{"value": ..., "unit": ...}` form as [telemetry][crate::telemetry] messages
+//! * `POST /set?name=<name>&value=<number>[&unit=<unit>]` changes a quantity, in its preferred unit unless `unit` is given
+//!
+//! So that a web page open in the same browser cannot change quantities, `POST /set` must carry an `X-Inspector: 1` header, which
+//! browsers do not let pages add to cross-origin requests, and requests naming any host other than `localhost` or an IP address
+//! are refused, which defeats DNS rebinding.  For example
+//! `curl -X POST -H 'X-Inspector: 1' 'http://127.0.0.1:8080/set?name=gain&value=2'`
+
+use std::fmt;
+use std::io::{self,BufRead,BufReader,Read,Write};
+use std::net::{IpAddr,TcpListener,TcpStream,ToSocketAddrs};
+use std::sync::{Mutex,MutexGuard};
+use std::thread::{self,JoinHandle};
+use std::time::{Duration,Instant};
+use crate::{AnyQuantity,Dimension,Float};
+use crate::json;
+use crate::parse::{parse_unit,ParsedUnit,UnitParseError};
+
+/// Error registering, reading or changing a quantity in the inspector
+#[derive(Clone, Debug, PartialEq)]
+pub enum InspectorError {
+	/// No quantity with this name is registered
+	UnknownQuantity(String),
+	/// The unit given for a quantity could not be parsed
+	InvalidUnit { name: String, error: UnitParseError },
+	/// The quantity or unit has a different dimension than the registered quantity
+	DimensionMismatch { name: String, expected: Dimension, found: Dimension },
+}
+
+impl fmt::Display for InspectorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			InspectorError::UnknownQuantity(name) => write!(f, "no quantity named '{}'", name),
+			InspectorError::InvalidUnit { name, error } => write!(f, "quantity '{}': {}", name, error),
+			InspectorError::DimensionMismatch { name, expected, found } =>
+				write!(f, "quantity '{}' has dimension [{}], not [{}]", name, expected, found),
+		}
+	}
+}
+
+impl std::error::Error for InspectorError {}
+
+/// Snapshot of a registered quantity
+#[derive(Clone, Debug)]
+pub struct Entry {
+	pub name: String,
+	/// Preferred display unit, as given at registration
+	pub units: String,
+	unit: ParsedUnit,
+	value_si: Float,
+}
+
+impl Entry {
+	/// Value in SI base units
+	pub fn as_si(&self) -> Float { self.value_si }
+	/// Value in the preferred unit
+	pub fn value(&self) -> Float { self.unit.from_si(self.value_si) }
+	pub fn dimension(&self) -> Dimension { self.unit.dimension() }
+}
+
+/// Formats as `name = value units`
+impl fmt::Display for Entry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} = {} {}", self.name, self.value(), self.units)
+	}
+}
+
+static REGISTRY: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+fn registry() -> MutexGuard<'static, Vec<Entry>> {
+	REGISTRY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn unit_for(name: &str, units: &str, dimension: Dimension) -> Result<ParsedUnit, InspectorError> {
+	let unit = parse_unit(units).map_err(|error| InspectorError::InvalidUnit { name: name.to_string(), error })?;
+	if unit.dimension() != dimension {
+		return Err(InspectorError::DimensionMismatch { name: name.to_string(), expected: dimension, found: unit.dimension() });
+	}
+	Ok(unit)
+}
+
+fn check<Q: AnyQuantity>(entry: &Entry) -> Result<(), InspectorError> {
+	if entry.dimension() != Q::DIMENSION {
+		return Err(InspectorError::DimensionMismatch { name: entry.name.clone(), expected: entry.dimension(), found: Q::DIMENSION });
+	}
+	Ok(())
+}
+
+/// Register `value` under `name`, displayed in `units`, or replace the value and unit of an existing quantity of the same dimension
+pub fn publish<Q: AnyQuantity>(name: &str, value: Q, units: &str) -> Result<(), InspectorError> {
+	let unit = unit_for(name, units, Q::DIMENSION)?;
+	let mut entries = registry();
+	match entries.iter_mut().find(|e| e.name == name) {
+		Some(entry) => {
+			check::<Q>(entry)?;
+			entry.units = units.to_string();
+			entry.unit = unit;
+			entry.value_si = value.as_si();
+		},
+		None => entries.push(Entry { name: name.to_string(), units: units.to_string(), unit, value_si: value.as_si() }),
+	}
+	Ok(())
+}
+
+/// Current value of the tunable parameter `name`, registering it with value `default` displayed in `units` if it does not exist yet.
+/// Call this every step to pick up changes made through [set] or over HTTP
+pub fn tune<Q: AnyQuantity>(name: &str, default: Q, units: &str) -> Result<Q, InspectorError> {
+	let mut entries = registry();
+	match entries.iter().find(|e| e.name == name) {
+		Some(entry) => {
+			check::<Q>(entry)?;
+			Ok(Q::from_si(entry.value_si))
+		},
+		None => {
+			let unit = unit_for(name, units, Q::DIMENSION)?;
+			entries.push(Entry { name: name.to_string(), units: units.to_string(), unit, value_si: default.as_si() });
+			Ok(default)
+		},
+	}
+}
+
+/// Current value of the quantity `name`
+pub fn get<Q: AnyQuantity>(name: &str) -> Result<Q, InspectorError> {
+	let entries = registry();
+	let entry = entries.iter().find(|e| e.name == name).ok_or_else(|| InspectorError::UnknownQuantity(name.to_string()))?;
+	check::<Q>(entry)?;
+	Ok(Q::from_si(entry.value_si))
+}
+
+/// Change the quantity `name` to `value` in `units`, or in its preferred unit if `units` is [None]
+pub fn set(name: &str, value: Float, units: Option<&str>) -> Result<(), InspectorError> {
+	let mut entries = registry();
+	let entry = entries.iter_mut().find(|e| e.name == name).ok_or_else(|| InspectorError::UnknownQuantity(name.to_string()))?;
+	let unit = match units {
+		Some(units) => unit_for(name, units, entry.dimension())?,
+		None => entry.unit,
+	};
+	entry.value_si = unit.to_si(value);
+	Ok(())
+}
+
+/// Remove the quantity `name`, returning whether it was registered
+pub fn remove(name: &str) -> bool {
+	let mut entries = registry();
+	let len = entries.len();
+	entries.retain(|e| e.name != name);
+	entries.len() != len
+}
+
+/// Remove every registered quantity
+pub fn clear() {
+	registry().clear();
+}
+
+/// Copy of every registered quantity, in the order they were registered
+pub fn snapshot() -> Vec<Entry> {
+	registry().clone()
+}
+
+/// Every registered quantity as text, one `name = value units` line each
+pub fn dump() -> String {
+	registry().iter().map(|e| format!("{}\n", e)).collect()
+}
+
+/// Every registered quantity as a JSON object mapping names to `{"value": <number>, "unit": "<units>"}`
+pub fn to_json() -> String {
+	let members: Vec<String> = registry().iter().map(|e| {
+		let value = if e.value().is_finite() { e.value().to_string() } else { "null".to_string() };
+		format!("{}:{{\"value\":{},\"unit\":{}}}", json::quote(&e.name), value, json::quote(&e.units))
+	}).collect();
+	format!("{{{}}}", members.join(","))
+}
+
+/// Serve the registry over HTTP on `addr` (e.g. `"127.0.0.1:8080"`) from a background thread.  Requests are handled one at a time,
+/// which is plenty for a debugging interface; a client that takes more than five seconds over its request or sends an oversized one
+/// is dropped.  There is no authentication, so bind to a loopback address
+pub fn serve(addr: impl ToSocketAddrs) -> io::Result<JoinHandle<()>> {
+	let listener = TcpListener::bind(addr)?;
+	let port = listener.local_addr()?.port();
+	Ok(thread::spawn(move || {
+		for stream in listener.incoming().flatten() {
+			// A misbehaving client should not bring down the inspector
+			let _ = handle(stream, port);
+		}
+	}))
+}
+
+/// Time a client has to send its whole request and accept the response before the connection is dropped, so a stalled or trickling
+/// client cannot hold up the requests behind it
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest request line or header line accepted, in bytes
+const MAX_LINE: usize = 8192;
+/// Most header lines accepted in a request
+const MAX_HEADERS: usize = 64;
+
+/// Read a line of at most [MAX_LINE] bytes into `line`, returning its length or `None` if it is longer
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> io::Result<Option<usize>> {
+	let n = Read::take(reader, MAX_LINE as u64).read_line(line)?;
+	Ok(if n == MAX_LINE && !line.ends_with('\n') { None } else { Some(n) })
+}
+
+/// Read the headers following the request line, up to the blank line ending them, as `(name, value)` pairs with lowercase names,
+/// or `None` if they are beyond the limits
+fn read_headers(reader: &mut impl BufRead) -> io::Result<Option<Vec<(String, String)>>> {
+	let mut headers = Vec::new();
+	let mut line = String::new();
+	for _ in 0..=MAX_HEADERS {
+		line.clear();
+		match read_line(reader, &mut line)? {
+			Some(n) if n <= 2 => return Ok(Some(headers)),
+			Some(_) => if let Some((name, value)) = line.split_once(':') {
+				headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+			},
+			None => return Ok(None),
+		}
+	}
+	Ok(None)
+}
+
+/// Whether a request with the `Host` header `host` is addressed to the inspector on `port` by IP address or as `localhost`, rather
+/// than through a domain name an attacker could point at the inspector's address
+fn allowed_host(host: &str, port: u16) -> bool {
+	let (name, host_port) = match host.rsplit_once(':') {
+		Some((name, p)) if !p.contains(']') => (name, p.parse().ok()),
+		_ => (host, Some(80)),
+	};
+	let is_ip = name.eq_ignore_ascii_case("localhost")
+		|| name.parse::<IpAddr>().is_ok()
+		|| name.strip_prefix('[').and_then(|n| n.strip_suffix(']')).is_some_and(|n| n.parse::<IpAddr>().is_ok());
+	is_ip && host_port == Some(port)
+}
+
+/// Stream that fails reads once `deadline` has passed, rather than only when a single read stalls
+struct Deadline {
+	stream: TcpStream,
+	deadline: Instant,
+}
+
+impl Deadline {
+	fn remaining(&self) -> io::Result<Duration> {
+		match self.deadline.checked_duration_since(Instant::now()) {
+			Some(remaining) if !remaining.is_zero() => Ok(remaining),
+			_ => Err(io::Error::new(io::ErrorKind::TimedOut, "request took too long")),
+		}
+	}
+}
+
+impl Read for Deadline {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.stream.set_read_timeout(Some(self.remaining()?))?;
+		self.stream.read(buf)
+	}
+}
+
+fn handle(stream: TcpStream, port: u16) -> io::Result<()> {
+	let mut reader = BufReader::new(Deadline { stream: stream.try_clone()?, deadline: Instant::now() + TIMEOUT });
+	let mut request = String::new();
+	let headers = match read_line(&mut reader, &mut request)? {
+		None => Err("414 URI Too Long"),
+		Some(_) => read_headers(&mut reader)?.ok_or("431 Request Header Fields Too Large"),
+	};
+	let header = |name: &str| headers.as_ref().ok().and_then(|h| h.iter().find(|(n,_)| n == name)).map(|(_,v)| v.as_str());
+
+	let mut parts = request.split_whitespace();
+	let method = parts.next().unwrap_or("");
+	let target = parts.next().unwrap_or("");
+	let (path, query) = target.split_once('?').unwrap_or((target, ""));
+	let (status, content_type, body) = match (headers.as_ref().err(), method, path) {
+		(Some(status), _, _) => (*status, "text/plain", "request too large\n".to_string()),
+		_ if !header("host").is_some_and(|host| allowed_host(host, port)) =>
+			("403 Forbidden", "text/plain", "host must be localhost or an IP address\n".to_string()),
+		(None, "GET", "/") => ("200 OK", "text/plain", dump()),
+		(None, "GET", "/json") => ("200 OK", "application/json", to_json()),
+		(None, "POST", "/set") if header("x-inspector") != Some("1") =>
+			("403 Forbidden", "text/plain", "missing header 'X-Inspector: 1'\n".to_string()),
+		(None, "POST", "/set") => match set_from_query(query) {
+			Ok(()) => ("200 OK", "text/plain", "ok\n".to_string()),
+			Err(message) => ("400 Bad Request", "text/plain", message + "\n"),
+		},
+		(None, "GET" | "POST", _) => ("404 Not Found", "text/plain", "not found\n".to_string()),
+		_ => ("405 Method Not Allowed", "text/plain", "method not allowed\n".to_string()),
+	};
+	let mut stream = stream;
+	stream.set_write_timeout(Some(reader.get_ref().remaining()?))?;
+	write!(stream, "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+		status, content_type, body.len(), body)
+}
+
+fn set_from_query(query: &str) -> Result<(), String> {
+	let params: Vec<(String, String)> = query.split('&')
+		.filter_map(|p| p.split_once('='))
+		.map(|(k, v)| (decode(k), decode(v)))
+		.collect();
+	let get = |key: &str| params.iter().find(|(k,_)| k == key).map(|(_,v)| v.as_str());
+	let name = get("name").ok_or("missing parameter 'name'")?;
+	let value = get("value").ok_or("missing parameter 'value'")?;
+	let value: Float = value.parse().map_err(|_| format!("invalid value '{}'", value))?;
+	set(name, value, get("unit")).map_err(|e| e.to_string())
+}
+
+/// Decode a percent-encoded query string component
+fn decode(s: &str) -> String {
+	let bytes = s.as_bytes();
+	let mut out = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		let escaped = if bytes[i] == b'%' { s.get(i+1..i+3).and_then(|hex| u8::from_str_radix(hex, 16).ok()) } else { None };
+		match (bytes[i], escaped) {
+			(_, Some(byte)) => {
+				out.push(byte);
+				i += 2;
+			},
+			(b'+', _) => out.push(b' '),
+			(b, _) => out.push(b),
+		}
+		i += 1;
+	}
+	String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::io::Cursor;
+
+	#[test]
+	fn request_limits() {
+		let mut line = String::new();
+		let mut reader = Cursor::new(format!("GET /{} HTTP/1.1\r\n", "a".repeat(MAX_LINE)));
+		assert_eq!(read_line(&mut reader, &mut line).unwrap(), None);
+
+		let mut reader = Cursor::new("Host: localhost\r\nAccept: */*\r\n\r\n");
+		let headers = read_headers(&mut reader).unwrap().unwrap();
+		assert_eq!(headers, [("host".to_string(), "localhost".to_string()), ("accept".to_string(), "*/*".to_string())]);
+		let mut reader = Cursor::new("X-Padding: 1\r\n".repeat(MAX_HEADERS + 1) + "\r\n");
+		assert!(read_headers(&mut reader).unwrap().is_none());
+		let mut reader = Cursor::new(format!("X-Padding: {}\r\n\r\n", "a".repeat(MAX_LINE)));
+		assert!(read_headers(&mut reader).unwrap().is_none());
+	}
+
+	#[test]
+	fn hosts() {
+		assert!(allowed_host("localhost:8080", 8080));
+		assert!(allowed_host("127.0.0.1:8080", 8080));
+		assert!(allowed_host("[::1]:8080", 8080));
+		assert!(allowed_host("192.168.1.5", 80));
+		assert!(!allowed_host("127.0.0.1:8081", 8080));
+		assert!(!allowed_host("evil.example:8080", 8080));
+		assert!(!allowed_host("localhost.evil.example:8080", 8080));
+	}
+
+	#[test]
+	fn request_deadline() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		let (server, _) = listener.accept().unwrap();
+		let mut reader = BufReader::new(Deadline { stream: server, deadline: Instant::now() + Duration::from_millis(200) });
+		// A client trickling a byte at a time is cut off at the deadline, however short the gap between bytes
+		let trickle = thread::spawn(move || {
+			for _ in 0..50 {
+				if client.write_all(b"a").is_err() {
+					break;
+				}
+				thread::sleep(Duration::from_millis(20));
+			}
+		});
+		let mut line = String::new();
+		let started = Instant::now();
+		assert!(read_line(&mut reader, &mut line).is_err());
+		assert!(started.elapsed() < Duration::from_millis(900));
+		trickle.join().unwrap();
+	}
+}
@@ -0,0 +1,139 @@
+//! Integration of sampled signals over time, with coulomb and energy counters for battery management and power supply testing
+//!
+//! Sampled signals are integrated with the trapezoidal rule, so integrating a [Current] gives a [Charge] and integrating a [Power]
+//! gives an [Energy].  The counters accumulate one step at a time for use inside acquisition or control loops.
+
+use crate::{Float,Quantity};
+use crate::dimens::*;
+
+/// Integral of `values` sampled at `times` by the trapezoidal rule.  Panics if the slices differ in length
+pub fn integrate_samples<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(times: &[Time], values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Quantity<{T+D},L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	assert_eq!(times.len(), values.len(), "times and values must have the same length");
+	let sum: Float = times.windows(2).zip(values.windows(2))
+		.map(|(t, v)| 0.5*(v[0].as_si() + v[1].as_si())*(t[1].as_si() - t[0].as_si()))
+		.sum();
+	Quantity::from_si(sum)
+}
+
+/// Integral of `values` sampled every `interval` by the trapezoidal rule
+pub fn integrate_uniform_samples<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], interval: Time) -> Quantity<{T+D},L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	let sum: Float = values.windows(2).map(|v| 0.5*(v[0].as_si() + v[1].as_si())).sum();
+	Quantity::from_si(sum*interval.as_si())
+}
+
+/// Charge passed by `currents` sampled at `times`
+pub fn charge(times: &[Time], currents: &[Current]) -> Charge {
+	integrate_samples(times, currents)
+}
+
+/// Energy delivered by `powers` sampled at `times`
+pub fn energy(times: &[Time], powers: &[Power]) -> Energy {
+	integrate_samples(times, powers)
+}
+
+/// Energy delivered by simultaneous `voltages` and `currents` sampled at `times`.  Panics if the slices differ in length
+pub fn energy_from_samples(times: &[Time], voltages: &[Voltage], currents: &[Current]) -> Energy {
+	assert_eq!(voltages.len(), currents.len(), "voltages and currents must have the same length");
+	let powers: Vec<Power> = voltages.iter().zip(currents).map(|(&v, &i)| v*i).collect();
+	energy(times, &powers)
+}
+
+/// Fraction of the energy put into a storage device recovered from it
+pub fn round_trip_efficiency(energy_in: Energy, energy_out: Energy) -> Unitless {
+	energy_out/energy_in
+}
+
+/// Coulomb counter tracking the charge held by a cell or pack.  Positive currents charge and negative currents discharge
+#[derive(Clone, Copy, Debug)]
+pub struct CoulombCounter {
+	capacity: Charge,
+	charge: Charge,
+	charge_efficiency: Unitless,
+	charged: Charge,
+	discharged: Charge,
+}
+
+impl CoulombCounter {
+	/// Counter for a cell of `capacity` currently holding `charge`, with no charging losses
+	pub fn new(capacity: Charge, charge: Charge) -> CoulombCounter {
+		CoulombCounter {
+			capacity,
+			charge,
+			charge_efficiency: Unitless::from(1.0),
+			charged: Charge::from_si(0.0),
+			discharged: Charge::from_si(0.0),
+		}
+	}
+	/// Apply the coulombic efficiency `efficiency` to charging currents, the fraction of charge put in that can later be drawn out
+	pub fn with_charge_efficiency(self, efficiency: Unitless) -> CoulombCounter {
+		CoulombCounter { charge_efficiency: efficiency, ..self }
+	}
+
+	/// Count `current` flowing for `interval`.  The stored charge is clamped between zero and the capacity
+	pub fn update(&mut self, current: Current, interval: Time) {
+		let delta = current*interval;
+		if delta.as_si() >= 0.0 {
+			self.charged += delta;
+			self.charge += self.charge_efficiency*delta;
+		} else {
+			self.discharged -= delta;
+			self.charge += delta;
+		}
+		self.charge = Charge::from_si(self.charge.as_si().clamp(0.0, self.capacity.as_si()));
+	}
+
+	/// Set the stored charge, e.g. after a full charge or a voltage based correction
+	pub fn reset(&mut self, charge: Charge) {
+		self.charge = charge;
+	}
+
+	pub fn capacity(&self) -> Charge { self.capacity }
+	/// Charge currently stored
+	pub fn charge(&self) -> Charge { self.charge }
+	/// Stored charge as a fraction of the capacity
+	pub fn state_of_charge(&self) -> Unitless { self.charge/self.capacity }
+	/// Total charge put in since the counter was created, before losses
+	pub fn charged(&self) -> Charge { self.charged }
+	/// Total charge drawn out since the counter was created
+	pub fn discharged(&self) -> Charge { self.discharged }
+	/// Equivalent full cycles, the discharged charge divided by the capacity
+	pub fn equivalent_cycles(&self) -> Float { Float::from(self.discharged/self.capacity) }
+}
+
+/// Bidirectional energy meter accumulating imported (positive power) and exported (negative power) energy separately
+#[derive(Clone, Copy, Debug)]
+pub struct EnergyMeter {
+	imported: Energy,
+	exported: Energy,
+}
+
+impl Default for EnergyMeter {
+	fn default() -> Self { EnergyMeter::new() }
+}
+
+impl EnergyMeter {
+	pub const fn new() -> EnergyMeter {
+		EnergyMeter { imported: Energy::from_si(0.0), exported: Energy::from_si(0.0) }
+	}
+
+	/// Count `power` flowing for `interval`
+	pub fn update(&mut self, power: Power, interval: Time) {
+		let delta = power*interval;
+		if delta.as_si() >= 0.0 {
+			self.imported += delta;
+		} else {
+			self.exported -= delta;
+		}
+	}
+	/// Count the power of `voltage` and `current` flowing for `interval`
+	pub fn update_vi(&mut self, voltage: Voltage, current: Current, interval: Time) {
+		self.update(voltage*current, interval);
+	}
+
+	pub fn imported(&self) -> Energy { self.imported }
+	pub fn exported(&self) -> Energy { self.exported }
+	/// Imported minus exported energy
+	pub fn net(&self) -> Energy { self.imported - self.exported }
+}
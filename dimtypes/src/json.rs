@@ -0,0 +1,205 @@
+//! Just enough JSON to read and write flat documents of quantities
+
+use std::fmt;
+use crate::Float;
+
+/// Error parsing JSON, with the byte offset it occurred at
+#[derive(Clone, Debug, PartialEq)]
+pub struct Error {
+	pub message: String,
+	pub position: usize,
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid JSON at byte {}: {}", self.position, self.message)
+	}
+}
+
+pub enum Value {
+	Null,
+	Bool,
+	Number(Float),
+	String(String),
+	Array,
+	Object(Vec<(String, Value)>),
+}
+
+pub fn quote(s: &str) -> String {
+	let mut out = String::from("\"");
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+/// Deepest nesting of arrays and objects accepted, so hostile input cannot overflow the stack of the recursive parser
+const MAX_DEPTH: usize = 128;
+
+pub fn parse(text: &str) -> Result<Value, Error> {
+	let mut parser = Parser { bytes: text.as_bytes(), pos: 0, depth: 0 };
+	let value = parser.value()?;
+	parser.skip_whitespace();
+	if parser.pos != parser.bytes.len() {
+		return Err(parser.error("trailing characters"));
+	}
+	Ok(value)
+}
+
+struct Parser<'a> {
+	bytes: &'a [u8],
+	pos: usize,
+	/// Number of arrays and objects the parser is inside
+	depth: usize,
+}
+
+impl Parser<'_> {
+	fn error(&self, message: &str) -> Error {
+		Error { message: message.to_string(), position: self.pos }
+	}
+	fn skip_whitespace(&mut self) {
+		while self.bytes.get(self.pos).is_some_and(|b| b.is_ascii_whitespace()) {
+			self.pos += 1;
+		}
+	}
+	fn expect(&mut self, byte: u8) -> Result<(), Error> {
+		self.skip_whitespace();
+		if self.bytes.get(self.pos) == Some(&byte) {
+			self.pos += 1;
+			Ok(())
+		} else {
+			Err(self.error(&format!("expected '{}'", byte as char)))
+		}
+	}
+	fn literal(&mut self, word: &str, value: Value) -> Result<Value, Error> {
+		if self.bytes[self.pos..].starts_with(word.as_bytes()) {
+			self.pos += word.len();
+			Ok(value)
+		} else {
+			Err(self.error("unexpected token"))
+		}
+	}
+
+	fn value(&mut self) -> Result<Value, Error> {
+		self.skip_whitespace();
+		match self.bytes.get(self.pos) {
+			Some(b'{' | b'[') if self.depth >= MAX_DEPTH => Err(self.error("nested too deeply")),
+			Some(b'{') => self.nested(Parser::object),
+			Some(b'[') => self.nested(Parser::array),
+			Some(b'"') => Ok(Value::String(self.string()?)),
+			Some(b't') => self.literal("true", Value::Bool),
+			Some(b'f') => self.literal("false", Value::Bool),
+			Some(b'n') => self.literal("null", Value::Null),
+			Some(b'-' | b'0'..=b'9') => self.number(),
+			_ => Err(self.error("expected a value")),
+		}
+	}
+
+	fn nested(&mut self, parse: fn(&mut Self) -> Result<Value, Error>) -> Result<Value, Error> {
+		self.depth += 1;
+		let value = parse(self);
+		self.depth -= 1;
+		value
+	}
+
+	fn object(&mut self) -> Result<Value, Error> {
+		self.expect(b'{')?;
+		let mut members = Vec::new();
+		self.skip_whitespace();
+		if self.bytes.get(self.pos) == Some(&b'}') {
+			self.pos += 1;
+			return Ok(Value::Object(members));
+		}
+		loop {
+			self.skip_whitespace();
+			let key = self.string()?;
+			self.expect(b':')?;
+			members.push((key, self.value()?));
+			self.skip_whitespace();
+			match self.bytes.get(self.pos) {
+				Some(b',') => self.pos += 1,
+				Some(b'}') => { self.pos += 1; return Ok(Value::Object(members)); },
+				_ => return Err(self.error("expected ',' or '}'")),
+			}
+		}
+	}
+
+	fn array(&mut self) -> Result<Value, Error> {
+		self.expect(b'[')?;
+		self.skip_whitespace();
+		if self.bytes.get(self.pos) == Some(&b']') {
+			self.pos += 1;
+			return Ok(Value::Array);
+		}
+		loop {
+			self.value()?;
+			self.skip_whitespace();
+			match self.bytes.get(self.pos) {
+				Some(b',') => self.pos += 1,
+				Some(b']') => { self.pos += 1; return Ok(Value::Array); },
+				_ => return Err(self.error("expected ',' or ']'")),
+			}
+		}
+	}
+
+	fn string(&mut self) -> Result<String, Error> {
+		self.expect(b'"')?;
+		let mut out = Vec::new();
+		loop {
+			match self.bytes.get(self.pos) {
+				None => return Err(self.error("unterminated string")),
+				Some(b'"') => { self.pos += 1; break; },
+				Some(b'\\') => {
+					self.pos += 1;
+					let escaped = match self.bytes.get(self.pos) {
+						Some(b'"') => '"', Some(b'\\') => '\\', Some(b'/') => '/',
+						Some(b'b') => '\u{8}', Some(b'f') => '\u{c}', Some(b'n') => '\n', Some(b'r') => '\r', Some(b't') => '\t',
+						Some(b'u') => {
+							let hex = self.bytes.get(self.pos+1..self.pos+5).and_then(|h| std::str::from_utf8(h).ok());
+							let code = hex.and_then(|h| u32::from_str_radix(h, 16).ok()).ok_or_else(|| self.error("invalid unicode escape"))?;
+							self.pos += 4;
+							char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+						},
+						_ => return Err(self.error("invalid escape")),
+					};
+					self.pos += 1;
+					let mut buf = [0u8; 4];
+					out.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+				},
+				Some(&b) => { out.push(b); self.pos += 1; },
+			}
+		}
+		String::from_utf8(out).map_err(|_| self.error("invalid UTF-8 in string"))
+	}
+
+	fn number(&mut self) -> Result<Value, Error> {
+		let start = self.pos;
+		while self.bytes.get(self.pos).is_some_and(|b| matches!(b, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+			self.pos += 1;
+		}
+		std::str::from_utf8(&self.bytes[start..self.pos]).ok()
+			.and_then(|s| s.parse().ok())
+			.map(Value::Number)
+			.ok_or_else(|| self.error("invalid number"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn nesting_limit() {
+		let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+		assert!(parse(&nested(MAX_DEPTH)).is_ok());
+		assert_eq!(parse(&nested(MAX_DEPTH + 1)).err().map(|e| e.message), Some("nested too deeply".to_string()));
+		// Deep enough to overflow the stack without the limit
+		assert!(parse(&"[{\"a\":".repeat(1_000_000)).is_err());
+	}
+}
@@ -1,15 +1,195 @@
-//! 
+//! Compile-time dimensional analysis for physical quantities using const generics
 
-#![allow(incomplete_features)]
-#![feature(generic_const_exprs)]
-#![feature(const_ops)]
-#![feature(const_trait_impl)]
-#![feature(const_convert)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "stable"), allow(incomplete_features))]
+#![cfg_attr(not(feature = "stable"), feature(generic_const_exprs))]
+#![cfg_attr(not(feature = "stable"), feature(const_ops))]
+#![cfg_attr(not(feature = "stable"), feature(const_trait_impl))]
+#![cfg_attr(not(feature = "stable"), feature(const_convert))]
+#![allow(clippy::result_large_err)]
+#![cfg_attr(feature = "f32", allow(clippy::excessive_precision))]
 
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("dimtypes needs the `std` feature, or `libm` for floating point math on #![no_std] targets");
+
+/// Floating point type quantities are stored in: [f64], or [f32] with the `f32` feature for targets without a double precision FPU
+#[cfg(not(feature = "f32"))]
+pub type Float = f64;
+/// Floating point type quantities are stored in: [f64], or [f32] with the `f32` feature for targets without a double precision FPU
+#[cfg(feature = "f32")]
+pub type Float = f32;
+
+/// Mathematical constants of the [Float] type
+#[cfg(not(feature = "f32"))]
+pub use core::f64::consts as float_consts;
+/// Mathematical constants of the [Float] type
+#[cfg(feature = "f32")]
+pub use core::f32::consts as float_consts;
+
+#[cfg(not(feature = "stable"))]
 mod defs;
+#[cfg(not(feature = "stable"))]
 mod coretypes;
+#[cfg(not(feature = "std"))]
+mod real;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+mod json;
 
+#[cfg(not(feature = "stable"))]
 pub mod math;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod array;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod grid;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod integration;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod lookup;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod bands;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod buffer;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod sweep;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod atomic;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod stats;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod stopwatch;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod uncertainty;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod fuzz;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod noise;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod tolerance;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod balance;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod systems;
+#[cfg(not(feature = "stable"))]
+pub mod parse;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod convert;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod excel;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod graph;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod migrate;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod display;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod dynamic;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod packed;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod telemetry;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod params;
+#[cfg(not(feature = "stable"))]
+pub mod embedded;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod bridge;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod sensors;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod calibration;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod monitor;
+#[cfg(all(feature = "inspector", not(feature = "stable")))]
+pub mod inspector;
+#[cfg(all(feature = "thermometry", not(feature = "stable")))]
+pub mod thermometry;
+#[cfg(all(feature = "serde", not(feature = "stable")))]
+pub mod serde;
+#[cfg(all(feature = "hdf5", not(feature = "stable")))]
+pub mod hdf5;
+#[cfg(all(feature = "netcdf", not(feature = "stable")))]
+pub mod netcdf;
+#[cfg(all(feature = "ndarray", not(feature = "stable")))]
+pub mod ndarray;
+#[cfg(all(feature = "rand", not(feature = "stable")))]
+pub mod rand;
+#[cfg(all(feature = "proptest", not(feature = "stable")))]
+pub mod proptest;
+#[cfg(all(feature = "quickcheck", not(feature = "stable")))]
+pub mod quickcheck;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod rf;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod relativity;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod quantum;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod radiation;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod decay;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod reliability;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod throughput;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod mechanics;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod rotation;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod motion;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod control;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod structures;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod geometry;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod drivetrain;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod motors;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod thermal;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod fluids;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod exchangers;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod hydraulics;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod aero;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod ballistics;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod geodesy;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod electrochem;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod battery;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod solar;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod acoustics;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod audio;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod pitch;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod photography;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod seismology;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod weather;
+#[cfg(all(feature = "std", not(feature = "stable")))]
+pub mod clinical;
+#[cfg(not(feature = "stable"))]
 pub use defs::{units,dimens,consts};
-pub use coretypes::{Quantity,Unit,OffsetUnit,LogUnit};
\ No newline at end of file
+#[cfg(not(feature = "stable"))]
+pub use coretypes::{Quantity,Product,Quotient,Power,AnyQuantity,Dimension,Unit,UnitSymbol,NamedUnit,OffsetUnit,LogUnit,ReciprocalUnit};
+#[cfg(not(feature = "stable"))]
+pub use dimtypes_macros::{check_dims,const_table,qty};
+
+#[cfg(feature = "stable")]
+pub mod stable;
+#[cfg(feature = "stable")]
+pub use stable::{Quantity,dimens,units,consts};
@@ -0,0 +1,93 @@
+//! Lookup tables of one quantity against another, interpolated linearly
+//!
+//! Characteristic curves from datasheets and calibrations, e.g. a thermistor's resistance against temperature or a cell's open
+//! circuit voltage against state of charge, are usually given as tables of points.  A [LookupTable] holds such a curve with both axes
+//! typed, interpolates linearly between points and holds the end values beyond the ends of the table rather than extrapolating.
+
+use std::cmp::Ordering;
+use std::fmt;
+use crate::{AnyQuantity,Float};
+
+/// Reason a set of points cannot form a [LookupTable]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableError {
+	/// The table has no points
+	Empty,
+	/// The input at this index is not greater than the one before it, or is NaN
+	NotIncreasing(usize),
+}
+
+impl fmt::Display for TableError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TableError::Empty => write!(f, "lookup table has no points"),
+			TableError::NotIncreasing(index) => write!(f, "lookup table input at index {} does not increase", index),
+		}
+	}
+}
+
+impl std::error::Error for TableError {}
+
+/// Piecewise linear curve giving an output of type `Y` for an input of type `X`
+#[derive(Clone, Debug)]
+pub struct LookupTable<X, Y> {
+	points: Vec<(X, Y)>,
+}
+
+impl<X: AnyQuantity, Y: AnyQuantity> LookupTable<X, Y> {
+	/// Table through `points` of (input, output), whose inputs must strictly increase
+	pub fn new(points: Vec<(X, Y)>) -> Result<LookupTable<X, Y>, TableError> {
+		if points.is_empty() {
+			return Err(TableError::Empty);
+		}
+		if let Some(index) = (1..points.len()).find(|&i| points[i].0.as_si().partial_cmp(&points[i - 1].0.as_si()) != Some(Ordering::Greater)) {
+			return Err(TableError::NotIncreasing(index));
+		}
+		Ok(LookupTable { points })
+	}
+
+	/// Table through `points` given in any order, sorted by input.  Fails on repeated inputs
+	pub fn from_unsorted(mut points: Vec<(X, Y)>) -> Result<LookupTable<X, Y>, TableError> {
+		points.sort_by(|a, b| a.0.as_si().total_cmp(&b.0.as_si()));
+		LookupTable::new(points)
+	}
+
+	pub fn points(&self) -> &[(X, Y)] {
+		&self.points
+	}
+
+	/// Range of inputs covered by the table
+	pub fn domain(&self) -> (X, X) {
+		(self.points[0].0, self.points[self.points.len() - 1].0)
+	}
+
+	/// Output at `x`, interpolated linearly between the neighbouring points and held at the end values outside the table
+	pub fn get(&self, x: X) -> Y {
+		let x = x.as_si();
+		let upper = self.points.partition_point(|(px, _)| px.as_si() < x);
+		if upper == 0 {
+			return self.points[0].1;
+		}
+		if upper == self.points.len() {
+			return self.points[upper - 1].1;
+		}
+		let ((x0, y0), (x1, y1)) = (self.points[upper - 1], self.points[upper]);
+		let fraction = (x - x0.as_si())/(x1.as_si() - x0.as_si());
+		Y::from_si(y0.as_si() + fraction*(y1.as_si() - y0.as_si()))
+	}
+
+	/// Table of the input against the output, e.g. for reading a sensor from its calibration curve.  Fails unless the outputs
+	/// strictly increase or strictly decrease
+	pub fn inverse(&self) -> Result<LookupTable<Y, X>, TableError> {
+		let mut points: Vec<(Y, X)> = self.points.iter().map(|&(x, y)| (y, x)).collect();
+		if points.len() > 1 && points[0].0.as_si() > points[points.len() - 1].0.as_si() {
+			points.reverse();
+		}
+		LookupTable::new(points)
+	}
+
+	/// Table with each output scaled by `factor`, e.g. to derate a curve
+	pub fn scaled(&self, factor: Float) -> LookupTable<X, Y> {
+		LookupTable { points: self.points.iter().map(|&(x, y)| (x, y*factor)).collect() }
+	}
+}
@@ -1,35 +1,91 @@
 //! Unit-aware variants of commmon mathematical function
 
-use crate::Quantity;
-use crate::dimens::Unitless;
+use crate::{Float,Quantity};
+#[cfg(not(feature = "std"))]
+use crate::real::Real;
+use crate::dimens::{Angle,Unitless};
 
-/// [f64::atan2] implemented on dimensioned types.  The dimension of `x` and `y` must be the same.  
-/// The result is a [Unitless] value representing the counterclockwise angle of the vector `[x,y]` with the x-axis.
-pub fn atan2<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize>
-	(x: Quantity<T,L,M,I,TEMP>, y: Quantity<T,L,M,I,TEMP>) -> Unitless {
-	Unitless::from(f64::atan2(x.as_si(),y.as_si()))
+/// [Float::atan2] implemented on dimensioned types.  The dimension of `x` and `y` must be the same.  
+/// The result is the counterclockwise [Angle] of the vector `[x,y]` with the x-axis.
+pub fn atan2<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(x: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, y: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Angle {
+	Angle::from_si(Float::atan2(x.as_si(),y.as_si()))
 }
 
 macro_rules! reimpl_f64_to_unitless
 {
 	($func:ident) => {
-		#[doc = concat!("Reimplementation of [f64::",stringify!($func),"] for [Unitless] types")]
-		pub fn $func(x: Unitless) -> Unitless { Unitless::from(f64::$func(x.into())) }
+		#[doc = concat!("Reimplementation of [Float::",stringify!($func),"] for [Unitless] types")]
+		pub fn $func(x: Unitless) -> Unitless { Unitless::from(Float::$func(x.into())) }
 	}
 }
 
-reimpl_f64_to_unitless!(sin);
-reimpl_f64_to_unitless!(cos);
-reimpl_f64_to_unitless!(tan);
+macro_rules! reimpl_angle_to_unitless
+{
+	($func:ident) => {
+		#[doc = concat!("Reimplementation of [Float::",stringify!($func),"] taking an [Angle]")]
+		pub fn $func(x: Angle) -> Unitless { Unitless::from(Float::$func(x.as_si())) }
+	}
+}
+
+macro_rules! reimpl_unitless_to_angle
+{
+	($func:ident) => {
+		#[doc = concat!("Reimplementation of [Float::",stringify!($func),"] returning an [Angle]")]
+		pub fn $func(x: Unitless) -> Angle { Angle::from_si(Float::$func(x.into())) }
+	}
+}
+
+reimpl_angle_to_unitless!(sin);
+reimpl_angle_to_unitless!(cos);
+reimpl_angle_to_unitless!(tan);
 reimpl_f64_to_unitless!(sinh);
 reimpl_f64_to_unitless!(cosh);
 reimpl_f64_to_unitless!(tanh);
-reimpl_f64_to_unitless!(asin);
-reimpl_f64_to_unitless!(acos);
-reimpl_f64_to_unitless!(atan);
+reimpl_unitless_to_angle!(asin);
+reimpl_unitless_to_angle!(acos);
+reimpl_unitless_to_angle!(atan);
 reimpl_f64_to_unitless!(asinh);
 reimpl_f64_to_unitless!(acosh);
 reimpl_f64_to_unitless!(atanh);
 reimpl_f64_to_unitless!(ln);
 reimpl_f64_to_unitless!(log10);
 reimpl_f64_to_unitless!(exp);
+
+/// Find a root of `f` between `low` and `high` by the Illinois variant of regula falsi.  `f(low)` and `f(high)` must have opposite
+/// signs, otherwise [None] is returned.  Iteration stops once the bracket is narrower than `tolerance` or successive estimates differ
+/// by less than it
+pub fn find_root<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+	(f: impl Fn(Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>) -> Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>, low: Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>, high: Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>,
+	tolerance: Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>) -> Option<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> {
+	let g = |x: Float| f(Quantity::from_si(x)).as_si();
+	let (mut a, mut b) = (low.as_si(), high.as_si());
+	let (mut fa, mut fb) = (g(a), g(b));
+	if fa == 0.0 { return Some(low); }
+	if fb == 0.0 { return Some(high); }
+	if fa.signum() == fb.signum() || fa.is_nan() || fb.is_nan() {
+		return None;
+	}
+	let tolerance = tolerance.as_si().abs();
+	let mut side = 0;
+	let mut previous = Float::NAN;
+	for _ in 0..200 {
+		let c = (a*fb - b*fa)/(fb - fa);
+		let fc = g(c);
+		if fc == 0.0 || (b - a).abs() <= tolerance || (c - previous).abs() <= tolerance {
+			return Some(Quantity::from_si(c));
+		}
+		previous = c;
+		if fc.signum() == fb.signum() {
+			b = c; fb = fc;
+			if side == -1 { fa /= 2.0; }
+			side = -1;
+		} else {
+			a = c; fa = fc;
+			if side == 1 { fb /= 2.0; }
+			side = 1;
+		}
+	}
+	Some(Quantity::from_si((a*fb - b*fa)/(fb - fa)))
+}
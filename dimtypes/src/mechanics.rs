@@ -0,0 +1,87 @@
+//! Helpers for common solid mechanics relations
+//!
+//! [Stress], [Strain] and [YoungsModulus] are aliases of [Pressure] and [Unitless] rather than separately tagged kinds, since units,
+//! parsing and arithmetic are all defined over dimensions.  They name the role of a value in these signatures, but do not stop a
+//! pressure being passed as a stress
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::RADIAN;
+
+/// Engineering strain of a member stretched by `elongation` from its original length `length`
+pub fn engineering_strain(elongation: Length, length: Length) -> Strain {
+	elongation/length
+}
+
+/// Uniaxial stress in a linear elastic material with modulus `modulus` under `strain` (Hooke's law, σ = E·ε)
+pub fn stress_from_strain(modulus: YoungsModulus, strain: Strain) -> Stress {
+	modulus*strain
+}
+
+/// Uniaxial strain in a linear elastic material with modulus `modulus` under `stress` (ε = σ/E)
+pub fn strain_from_stress(modulus: YoungsModulus, stress: Stress) -> Strain {
+	stress/modulus
+}
+
+/// Young's modulus estimated from a measured `stress` and `strain` pair in the linear region
+pub fn youngs_modulus(stress: Stress, strain: Strain) -> YoungsModulus {
+	stress/strain
+}
+
+/// Average normal stress from a `force` carried over the cross-sectional `area`
+pub fn axial_stress(force: Force, area: Area) -> Stress {
+	force/area
+}
+
+/// Undamped natural angular frequency ω<sub>n</sub> = √(k/m) of a mass `mass` on a spring of stiffness `stiffness`.  Being an
+/// [AngularVelocity], it converts to cycles per second as `.as_unit(TURN/SECOND)`, not `HERTZ`
+pub fn natural_frequency(stiffness: SpringConstant, mass: Mass) -> AngularVelocity {
+	(stiffness/mass).root::<2>()*RADIAN
+}
+
+/// Critical damping coefficient c<sub>c</sub> = 2√(k·m) for a mass `mass` on a spring of stiffness `stiffness`
+pub fn critical_damping(stiffness: SpringConstant, mass: Mass) -> DampingCoefficient {
+	2.0*(stiffness*mass).root::<2>()
+}
+
+/// Damping ratio ζ = c/c<sub>c</sub> of a mass-spring-damper system
+pub fn damping_ratio(damping: DampingCoefficient, stiffness: SpringConstant, mass: Mass) -> Unitless {
+	damping/critical_damping(stiffness, mass)
+}
+
+/// Damped natural angular frequency ω<sub>d</sub> = ω<sub>n</sub>√(1-ζ²) of an underdamped mass-spring-damper system.  Returns NaN
+/// for critically damped or overdamped systems
+pub fn damped_natural_frequency(damping: DampingCoefficient, stiffness: SpringConstant, mass: Mass) -> AngularVelocity {
+	let zeta: Float = damping_ratio(damping, stiffness, mass).into();
+	natural_frequency(stiffness, mass)*(1.0 - zeta*zeta).sqrt()
+}
+
+/// Static deflection of a spring of stiffness `stiffness` under a `load`
+pub fn spring_deflection(stiffness: SpringConstant, load: Force) -> Length {
+	load/stiffness
+}
+
+/// Effective stiffness of two springs in series
+pub fn springs_in_series(k1: SpringConstant, k2: SpringConstant) -> SpringConstant {
+	k1*k2/(k1 + k2)
+}
+
+/// Wheel rate seen at the tire for a suspension spring of stiffness `spring_rate` mounted at `motion_ratio` (spring travel per unit wheel travel)
+pub fn wheel_rate(spring_rate: SpringConstant, motion_ratio: Unitless) -> SpringConstant {
+	spring_rate*motion_ratio*motion_ratio
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn natural_frequency_is_angular() {
+		let omega = natural_frequency(1.0*NEWTON/METER, 1.0*KILO*GRAM);
+		assert!((omega.as_unit(RADIAN/SECOND) - 1.0).abs() < 1e-12);
+		assert!((omega.as_unit(TURN/SECOND) - 0.159155).abs() < 1e-6);
+		let damped = damped_natural_frequency(1.2*NEWTON*SECOND/METER, 1.0*NEWTON/METER, 1.0*KILO*GRAM);
+		assert!((damped.as_unit(RADIAN/SECOND) - 0.8).abs() < 1e-12);
+	}
+}
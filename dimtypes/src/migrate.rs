@@ -0,0 +1,346 @@
+//! Migration of existing code storing physical quantities in raw [Float] fields
+//!
+//! A [Shim] is told which fields of an existing struct hold quantities, the type each should become and the unit its raw value is in,
+//! e.g. `torque` as `Torque` in `lbf ft`.  [Shim::to_rust] writes Rust source for a typed copy of the struct with `From` conversions in
+//! both directions, so the typed struct can be adopted one function at a time while the rest of the code keeps the raw one.  The
+//! generated code asserts at compile time that each unit has the dimension of its type, as [const_table!][crate::const_table] does.
+//!
+//! [Shim::ambiguities] scans existing source for uses of the annotated fields whose units cannot be told from the code: a field added
+//! to, compared with or assigned a bare number, combined with a field in other units, or passed to a function.  These are the places
+//! to review by hand when moving to the typed struct.  The scan works on tokens, not a parsed syntax tree, so it may miss or over-report
+//! uses in unusual code.
+
+use std::fmt;
+use std::fmt::Write;
+use crate::parse::{parse_unit,ParsedUnit,UnitParseError};
+
+/// Error annotating a field of a [Shim]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MigrateError {
+	/// The field is already annotated
+	Duplicate(String),
+	/// The unit of a field could not be parsed
+	Units { field: String, error: UnitParseError },
+}
+
+impl fmt::Display for MigrateError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MigrateError::Duplicate(field) => write!(f, "field '{}' is already annotated", field),
+			MigrateError::Units { field, error } => write!(f, "field '{}': {}", field, error),
+		}
+	}
+}
+
+impl std::error::Error for MigrateError {}
+
+/// A field of the raw struct and what it becomes in the typed one
+#[derive(Clone, Debug)]
+pub struct ShimField {
+	pub name: String,
+	/// Type of the field in the typed struct, e.g. `Torque`, or the raw type of a field carried over unchanged
+	pub ty: String,
+	/// Unit the raw value is in, as written in the annotation, or `None` for a field carried over unchanged
+	pub units: Option<String>,
+	pub unit: Option<ParsedUnit>,
+}
+
+/// Annotations of the fields of a raw struct, generating a typed struct and the conversions between them
+#[derive(Clone, Debug)]
+pub struct Shim {
+	raw: String,
+	typed: String,
+	fields: Vec<ShimField>,
+}
+
+impl Shim {
+	/// Shim from the existing struct `raw` to a new struct `typed`
+	pub fn new(raw: &str, typed: &str) -> Shim {
+		Shim { raw: raw.to_string(), typed: typed.to_string(), fields: Vec::new() }
+	}
+
+	pub fn fields(&self) -> &[ShimField] { &self.fields }
+
+	/// The annotation of field `name`, if there is one
+	pub fn get(&self, name: &str) -> Option<&ShimField> {
+		self.fields.iter().find(|f| f.name == name)
+	}
+
+	/// Annotate the raw [Float] field `name` as holding a quantity of type `ty` in `units`, e.g.
+	/// `quantity("torque", "Torque", "lbf ft")`.  Returns the parsed unit
+	pub fn quantity(&mut self, name: &str, ty: &str, units: &str) -> Result<ParsedUnit, MigrateError> {
+		if self.get(name).is_some() {
+			return Err(MigrateError::Duplicate(name.to_string()));
+		}
+		let unit = parse_unit(units).map_err(|error| MigrateError::Units { field: name.to_string(), error })?;
+		self.fields.push(ShimField { name: name.to_string(), ty: ty.to_string(), units: Some(units.to_string()), unit: Some(unit) });
+		Ok(unit)
+	}
+
+	/// Carry field `name` of type `ty` over to the typed struct unchanged
+	pub fn keep(&mut self, name: &str, ty: &str) -> Result<(), MigrateError> {
+		if self.get(name).is_some() {
+			return Err(MigrateError::Duplicate(name.to_string()));
+		}
+		self.fields.push(ShimField { name: name.to_string(), ty: ty.to_string(), units: None, unit: None });
+		Ok(())
+	}
+
+	/// Name of the associated constant holding the unit of a field in the generated code
+	fn unit_const(field: &ShimField) -> String {
+		format!("{}_UNIT", field.name.trim_start_matches("r#").to_uppercase())
+	}
+
+	/// Rust source of the typed struct, the constants holding the unit of each annotated field, compile-time checks of their dimensions
+	/// and `From` impls converting to and from the raw struct.  Paths to this crate are absolute, and the quantity types named in the
+	/// annotations must be in scope where the code is included.  As in parsing a quantity, a unit such as `lbf ft` which leaves out the
+	/// radian of a per-radian type such as `Torque` is taken as that type
+	pub fn to_rust(&self) -> String {
+		let (raw, typed) = (&self.raw, &self.typed);
+		let mut out = String::new();
+		let _ = writeln!(out, "/// Typed copy of [{}], generated by `dimtypes::migrate`", raw);
+		let _ = writeln!(out, "#[derive(Clone, Debug)]");
+		let _ = writeln!(out, "pub struct {} {{", typed);
+		for field in &self.fields {
+			if let Some(units) = &field.units {
+				let _ = writeln!(out, "\t/// Raw value in {}", units);
+			}
+			let _ = writeln!(out, "\tpub {}: {},", field.name, field.ty);
+		}
+		out.push_str("}\n\n");
+
+		let _ = writeln!(out, "impl {} {{", typed);
+		for field in self.fields.iter().filter(|f| f.unit.is_some()) {
+			let unit = field.unit.expect("filtered on unit");
+			let d = unit.dimension();
+			let _ = writeln!(out, "\t/// Unit of `{}.{}`, {}", raw, field.name, field.units.as_deref().unwrap_or_default());
+			let _ = writeln!(out, "\tpub const {}: ::dimtypes::parse::ParsedUnit = ::dimtypes::parse::ParsedUnit::with_offset({:?}, {:?},",
+				Shim::unit_const(field), unit.scale(), unit.offset());
+			let _ = writeln!(out, "\t\t::dimtypes::Dimension::new({},{},{},{},{},{},{},{},{}).over({}))", d.time, d.length, d.mass, d.current,
+				d.temperature, d.amount, d.luminous_intensity, d.angle, d.count, d.denominator);
+			let _ = writeln!(out, "\t\t.per_radian_as(<{} as ::dimtypes::AnyQuantity>::DIMENSION);", field.ty);
+		}
+		out.push_str("}\n\n");
+
+		for field in self.fields.iter().filter(|f| f.unit.is_some()) {
+			let message = format!("unit `{}` of {}.{} does not have the dimension of `{}`", field.units.as_deref().unwrap_or_default(),
+				raw, field.name, field.ty);
+			let _ = writeln!(out, "const _: () = ::core::assert!({}::{}.dimension().equals(&<{} as ::dimtypes::AnyQuantity>::DIMENSION), {:?});",
+				typed, Shim::unit_const(field), field.ty, message);
+		}
+		if self.fields.iter().any(|f| f.unit.is_some()) {
+			out.push('\n');
+		}
+
+		let _ = writeln!(out, "impl From<{}> for {} {{", raw, typed);
+		let _ = writeln!(out, "\tfn from(raw: {}) -> {} {{", raw, typed);
+		let _ = writeln!(out, "\t\t{} {{", typed);
+		for field in &self.fields {
+			if field.unit.is_some() {
+				let _ = writeln!(out, "\t\t\t{}: <{} as ::dimtypes::AnyQuantity>::from_si({}::{}.to_si(raw.{})),", field.name, field.ty, typed,
+					Shim::unit_const(field), field.name);
+			} else {
+				let _ = writeln!(out, "\t\t\t{}: raw.{},", field.name, field.name);
+			}
+		}
+		out.push_str("\t\t}\n\t}\n}\n\n");
+
+		let _ = writeln!(out, "impl From<{}> for {} {{", typed, raw);
+		let _ = writeln!(out, "\tfn from(typed: {}) -> {} {{", typed, raw);
+		let _ = writeln!(out, "\t\t{} {{", raw);
+		for field in &self.fields {
+			if field.unit.is_some() {
+				let _ = writeln!(out, "\t\t\t{}: {}::{}.from_si(::dimtypes::AnyQuantity::as_si(typed.{})),", field.name, typed,
+					Shim::unit_const(field), field.name);
+			} else {
+				let _ = writeln!(out, "\t\t\t{}: typed.{},", field.name, field.name);
+			}
+		}
+		out.push_str("\t\t}\n\t}\n}\n");
+		out
+	}
+
+	/// Uses of the annotated fields in `source` whose units are ambiguous, in order of appearance.  Fields are recognized by name after a
+	/// `.`, whatever the type of the value they are read from
+	pub fn ambiguities(&self, source: &str) -> Vec<Ambiguity> {
+		let tokens = tokenize(source);
+		let field_at = |i: usize| -> Option<&ShimField> {
+			if i == 0 || i >= tokens.len() || !tokens[i - 1].is(".") || tokens.get(i + 1).is_some_and(|t| t.is("(")) {
+				return None;
+			}
+			self.get(&tokens[i].text).filter(|f| f.unit.is_some())
+		};
+		let mut found = Vec::new();
+		for i in 0..tokens.len() {
+			let Some(field) = field_at(i) else { continue };
+			let unit = field.unit.expect("field_at only returns annotated fields");
+			let site = |kind| Ambiguity { line: tokens[i].line, column: tokens[i].column, field: field.name.clone(), kind };
+
+			// Start of the expression the field is read from, e.g. `self` in `self.motor.torque`
+			let mut start = i;
+			while start >= 2 && tokens[start - 1].is(".") && tokens[start - 2].is_ident() {
+				start -= 2;
+			}
+
+			if let Some(op) = tokens.get(i + 1).filter(|t| AMBIGUOUS_OPERATORS.contains(&t.text.as_str())) {
+				if tokens.get(i + 2).is_some_and(Token::is_number) {
+					found.push(site(AmbiguityKind::BareNumber { operator: op.text.clone() }));
+				} else if let Some(other) = (i + 3..tokens.len()).take_while(|&j| tokens[j].is_ident() || tokens[j].is("."))
+					.filter_map(field_at).last() {
+					let other_unit = other.unit.expect("field_at only returns annotated fields");
+					if other_unit.dimension() != unit.dimension() {
+						found.push(site(AmbiguityKind::MixedDimensions { operator: op.text.clone(), other: other.name.clone() }));
+					} else if other_unit != unit {
+						found.push(site(AmbiguityKind::MixedUnits { operator: op.text.clone(), other: other.name.clone() }));
+					}
+				}
+			}
+			if start >= 2 && AMBIGUOUS_OPERATORS.contains(&tokens[start - 1].text.as_str()) && tokens[start - 2].is_number() {
+				found.push(site(AmbiguityKind::BareNumber { operator: tokens[start - 1].text.clone() }));
+			}
+			if start >= 1 && (tokens[start - 1].is("(") || tokens[start - 1].is(","))
+				&& tokens.get(i + 1).is_some_and(|t| t.is(")") || t.is(","))
+				&& let Some(callee) = &tokens[start - 1].callee {
+				found.push(site(AmbiguityKind::Argument { callee: callee.clone() }));
+			}
+		}
+		found
+	}
+}
+
+/// A use of an annotated field whose units cannot be told from the code, found by [Shim::ambiguities]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ambiguity {
+	/// Line of the field name in the source, counting from 1
+	pub line: usize,
+	/// Column of the field name in characters, counting from 1
+	pub column: usize,
+	pub field: String,
+	pub kind: AmbiguityKind,
+}
+
+/// Why the units of a use of a field are ambiguous
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmbiguityKind {
+	/// The field is combined with, compared with or assigned a number whose unit is not written
+	BareNumber { operator: String },
+	/// The field is combined with, compared with or assigned a field of another dimension
+	MixedDimensions { operator: String, other: String },
+	/// The field is combined with, compared with or assigned a field of the same dimension in a different unit
+	MixedUnits { operator: String, other: String },
+	/// The field is passed to a function or method, which may expect another unit
+	Argument { callee: String },
+}
+
+impl fmt::Display for Ambiguity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}:{}: '{}' ", self.line, self.column, self.field)?;
+		match &self.kind {
+			AmbiguityKind::BareNumber { operator } => write!(f, "used with a bare number through '{}'", operator),
+			AmbiguityKind::MixedDimensions { operator, other } => write!(f, "used with '{}' of another dimension through '{}'", other, operator),
+			AmbiguityKind::MixedUnits { operator, other } => write!(f, "used with '{}' in another unit through '{}'", other, operator),
+			AmbiguityKind::Argument { callee } => write!(f, "passed to '{}'", callee),
+		}
+	}
+}
+
+/// Operators whose operands must be in the same unit
+const AMBIGUOUS_OPERATORS: &[&str] = &["+", "-", "=", "+=", "-=", "==", "!=", "<", ">", "<=", ">="];
+
+struct Token {
+	text: String,
+	line: usize,
+	column: usize,
+	/// For `(` and `,`, the name of the function or method called with the enclosing parentheses
+	callee: Option<String>,
+}
+
+impl Token {
+	fn is(&self, text: &str) -> bool { self.text == text }
+	fn is_ident(&self) -> bool {
+		self.text.starts_with(|c: char| c.is_alphabetic() || c == '_')
+	}
+	fn is_number(&self) -> bool {
+		self.text.starts_with(|c: char| c.is_ascii_digit())
+	}
+}
+
+/// Split Rust source into identifiers, numbers and punctuation, skipping whitespace, comments, strings and character literals
+fn tokenize(source: &str) -> Vec<Token> {
+	let chars: Vec<char> = source.chars().collect();
+	let mut tokens: Vec<Token> = Vec::new();
+	// Callee of each open parenthesis, None for grouping parentheses
+	let mut calls: Vec<Option<String>> = Vec::new();
+	let (mut i, mut line, mut line_start) = (0, 1, 0);
+	while i < chars.len() {
+		let c = chars[i];
+		let column = i - line_start + 1;
+		let begin = i;
+		if c == '\n' {
+			line += 1;
+			line_start = i + 1;
+			i += 1;
+			continue;
+		} else if c.is_whitespace() {
+			i += 1;
+			continue;
+		} else if c == '/' && chars.get(i + 1) == Some(&'/') {
+			while i < chars.len() && chars[i] != '\n' {
+				i += 1;
+			}
+			continue;
+		} else if c == '/' && chars.get(i + 1) == Some(&'*') {
+			i += 2;
+			while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+				if chars[i] == '\n' {
+					line += 1;
+					line_start = i + 1;
+				}
+				i += 1;
+			}
+			i += 2;
+			continue;
+		} else if c == '"' || (c == '\'' && chars.get(i + 2) == Some(&'\'')) || (c == '\'' && chars.get(i + 1) == Some(&'\\')) {
+			i += 1;
+			while i < chars.len() && chars[i] != c {
+				if chars[i] == '\\' {
+					i += 1;
+				} else if chars[i] == '\n' {
+					line += 1;
+					line_start = i + 1;
+				}
+				i += 1;
+			}
+			i += 1;
+			continue;
+		}
+
+		let text: String = if c.is_alphanumeric() || c == '_' {
+			let number = c.is_ascii_digit();
+			while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_'
+				|| (number && chars[i] == '.' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+				|| (number && matches!(chars[i], '+' | '-') && matches!(chars[i - 1], 'e' | 'E'))) {
+				i += 1;
+			}
+			chars[begin..i].iter().collect()
+		} else {
+			let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+			i += if ["==", "!=", "<=", ">=", "+=", "-=", "::", "->", "=>", "&&", "||"].contains(&pair.as_str()) { 2 } else { 1 };
+			chars[begin..i].iter().collect()
+		};
+
+		let callee = match text.as_str() {
+			"(" => {
+				let callee = tokens.last().filter(|t| t.is_ident() && !["if", "while", "match", "return", "in"].contains(&t.text.as_str()))
+					.map(|t| t.text.clone());
+				calls.push(callee.clone());
+				callee
+			},
+			")" => { calls.pop(); None },
+			"," => calls.last().cloned().flatten(),
+			_ => None,
+		};
+		tokens.push(Token { text, line, column, callee });
+	}
+	tokens
+}
@@ -0,0 +1,142 @@
+//! Alarm monitoring of a process variable against warning and critical thresholds
+//!
+//! A [Monitor] follows a stream of samples of one quantity, e.g. a bearing temperature or a tank level, and raises [Level::Warning]
+//! and [Level::Critical] as it crosses the configured thresholds.  As in industrial alarm systems, a level clears only once the
+//! variable is back past its threshold by a hysteresis deadband, and raising and clearing can be delayed until the condition has held
+//! for a minimum dwell time, so a noisy signal sitting on a threshold does not flood the operator with events.  Each change of level
+//! is returned from [Monitor::update] as an [AlarmEvent].
+
+use crate::{AnyQuantity,Float};
+use crate::dimens::Time;
+
+/// Severity of the state of a monitored variable, ordered from [Level::Normal] to [Level::Critical]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+	Normal,
+	Warning,
+	Critical,
+}
+
+/// Change of level of a [Monitor]
+#[derive(Clone, Copy, Debug)]
+pub struct AlarmEvent<D> {
+	pub from: Level,
+	pub to: Level,
+	/// Sample which caused the change
+	pub value: D,
+	/// Time of the sample, as the sum of the time steps passed to [Monitor::update]
+	pub time: Time,
+}
+
+impl<D> AlarmEvent<D> {
+	/// Whether the event raises the level rather than clearing it
+	pub fn is_raise(&self) -> bool {
+		self.to > self.from
+	}
+}
+
+/// Alarm state of a process variable of type `D`, alarming when it rises above or falls below its thresholds
+#[derive(Clone, Copy, Debug)]
+pub struct Monitor<D> {
+	/// Warning and critical thresholds
+	thresholds: [D; 2],
+	/// 1 to alarm above the thresholds, -1 below
+	direction: Float,
+	hysteresis: D,
+	raise_delay: Time,
+	clear_delay: Time,
+	level: Level,
+	time: Time,
+	/// Times since which each threshold has been continuously exceeded
+	exceeded_since: [Option<Time>; 2],
+	/// Times since which the variable has continuously been back inside each threshold by the hysteresis
+	cleared_since: [Option<Time>; 2],
+}
+
+impl<D: AnyQuantity> Monitor<D> {
+	fn new(warning: D, critical: D, direction: Float) -> Monitor<D> {
+		let zero = Time::from_si(0.0);
+		Monitor {
+			thresholds: [warning, critical], direction, hysteresis: D::from_si(0.0), raise_delay: zero, clear_delay: zero,
+			level: Level::Normal, time: zero, exceeded_since: [None; 2], cleared_since: [None; 2],
+		}
+	}
+
+	/// Monitor alarming when the variable rises above `warning` and `critical`, e.g. an overtemperature.  Panics unless `critical` is
+	/// at or above `warning`
+	pub fn high(warning: D, critical: D) -> Monitor<D> {
+		assert!(critical.as_si() >= warning.as_si(), "critical threshold must be at or above the warning threshold");
+		Monitor::new(warning, critical, 1.0)
+	}
+
+	/// Monitor alarming when the variable falls below `warning` and `critical`, e.g. a low tank level.  Panics unless `critical` is
+	/// at or below `warning`
+	pub fn low(warning: D, critical: D) -> Monitor<D> {
+		assert!(critical.as_si() <= warning.as_si(), "critical threshold must be at or below the warning threshold");
+		Monitor::new(warning, critical, -1.0)
+	}
+
+	/// Clear a level only once the variable is back inside its threshold by `hysteresis`.  Should exceed the noise band of the signal
+	pub fn hysteresis(self, hysteresis: D) -> Monitor<D> {
+		Monitor { hysteresis: D::from_si(hysteresis.as_si().abs()), ..self }
+	}
+
+	/// Raise a level only once its threshold has been exceeded for `raise`, and clear it only once the variable has been back inside
+	/// for `clear`.  Both are zero by default, acting on the first sample past a threshold
+	pub fn dwell(self, raise: Time, clear: Time) -> Monitor<D> {
+		Monitor { raise_delay: raise, clear_delay: clear, ..self }
+	}
+
+	pub fn level(&self) -> Level {
+		self.level
+	}
+
+	/// The warning and critical thresholds
+	pub fn thresholds(&self) -> (D, D) {
+		(self.thresholds[0], self.thresholds[1])
+	}
+
+	/// Return to [Level::Normal] and forget how long thresholds have been exceeded, e.g. after the operator acknowledges a fault
+	/// which has been repaired
+	pub fn reset(&mut self) {
+		self.level = Level::Normal;
+		self.exceeded_since = [None; 2];
+		self.cleared_since = [None; 2];
+	}
+
+	/// Process `sample`, taken `dt` after the previous one, returning the change of level it causes, if any.  A NaN sample neither
+	/// exceeds nor clears any threshold, so it restarts the dwell timers without changing the level
+	pub fn update(&mut self, sample: D, dt: Time) -> Option<AlarmEvent<D>> {
+		self.time += dt;
+		let value = self.direction*sample.as_si();
+		for k in 0..2 {
+			let threshold = self.direction*self.thresholds[k].as_si();
+			let exceeded = value >= threshold;
+			let cleared = value < threshold - self.hysteresis.as_si();
+			self.exceeded_since[k] = if exceeded { self.exceeded_since[k].or(Some(self.time)) } else { None };
+			self.cleared_since[k] = if cleared { self.cleared_since[k].or(Some(self.time)) } else { None };
+		}
+
+		let held = |since: Option<Time>, delay: Time| since.is_some_and(|since| (self.time - since).as_si() >= delay.as_si());
+		let levels = [Level::Warning, Level::Critical];
+		let mut level = self.level;
+		if let Some(k) = (0..2).rev().find(|&k| levels[k] > level && held(self.exceeded_since[k], self.raise_delay)) {
+			level = levels[k];
+		} else {
+			while level > Level::Normal {
+				let k = level as usize - 1;
+				if !held(self.cleared_since[k], self.clear_delay) {
+					break;
+				}
+				level = if k == 0 { Level::Normal } else { levels[k - 1] };
+			}
+		}
+
+		if level == self.level {
+			return None;
+		}
+		let event = AlarmEvent { from: self.level, to: level, value: sample, time: self.time };
+		self.level = level;
+		Some(event)
+	}
+}
@@ -0,0 +1,165 @@
+//! Point-to-point motion profiles for motion control
+//!
+//! A [MotionProfile] moves an axis a given distance from rest to rest within limits on velocity and acceleration, and for an S-curve
+//! profile on jerk.  Trapezoidal profiles change acceleration instantly, which is simplest but excites vibration in the mechanism;
+//! S-curve profiles ramp the acceleration at a limited [Jerk] instead.  Either is sampled at any time into a [MotionState] of typed
+//! position, velocity, acceleration and jerk, e.g. to feed setpoints to a servo loop.  Profiles which are too short to reach the
+//! velocity or acceleration limit peak below it.
+
+use crate::Float;
+use crate::dimens::*;
+
+/// Position and its derivatives at one instant of a [MotionProfile]
+#[derive(Clone, Copy, Debug)]
+pub struct MotionState {
+	pub position: Length,
+	pub velocity: Velocity,
+	pub acceleration: Acceleration,
+	pub jerk: Jerk,
+}
+
+/// State at rest at the origin
+const REST: MotionState = MotionState {
+	position: Length::from_si(0.0),
+	velocity: Velocity::from_si(0.0),
+	acceleration: Acceleration::from_si(0.0),
+	jerk: Jerk::from_si(0.0),
+};
+
+/// Interval of constant jerk, with the state at its start
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+	duration: Time,
+	start: MotionState,
+}
+
+impl Segment {
+	/// State `t` into the segment
+	fn at(&self, t: Time) -> MotionState {
+		let MotionState { position, velocity, acceleration, jerk } = self.start;
+		MotionState {
+			position: position + velocity*t + acceleration*t*t/2.0 + jerk*t*t*t/6.0,
+			velocity: velocity + acceleration*t + jerk*t*t/2.0,
+			acceleration: acceleration + jerk*t,
+			jerk,
+		}
+	}
+}
+
+/// Rest-to-rest move planned by [MotionProfile::trapezoidal] or [MotionProfile::s_curve]
+#[derive(Clone, Debug)]
+pub struct MotionProfile {
+	segments: Vec<Segment>,
+}
+
+impl MotionProfile {
+	/// Profile accelerating at `max_acceleration` up to `max_velocity`, cruising, then decelerating to stop after `distance`
+	pub fn trapezoidal(distance: Length, max_velocity: Velocity, max_acceleration: Acceleration) -> MotionProfile {
+		let (d, vmax, amax) = (distance.as_si().abs(), max_velocity.as_si(), max_acceleration.as_si());
+		// Peak velocity, limited by the distance available to reach it and stop again (d = v²/a)
+		let v = vmax.min((d*amax).sqrt());
+		let ramp = v/amax;
+		let cruise = if v > 0.0 { d/v - ramp } else { 0.0 };
+		MotionProfile::build(distance, &[(ramp, amax, 0.0), (cruise, 0.0, 0.0), (ramp, -amax, 0.0)])
+	}
+
+	/// Profile whose acceleration ramps at `max_jerk` up to `max_acceleration`, reaching `max_velocity` and stopping after `distance`
+	/// with the acceleration ramped down the same way
+	pub fn s_curve(distance: Length, max_velocity: Velocity, max_acceleration: Acceleration, max_jerk: Jerk) -> MotionProfile {
+		let (d, vmax, amax, j) = (distance.as_si().abs(), max_velocity.as_si(), max_acceleration.as_si(), max_jerk.as_si());
+		// Distance to reach velocity v and stop again, with symmetric acceleration and deceleration phases: each phase takes
+		// 2√(v/j) if v is too low to reach the acceleration limit and v/a + a/j otherwise, covering v/2 times that
+		let a_limited = amax*amax/j;
+		let v_distance = |v: Float| if v < a_limited { 2.0*v*(v/j).sqrt() } else { v*(v/amax + amax/j) };
+		let v = if v_distance(vmax) <= d {
+			vmax
+		} else if v_distance(a_limited) >= d {
+			(d*j.sqrt()/2.0).powf(2.0/3.0)
+		} else {
+			amax/2.0*(-amax/j + (amax*amax/(j*j) + 4.0*d/amax).sqrt())
+		};
+		let (ramp, peak) = if v < a_limited { ((v/j).sqrt(), (v*j).sqrt()) } else { (amax/j, amax) };
+		let hold = if v > 0.0 { v/peak - ramp } else { 0.0 };
+		let cruise = if v > 0.0 { d/v - (2.0*ramp + hold) } else { 0.0 };
+		MotionProfile::build(distance, &[
+			(ramp, 0.0, j), (hold, peak, 0.0), (ramp, peak, -j),
+			(cruise, 0.0, 0.0),
+			(ramp, 0.0, -j), (hold, -peak, 0.0), (ramp, -peak, j),
+		])
+	}
+
+	/// Chain segments given as (duration, starting acceleration, jerk) in SI units for a positive move, mirrored for negative `distance`
+	fn build(distance: Length, phases: &[(Float, Float, Float)]) -> MotionProfile {
+		let sign = distance.as_si().signum();
+		let mut state = REST;
+		let mut segments = Vec::with_capacity(phases.len());
+		for &(duration, acceleration, jerk) in phases {
+			let duration = Time::from_si(duration.max(0.0));
+			state.acceleration = Acceleration::from_si(sign*acceleration);
+			state.jerk = Jerk::from_si(sign*jerk);
+			let segment = Segment { duration, start: state };
+			state = segment.at(duration);
+			segments.push(segment);
+		}
+		MotionProfile { segments }
+	}
+
+	/// Time taken by the whole move
+	pub fn duration(&self) -> Time {
+		self.segments.iter().fold(Time::from_si(0.0), |total, s| total + s.duration)
+	}
+
+	/// Highest speed reached during the move
+	pub fn peak_velocity(&self) -> Velocity {
+		let peak = self.segments.iter().map(|s| s.at(s.duration).velocity.as_si().abs()).fold(0.0, Float::max);
+		Velocity::from_si(peak)
+	}
+
+	/// State at time `t` from the start of the move, at rest at either end outside the move
+	pub fn at(&self, t: Time) -> MotionState {
+		let mut t = Time::from_si(t.as_si().max(0.0));
+		for segment in &self.segments {
+			if t.as_si() <= segment.duration.as_si() {
+				return segment.at(t);
+			}
+			t -= segment.duration;
+		}
+		let last = self.segments[self.segments.len() - 1];
+		MotionState { position: last.at(last.duration).position, ..REST }
+	}
+
+	/// States every `interval` from the start of the move up to and including its end, paired with their times.  Panics unless
+	/// `interval` is positive
+	pub fn samples(&self, interval: Time) -> impl Iterator<Item = (Time, MotionState)> + '_ {
+		assert!(interval.as_si() > 0.0, "sampling interval must be positive");
+		let duration = self.duration();
+		let steps = (duration/interval).as_si().ceil() as usize;
+		(0..=steps).map(move |i| {
+			let t = if i == steps { duration } else { interval*(i as Float) };
+			(t, self.at(t))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn samples_cover_move() {
+		let profile = MotionProfile::trapezoidal(1.0*METER, 1.0*METER/SECOND, 1.0*METER/SECOND/SECOND);
+		let samples: Vec<_> = profile.samples(0.3*SECOND).collect();
+		assert_eq!(samples[0].0.as_si(), 0.0);
+		let (t, end) = samples[samples.len() - 1];
+		assert_eq!(t.as_si(), profile.duration().as_si());
+		assert!((end.position.as_si() - 1.0).abs() < 1e-5);
+	}
+
+	#[test]
+	#[should_panic(expected = "interval must be positive")]
+	fn samples_zero_interval() {
+		let profile = MotionProfile::trapezoidal(1.0*METER, 1.0*METER/SECOND, 1.0*METER/SECOND/SECOND);
+		let _ = profile.samples(0.0*SECOND);
+	}
+}
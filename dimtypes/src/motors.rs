@@ -0,0 +1,82 @@
+//! Brushed and brushless DC motor constants
+//!
+//! The velocity constant K<sub>v</sub> is quoted either in rpm/V or in rad/s/V, and the torque constant K<sub>t</sub> in N·m/A.  Both
+//! are stored in SI, where K<sub>t</sub> = 1/K<sub>v</sub> holds exactly, so a motor specified as `920.0*RPM_PER_VOLT` gives the
+//! correct K<sub>t</sub> ≈ 0.0104 N·m/A rather than the 1/920 of the common mistake.
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::{RPM_PER_VOLT,RAD_PER_SECOND_PER_VOLT};
+
+/// Torque constant of a motor with velocity constant `kv`
+pub fn torque_constant(kv: MotorVelocityConstant) -> TorqueConstant {
+	1.0/kv
+}
+
+/// Velocity constant of a motor with torque constant `kt`
+pub fn velocity_constant(kt: TorqueConstant) -> MotorVelocityConstant {
+	1.0/kt
+}
+
+/// Velocity constant from a value in rpm/V
+pub fn kv_from_rpm_per_volt(kv: Float) -> MotorVelocityConstant {
+	kv*RPM_PER_VOLT
+}
+
+/// Velocity constant from a value in rad/s/V
+pub fn kv_from_rad_per_second_per_volt(kv: Float) -> MotorVelocityConstant {
+	kv*RAD_PER_SECOND_PER_VOLT
+}
+
+/// Back-EMF generated by a motor with velocity constant `kv` turning at `speed`
+pub fn back_emf(kv: MotorVelocityConstant, speed: AngularVelocity) -> Voltage {
+	speed/kv
+}
+
+/// Torque produced by a motor with torque constant `kt` carrying `current`
+pub fn motor_torque(kt: TorqueConstant, current: Current) -> Torque {
+	kt*current
+}
+
+/// Steady-state model of a DC motor with velocity constant `kv`, winding resistance `resistance` and no-load current `no_load_current`
+#[derive(Clone, Copy, Debug)]
+pub struct DcMotor {
+	pub kv: MotorVelocityConstant,
+	pub resistance: Resistance,
+	/// Current drawn with no load, covering friction and iron losses
+	pub no_load_current: Current,
+}
+
+impl DcMotor {
+	pub const fn new(kv: MotorVelocityConstant, resistance: Resistance, no_load_current: Current) -> DcMotor {
+		DcMotor { kv, resistance, no_load_current }
+	}
+
+	pub fn torque_constant(&self) -> TorqueConstant {
+		torque_constant(self.kv)
+	}
+	/// Current drawn to deliver `torque` at the shaft
+	pub fn current_at(&self, torque: Torque) -> Current {
+		torque/self.torque_constant() + self.no_load_current
+	}
+	/// Speed when supplied with `voltage` and delivering `torque`
+	pub fn speed_at(&self, voltage: Voltage, torque: Torque) -> AngularVelocity {
+		(voltage - self.current_at(torque)*self.resistance)*self.kv
+	}
+	/// Speed with no load at `voltage`
+	pub fn no_load_speed(&self, voltage: Voltage) -> AngularVelocity {
+		self.speed_at(voltage, Torque::from_si(0.0))
+	}
+	/// Current drawn at `voltage` with the rotor held still
+	pub fn stall_current(&self, voltage: Voltage) -> Current {
+		voltage/self.resistance
+	}
+	/// Torque produced at `voltage` with the rotor held still
+	pub fn stall_torque(&self, voltage: Voltage) -> Torque {
+		(self.stall_current(voltage) - self.no_load_current)*self.torque_constant()
+	}
+	/// Fraction of electrical input power delivered at the shaft when supplied with `voltage` and delivering `torque`
+	pub fn efficiency(&self, voltage: Voltage, torque: Torque) -> Unitless {
+		torque*self.speed_at(voltage, torque)/(voltage*self.current_at(torque))
+	}
+}
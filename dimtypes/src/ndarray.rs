@@ -0,0 +1,80 @@
+//! Arrays of quantities with ndarray, enabled with the `ndarray` feature
+//!
+//! An `Array<Quantity<...>, D>` adds, subtracts and scales by [Float]s with ndarray's own operators, and quantities act as scalar
+//! operands so a whole array can be offset by one, e.g. `&temperatures + 5.0*KELVIN`.  ndarray's operators between two arrays keep
+//! the element type, so products and quotients whose dimension differs from their operands go through [QuantityArray::mul_elements],
+//! [QuantityArray::div_elements] and [dot], whose output types carry the combined dimension.  [QuantityArray::as_unit] and
+//! [from_unit] convert to and from plain arrays of numbers for plotting and numerical libraries.
+
+use core::ops::{Div,Mul};
+use ::ndarray::{Array,ArrayBase,Data,Dimension as Shape,Ix1,ScalarOperand,Zip};
+use crate::{AnyQuantity,Float,Quantity,Unit};
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+ScalarOperand for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {}
+
+/// Conversions and dimension-changing arithmetic on arrays of quantities of type `Q`
+pub trait QuantityArray<Q: AnyQuantity, D: Shape> {
+	/// Numerical values in `unit`, e.g. `speeds.as_unit(KPH)`
+	fn as_unit(&self, unit: impl Unit<Dimen=Q>) -> Array<Float, D>;
+	/// Numerical values in SI base units
+	fn as_si(&self) -> Array<Float, D>;
+	/// Elementwise product with an array of quantities of another type, e.g. forces times distances giving energies.  Panics if the
+	/// shapes differ
+	fn mul_elements<Q2: AnyQuantity, S2: Data<Elem=Q2>>(&self, rhs: &ArrayBase<S2, D>) -> Array<<Q as Mul<Q2>>::Output, D> where
+		Q: Mul<Q2>;
+	/// Elementwise quotient by an array of quantities of another type.  Panics if the shapes differ
+	fn div_elements<Q2: AnyQuantity, S2: Data<Elem=Q2>>(&self, rhs: &ArrayBase<S2, D>) -> Array<<Q as Div<Q2>>::Output, D> where
+		Q: Div<Q2>;
+	/// Sum of the elements
+	fn total(&self) -> Q;
+}
+
+impl<Q: AnyQuantity, S: Data<Elem=Q>, D: Shape> QuantityArray<Q, D> for ArrayBase<S, D> {
+	fn as_unit(&self, unit: impl Unit<Dimen=Q>) -> Array<Float, D> {
+		self.map(|&q| unit.qty_to_val(q))
+	}
+
+	fn as_si(&self) -> Array<Float, D> {
+		self.map(|&q| q.as_si())
+	}
+
+	fn mul_elements<Q2: AnyQuantity, S2: Data<Elem=Q2>>(&self, rhs: &ArrayBase<S2, D>) -> Array<<Q as Mul<Q2>>::Output, D> where
+		Q: Mul<Q2>
+	{
+		Zip::from(self).and(rhs).map_collect(|&a, &b| a*b)
+	}
+
+	fn div_elements<Q2: AnyQuantity, S2: Data<Elem=Q2>>(&self, rhs: &ArrayBase<S2, D>) -> Array<<Q as Div<Q2>>::Output, D> where
+		Q: Div<Q2>
+	{
+		Zip::from(self).and(rhs).map_collect(|&a, &b| a/b)
+	}
+
+	fn total(&self) -> Q {
+		self.fold(Q::from_si(0.0), |total, &q| total + q)
+	}
+}
+
+/// Array of quantities from numerical values in SI base units
+pub fn from_si<Q: AnyQuantity, S: Data<Elem=Float>, D: Shape>(values: &ArrayBase<S, D>) -> Array<Q, D> {
+	values.map(|&v| Q::from_si(v))
+}
+
+/// Array of quantities from numerical values in `unit`, e.g. a column of a data file in `MILE`s
+pub fn from_unit<U: Unit, S: Data<Elem=Float>, D: Shape>(values: &ArrayBase<S, D>, unit: U) -> Array<U::Dimen, D> {
+	values.map(|&v| unit.val_to_qty(v))
+}
+
+/// Dot product of two vectors of quantities, e.g. forces and displacements giving the total work, of the dimension of the product
+/// of their elements.  Panics if the lengths differ
+pub fn dot<Q1, Q2, S1, S2>(a: &ArrayBase<S1, Ix1>, b: &ArrayBase<S2, Ix1>) -> <Q1 as Mul<Q2>>::Output where
+	Q1: AnyQuantity + Mul<Q2>,
+	Q2: AnyQuantity,
+	<Q1 as Mul<Q2>>::Output: AnyQuantity,
+	S1: Data<Elem=Q1>,
+	S2: Data<Elem=Q2>,
+{
+	assert_eq!(a.len(), b.len(), "dot product of vectors of different lengths");
+	Zip::from(a).and(b).fold(<Q1 as Mul<Q2>>::Output::from_si(0.0), |total, &x, &y| total + x*y)
+}
@@ -0,0 +1,112 @@
+//! Reading and writing quantities to netCDF files, enabled with the `netcdf` feature
+//!
+//! Variables carry their unit in a string `units` attribute following the CF conventions.  On read the attribute is
+//! [parsed][crate::parse], packed values are unpacked with `scale_factor` and `add_offset`, and values equal to `_FillValue` or
+//! `missing_value` become NaN.  Writers stamp the `units` attribute so files round trip.
+
+use std::fmt;
+use ::netcdf::{AttributeValue,FileMut,Variable};
+use crate::{Dimension,Float,Quantity,Unit};
+use crate::parse::{parse_unit,DimensionMismatch,ParsedUnit,UnitParseError};
+
+/// Name of the attribute holding the unit of a variable
+pub const UNITS_ATTRIBUTE: &str = "units";
+
+/// Error reading or writing quantities in a netCDF file
+#[derive(Debug)]
+pub enum NetcdfError {
+	/// Error from the netCDF library
+	Netcdf(::netcdf::Error),
+	/// The variable has no string `units` attribute
+	MissingUnits,
+	/// The `units` attribute could not be parsed
+	Units(UnitParseError),
+	/// The unit does not match the dimension of the quantity
+	Dimension(DimensionMismatch),
+}
+
+impl fmt::Display for NetcdfError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			NetcdfError::Netcdf(e) => write!(f, "{}", e),
+			NetcdfError::MissingUnits => write!(f, "variable has no '{}' attribute", UNITS_ATTRIBUTE),
+			NetcdfError::Units(e) => write!(f, "invalid units: {}", e),
+			NetcdfError::Dimension(e) => write!(f, "{}", e),
+		}
+	}
+}
+impl std::error::Error for NetcdfError {}
+
+impl From<::netcdf::Error> for NetcdfError {
+	fn from(e: ::netcdf::Error) -> Self { NetcdfError::Netcdf(e) }
+}
+impl From<UnitParseError> for NetcdfError {
+	fn from(e: UnitParseError) -> Self { NetcdfError::Units(e) }
+}
+impl From<DimensionMismatch> for NetcdfError {
+	fn from(e: DimensionMismatch) -> Self { NetcdfError::Dimension(e) }
+}
+
+/// Parse the `units` attribute of `var`
+pub fn units_of(var: &Variable) -> Result<ParsedUnit, NetcdfError> {
+	match var.attribute_value(UNITS_ATTRIBUTE).transpose()? {
+		Some(AttributeValue::Str(units)) => Ok(parse_unit(&units)?),
+		_ => Err(NetcdfError::MissingUnits),
+	}
+}
+
+/// Read all values of `var` converted to SI base units, along with their dimension.  Useful when the dimension is not known until runtime
+pub fn read_si(var: &Variable) -> Result<(Vec<Float>, Dimension), NetcdfError> {
+	let unit = units_of(var)?;
+	let values = read_unpacked(var)?.into_iter().map(|v| unit.to_si(v)).collect();
+	Ok((values, unit.dimension()))
+}
+
+/// Read all values of `var` as quantities, converting from the unit given by its `units` attribute
+pub fn read_quantities<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	var: &Variable
+) -> Result<Vec<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, NetcdfError> {
+	let unit = units_of(var)?;
+	unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+	Ok(read_unpacked(var)?.into_iter().map(|v| Quantity::from_si(unit.to_si(v))).collect())
+}
+
+/// Read all values of `var` converted into the requested `unit`
+pub fn read_as<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	var: &Variable, unit: impl Unit<Dimen=Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>
+) -> Result<Vec<Float>, NetcdfError> {
+	Ok(read_quantities::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>(var)?.into_iter().map(|q| unit.qty_to_val(q)).collect())
+}
+
+/// Add a variable `name` over the dimensions `dims` holding `values` as numerical values in `units`, and stamp its `units` attribute.
+/// Fails without adding the variable if `units` cannot be parsed or does not match the dimension of the values
+pub fn write_quantities<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(
+	file: &mut FileMut, name: &str, dims: &[&str], values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], units: &str
+) -> Result<(), NetcdfError> {
+	let unit = parse_unit(units)?;
+	unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+	let data: Vec<Float> = values.iter().map(|v| unit.from_si(v.as_si())).collect();
+	let mut var = file.add_variable::<Float>(name, dims)?;
+	var.put_attribute(UNITS_ATTRIBUTE, units)?;
+	var.put_values(&data, ..)?;
+	Ok(())
+}
+
+/// Raw values of `var` with CF packing and missing values applied
+fn read_unpacked(var: &Variable) -> Result<Vec<Float>, NetcdfError> {
+	let scale = numeric_attribute(var, "scale_factor")?.unwrap_or(1.0);
+	let offset = numeric_attribute(var, "add_offset")?.unwrap_or(0.0);
+	let fill = numeric_attribute(var, "_FillValue")?;
+	let missing = numeric_attribute(var, "missing_value")?;
+	let values = var.get_values::<Float,_>(..)?;
+	Ok(values.into_iter().map(|v| {
+		if Some(v) == fill || Some(v) == missing { Float::NAN } else { v*scale + offset }
+	}).collect())
+}
+
+fn numeric_attribute(var: &Variable, name: &str) -> Result<Option<Float>, NetcdfError> {
+	match var.attribute_value(name).transpose()? {
+		Some(value) => Ok(Some(value.try_into()?)),
+		None => Ok(None),
+	}
+}
@@ -0,0 +1,150 @@
+//! Seeded noise and random walk generators producing typed samples, for simulating sensor noise
+//!
+//! Noise levels are given as one-sided power spectral densities, whose dimension is the square of the sample dimension per hertz
+//! (e.g. (m/s²)²/Hz for an accelerometer), or directly as a standard deviation per sample.  Densities quoted per root hertz square to
+//! a power spectral density, e.g. `(150.0*MICRO*STANDARD_GRAVITY/ROOT_HERTZ).pow::<2>()`.  Generators are deterministic for a given
+//! seed and implement [Iterator], producing an endless stream of samples.
+
+use crate::{Float,Quantity};
+use crate::coretypes::combined_power;
+use crate::dimens::*;
+use crate::uncertainty::Rng;
+
+/// One-sided power spectral density of a signal of type `Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>`, i.e. its square per hertz
+pub type SpectralDensity<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> =
+	Quantity<{combined_power(0,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(1,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(2,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(3,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(4,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(5,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(6,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(7,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(8,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(9,1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)}>;
+/// One-sided power spectral density of the rate of change of a signal of type `Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>`
+pub type RateSpectralDensity<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> =
+	Quantity<{combined_power(0,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(1,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(2,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(3,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(4,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(5,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(6,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(7,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(8,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)},{combined_power(9,-1,2*T,2*L,2*M,2*I,2*TEMP,2*MOL,2*LUM,2*ANG,2*CNT,D,1,0,0,0,0,0,0,0,0,1)}>;
+
+/// Gaussian white noise with a flat spectrum
+#[derive(Clone, Debug)]
+pub struct WhiteNoise<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize = 1> {
+	deviation: Float,
+	rng: Rng,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> WhiteNoise<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Noise with one-sided power spectral density `psd`, sampled at `sample_rate`.  The per-sample standard deviation is
+	/// √(psd·sample_rate/2)
+	pub fn new(psd: SpectralDensity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, sample_rate: Frequency, seed: u64) -> Self {
+		WhiteNoise { deviation: Float::sqrt(psd.as_si()*sample_rate.as_si()/2.0), rng: Rng::new(seed) }
+	}
+	/// Noise with per-sample standard deviation `deviation`
+	pub fn with_deviation(deviation: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, seed: u64) -> Self {
+		WhiteNoise { deviation: deviation.as_si(), rng: Rng::new(seed) }
+	}
+	/// Standard deviation of each sample
+	pub fn deviation(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.deviation)
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> Iterator for WhiteNoise<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Item = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(Quantity::from_si(self.deviation*self.rng.next_normal()))
+	}
+}
+
+/// Pink (flicker, 1/f) noise generated by Kasdin's fractional integration of white noise, with a spectrum falling 3 dB per octave
+/// down to about `sample_rate/memory`
+#[derive(Clone, Debug)]
+pub struct PinkNoise<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize = 1> {
+	coefficients: Vec<Float>,
+	history: Vec<Float>,
+	position: usize,
+	deviation: Float,
+	rng: Rng,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> PinkNoise<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Pink noise whose one-sided power spectral density is `psd_at_1hz` at 1 Hz and inversely proportional to frequency elsewhere.
+	/// `memory` is the length of the filter, which sets the lowest frequency where the spectrum is still 1/f
+	pub fn new(psd_at_1hz: SpectralDensity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, memory: usize, seed: u64) -> Self {
+		let memory = memory.max(1);
+		let mut coefficients = Vec::with_capacity(memory);
+		let mut h = 1.0;
+		for k in 0..memory {
+			if k > 0 {
+				h *= (k as Float - 0.5)/(k as Float);
+			}
+			coefficients.push(h);
+		}
+		// For 1/f noise S(f) ≈ σ²/(π f), independent of the sample rate
+		let deviation = Float::sqrt(crate::float_consts::PI*psd_at_1hz.as_si());
+		PinkNoise { coefficients, history: vec![0.0; memory], position: 0, deviation, rng: Rng::new(seed) }
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> Iterator for PinkNoise<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Item = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn next(&mut self) -> Option<Self::Item> {
+		let n = self.history.len();
+		self.position = (self.position + n - 1) % n;
+		self.history[self.position] = self.deviation*self.rng.next_normal();
+		let value: Float = self.coefficients.iter().enumerate().map(|(k, h)| h*self.history[(self.position + k) % n]).sum();
+		Some(Quantity::from_si(value))
+	}
+}
+
+/// Random walk (Brownian motion) accumulating independent Gaussian steps, e.g. gyroscope bias drift
+#[derive(Clone, Debug)]
+pub struct RandomWalk<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize = 1> {
+	value: Float,
+	step: Float,
+	rng: Rng,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> RandomWalk<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	/// Walk starting at `start` with steps of standard deviation `step`
+	pub fn new(start: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, step: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, seed: u64) -> Self {
+		RandomWalk { value: start.as_si(), step: step.as_si(), rng: Rng::new(seed) }
+	}
+	/// Walk starting at `start` that integrates white noise of one-sided power spectral density `rate_psd` in its rate of change,
+	/// sampled every `interval`.  The step deviation is √(rate_psd/2 · interval)
+	pub fn from_rate_density(start: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, rate_psd: RateSpectralDensity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, interval: Time, seed: u64) -> Self {
+		RandomWalk { value: start.as_si(), step: Float::sqrt(rate_psd.as_si()/2.0*interval.as_si()), rng: Rng::new(seed) }
+	}
+	/// Current position of the walk
+	pub fn value(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.value)
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize> Iterator for RandomWalk<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Item = Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>;
+	fn next(&mut self) -> Option<Self::Item> {
+		self.value += self.step*self.rng.next_normal();
+		Some(Quantity::from_si(self.value))
+	}
+}
+
+/// One-sided power spectral density equivalent to a noise density quoted per root hertz, such as 150 µg/√Hz for an accelerometer.
+/// `density` is the numerical value in the SI unit of `Q` per √Hz
+pub fn psd_from_noise_density<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(density: Float) -> SpectralDensity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	Quantity::from_si(density*density)
+}
+
+/// White noise standard deviation of a signal with one-sided `psd` measured in a `bandwidth`
+pub fn noise_deviation<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(psd: SpectralDensity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, bandwidth: Frequency) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	Quantity::from_si(Float::sqrt(psd.as_si()*bandwidth.as_si()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn white_noise_has_its_deviation() {
+		let noise = WhiteNoise::with_deviation(2.0*METER, 7);
+		let samples: Vec<Float> = noise.take(20000).map(|x| x.as_unit(METER)).collect();
+		let mean = samples.iter().sum::<Float>()/samples.len() as Float;
+		let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<Float>()/samples.len() as Float;
+		assert!(mean.abs() < 0.05);
+		assert!((variance.sqrt() - 2.0).abs() < 0.05);
+	}
+}
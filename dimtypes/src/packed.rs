@@ -0,0 +1,81 @@
+//! Decoding of scale/offset packed values as used by GRIB and BUFR meteorological data
+//!
+//! Both formats store a field as unsigned integers X together with a reference value R, a binary scale factor E, a decimal scale factor
+//! D and a unit, decoding to Y = (R + X·2<sup>E</sup>) / 10<sup>D</sup> in that unit.  [PackedField] carries all four so the decoded
+//! values come out as typed quantities with the dimension checked against the declared unit.
+
+use crate::{Float,Quantity};
+use crate::parse::{parse_unit,DimensionMismatch,ParsedUnit,UnitParseError};
+
+/// Description of a packed field: how raw integers map to values in a unit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackedField {
+	reference: Float,
+	binary_scale: i32,
+	decimal_scale: i32,
+	unit: ParsedUnit,
+	missing: Option<u64>,
+}
+
+impl PackedField {
+	/// GRIB simple packing with reference value `reference`, binary scale factor `binary_scale` and decimal scale factor `decimal_scale`,
+	/// decoding to values in `units`
+	pub fn grib(reference: Float, binary_scale: i32, decimal_scale: i32, units: &str) -> Result<PackedField, UnitParseError> {
+		Ok(PackedField { reference, binary_scale, decimal_scale, unit: parse_unit(units)?, missing: None })
+	}
+	/// BUFR element with reference value `reference` and scale `scale`, decoding to values in `units`.  Raw values with all `width` bits
+	/// set are treated as missing, as specified by BUFR
+	pub fn bufr(reference: i64, scale: i32, width: u32, units: &str) -> Result<PackedField, UnitParseError> {
+		let field = PackedField::grib(reference as Float, 0, scale, units)?;
+		Ok(field.with_missing(all_ones(width)))
+	}
+
+	/// Treat the raw value `raw` as missing, decoding it to NaN
+	pub fn with_missing(self, raw: u64) -> PackedField {
+		PackedField { missing: Some(raw), ..self }
+	}
+
+	/// The unit the unpacked values are expressed in
+	pub const fn unit(&self) -> ParsedUnit { self.unit }
+
+	/// Decode a raw integer to its numerical value in [Self::unit()], or NaN if it is the missing value
+	pub fn unpack(&self, raw: u64) -> Float {
+		if self.missing == Some(raw) {
+			return Float::NAN;
+		}
+		(self.reference + (raw as Float)*Float::powi(2.0, self.binary_scale)) / Float::powi(10.0, self.decimal_scale)
+	}
+
+	/// Decode a single raw integer to a quantity, failing if the field's unit does not match the quantity's dimension
+	pub fn decode_one<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, raw: u64) -> Result<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, DimensionMismatch> {
+		self.unit.quantity_of(self.unpack(raw))
+	}
+
+	/// Decode raw integers to quantities, failing if the field's unit does not match the quantity's dimension
+	pub fn decode<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, raw: &[u64]) -> Result<Vec<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, DimensionMismatch> {
+		self.unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+		Ok(raw.iter().map(|&x| Quantity::from_si(self.unit.to_si(self.unpack(x)))).collect())
+	}
+}
+
+/// Split a big-endian bit stream into `count` unsigned integers of `bits` bits each, as stored in GRIB and BUFR data sections.
+/// Stops early if `data` runs out.  `bits` may be at most 64
+pub fn unpack_bits(data: &[u8], bits: u32, count: usize) -> Vec<u64> {
+	assert!(bits <= 64, "packed values are at most 64 bits wide");
+	let mut values = Vec::with_capacity(count);
+	let mut bit = 0usize;
+	while values.len() < count && bit + bits as usize <= data.len()*8 {
+		let mut value = 0u64;
+		for _ in 0..bits {
+			let byte = data[bit/8];
+			value = (value << 1) | u64::from((byte >> (7 - bit%8)) & 1);
+			bit += 1;
+		}
+		values.push(value);
+	}
+	values
+}
+
+fn all_ones(width: u32) -> u64 {
+	if width >= 64 { u64::MAX } else { (1u64 << width) - 1 }
+}
@@ -0,0 +1,304 @@
+//! Named parameter sets for saving and loading simulation scenarios
+//!
+//! A [ParamSet] maps names to quantities, each remembered with the unit it was given in so saved files stay readable.  Sets round trip
+//! through a flat TOML form (`gravity = "9.81 m/s^2"`) and the same JSON form as [telemetry][crate::telemetry]
+//! (`{"gravity": {"value": 9.81, "unit": "m/s^2"}}`).  Typed parameter structs implement [Params] to validate a loaded set against the
+//! dimensions they expect, usually through `#[derive(Params)]`, which reads and stores each field as the parameter of its name.
+
+use std::fmt;
+use crate::{Dimension,Float,Quantity};
+use crate::json;
+use crate::parse::{parse_unit,parse_value,split_value,ParsedUnit,UnitParseError};
+
+/// Error building, loading or reading a [ParamSet]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamError {
+	/// No parameter with this name exists
+	Missing(String),
+	/// The parameter exists but has a different dimension than requested
+	DimensionMismatch { name: String, expected: Dimension, found: Dimension },
+	/// The value or unit of a parameter could not be parsed
+	InvalidValue { name: String, error: UnitParseError },
+	/// The document could not be read; `position` is a line number for TOML and a byte offset for JSON
+	Syntax { message: String, position: usize },
+}
+
+impl fmt::Display for ParamError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParamError::Missing(name) => write!(f, "no parameter named '{}'", name),
+			ParamError::DimensionMismatch { name, expected, found } =>
+				write!(f, "parameter '{}' has dimension [{}], not [{}]", name, found, expected),
+			ParamError::InvalidValue { name, error } => write!(f, "parameter '{}': {}", name, error),
+			ParamError::Syntax { message, position } => write!(f, "syntax error at {}: {}", position, message),
+		}
+	}
+}
+
+impl std::error::Error for ParamError {}
+
+impl From<json::Error> for ParamError {
+	fn from(e: json::Error) -> Self { ParamError::Syntax { message: e.message, position: e.position } }
+}
+
+/// Derive [Params] for a struct of quantities, see the [macro][macro@Params]
+pub use dimtypes_macros::Params;
+
+/// A typed set of parameters which can be stored in and validated against a [ParamSet]
+pub trait Params: Sized {
+	/// Read each parameter from `set`, failing if any is missing or of the wrong dimension
+	fn from_params(set: &ParamSet) -> Result<Self, ParamError>;
+	/// Store each parameter in a new [ParamSet]
+	fn to_params(&self) -> ParamSet;
+}
+
+#[derive(Clone, Debug)]
+struct Param {
+	name: String,
+	value: Float,
+	units: String,
+	unit: ParsedUnit,
+}
+
+/// Ordered collection of named, dimensioned parameters
+#[derive(Clone, Debug, Default)]
+pub struct ParamSet {
+	params: Vec<Param>,
+}
+
+impl ParamSet {
+	pub fn new() -> ParamSet {
+		ParamSet::default()
+	}
+
+	/// Set the parameter `name` to `value`, to be saved in `units`.  Replaces any existing parameter of that name
+	pub fn set<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, units: &str) -> Result<&mut Self, ParamError> {
+		let invalid = |error| ParamError::InvalidValue { name: name.to_string(), error };
+		let unit = parse_unit(units).map_err(invalid)?;
+		let value = unit.value_of(value).map_err(|e| ParamError::DimensionMismatch { name: name.to_string(), expected: e.expected, found: e.found })?;
+		self.insert(Param { name: name.to_string(), value, units: units.to_string(), unit });
+		Ok(self)
+	}
+
+	/// Set the parameter `name` to `value`, to be saved in SI base units
+	pub fn set_si<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		let dimension = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		let units = if dimension.is_dimensionless() { String::new() } else { dimension.to_string() };
+		self.insert(Param { name: name.to_string(), value: value.as_si(), units, unit: ParsedUnit::si(dimension) });
+		self
+	}
+
+	/// Get the parameter `name`, checking it is a quantity of type `Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>`
+	pub fn get<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, name: &str) -> Result<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, ParamError> {
+		let param = self.params.iter().find(|p| p.name == name).ok_or_else(|| ParamError::Missing(name.to_string()))?;
+		param.unit.quantity_of(param.value)
+			.map_err(|e| ParamError::DimensionMismatch { name: name.to_string(), expected: e.expected, found: e.found })
+	}
+
+	/// Remove the parameter `name`, returning whether it existed
+	pub fn remove(&mut self, name: &str) -> bool {
+		let before = self.params.len();
+		self.params.retain(|p| p.name != name);
+		self.params.len() != before
+	}
+
+	/// Names and dimensions of each parameter, in the order they were first set
+	pub fn iter(&self) -> impl Iterator<Item=(&str, Dimension)> {
+		self.params.iter().map(|p| (p.name.as_str(), p.unit.dimension()))
+	}
+	pub fn len(&self) -> usize { self.params.len() }
+	pub fn is_empty(&self) -> bool { self.params.is_empty() }
+
+	/// Write as flat TOML, one `name = "value unit"` line per parameter
+	pub fn to_toml(&self) -> String {
+		let mut out = String::new();
+		for p in &self.params {
+			if p.units.is_empty() {
+				out.push_str(&format!("{} = {}\n", toml_key(&p.name), toml_float(p.value)));
+			} else {
+				let value = if p.value.is_finite() { p.value.to_string() } else { toml_float(p.value) };
+				out.push_str(&format!("{} = {}\n", toml_key(&p.name), json::quote(&format!("{} {}", value, p.units))));
+			}
+		}
+		out
+	}
+
+	/// Read flat TOML as written by [Self::to_toml()].  Values are either strings of a number and a unit, or bare numbers for
+	/// dimensionless parameters, with non-finite numbers spelled `nan`, `inf` and `-inf` as in TOML.  Comments and blank lines are
+	/// skipped
+	pub fn from_toml(text: &str) -> Result<ParamSet, ParamError> {
+		let mut set = ParamSet::new();
+		for (number, line) in text.lines().enumerate() {
+			let syntax = |message: &str| ParamError::Syntax { message: message.to_string(), position: number + 1 };
+			let line = strip_comment(line).trim();
+			if line.is_empty() {
+				continue;
+			}
+			let (name, value) = split_key(line).ok_or_else(|| syntax("expected 'name = value'"))?;
+			let value = value.trim();
+			let text = if value.starts_with('"') {
+				match json::parse(value) {
+					Ok(json::Value::String(s)) => s,
+					_ => return Err(syntax("invalid string")),
+				}
+			} else {
+				value.to_string()
+			};
+			let (value, unit, units) = toml_value(&text).map_err(|error| ParamError::InvalidValue { name: name.clone(), error })?;
+			let units = units.to_string();
+			set.insert(Param { name, value, units, unit });
+		}
+		Ok(set)
+	}
+
+	/// Write as a JSON object mapping each name to `{"value": <number>, "unit": "<unit>"}`
+	pub fn to_json(&self) -> String {
+		let fields: Vec<String> = self.params.iter().map(|p| {
+			let value = if p.value.is_finite() { p.value.to_string() } else { "null".to_string() };
+			format!("{}:{{\"value\":{},\"unit\":{}}}", json::quote(&p.name), value, json::quote(&p.units))
+		}).collect();
+		format!("{{{}}}", fields.join(","))
+	}
+
+	/// Read JSON as written by [Self::to_json()]
+	pub fn from_json(text: &str) -> Result<ParamSet, ParamError> {
+		let members = match json::parse(text)? {
+			json::Value::Object(members) => members,
+			_ => return Err(ParamError::Syntax { message: "expected an object".to_string(), position: 0 }),
+		};
+		let mut set = ParamSet::new();
+		for (name, value) in members {
+			let malformed = || ParamError::Syntax { message: format!("parameter '{}' must have a numeric value and a unit string", name), position: 0 };
+			let json::Value::Object(parts) = value else { return Err(malformed()) };
+			let get = |key: &str| parts.iter().find(|(k,_)| k == key).map(|(_,v)| v);
+			let (Some(json::Value::Number(value)), Some(json::Value::String(units))) = (get("value"), get("unit")) else { return Err(malformed()) };
+			let unit = if units.trim().is_empty() {
+				ParsedUnit::ONE
+			} else {
+				parse_unit(units).map_err(|error| ParamError::InvalidValue { name: name.clone(), error })?
+			};
+			set.insert(Param { name: name.clone(), value: *value, units: units.clone(), unit });
+		}
+		Ok(set)
+	}
+
+	fn insert(&mut self, param: Param) {
+		match self.params.iter_mut().find(|p| p.name == param.name) {
+			Some(existing) => *existing = param,
+			None => self.params.push(param),
+		}
+	}
+}
+
+fn toml_key(name: &str) -> String {
+	if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+		name.to_string()
+	} else {
+		json::quote(name)
+	}
+}
+
+/// Split a `name = value` line at the `=` following the name, unescaping a quoted name
+fn split_key(line: &str) -> Option<(String, &str)> {
+	if !line.starts_with('"') {
+		let (key, value) = line.split_once('=')?;
+		return Some((key.trim().to_string(), value));
+	}
+	let mut escaped = false;
+	let (end, _) = line.char_indices().skip(1).find(|&(_, c)| {
+		let closing = c == '"' && !escaped;
+		escaped = c == '\\' && !escaped;
+		closing
+	})?;
+	let json::Value::String(key) = json::parse(&line[..=end]).ok()? else { return None };
+	let value = line[end+1..].trim_start().strip_prefix('=')?;
+	Some((key, value))
+}
+
+/// Number as a TOML float, which spells the non-finite values `nan`, `inf` and `-inf`
+fn toml_float(value: Float) -> String {
+	if value.is_nan() {
+		"nan".to_string()
+	} else if value.is_infinite() {
+		(if value > 0.0 { "inf" } else { "-inf" }).to_string()
+	} else {
+		format!("{:?}", value)
+	}
+}
+
+/// Number, unit and unit text of a value written by [ParamSet::to_toml], as [parse_value] but also taking the TOML spellings of
+/// non-finite numbers
+fn toml_value(text: &str) -> Result<(Float, ParsedUnit, &str), UnitParseError> {
+	let (number, units) = text.split_once(char::is_whitespace).map_or((text, ""), |(number, units)| (number, units.trim()));
+	let value = match number.trim_start_matches('+') {
+		"nan" | "-nan" => Float::NAN,
+		"inf" => Float::INFINITY,
+		"-inf" => Float::NEG_INFINITY,
+		_ => {
+			let (value, unit) = parse_value(text)?;
+			return Ok((value, unit, split_value(text).1));
+		},
+	};
+	let unit = if units.is_empty() { ParsedUnit::ONE } else { parse_unit(units)? };
+	Ok((value, unit, units))
+}
+
+/// Remove a trailing `#` comment, ignoring `#` inside strings
+fn strip_comment(line: &str) -> &str {
+	let mut in_string = false;
+	let mut escaped = false;
+	for (i, c) in line.char_indices() {
+		match c {
+			'\\' if in_string => { escaped = !escaped; continue; },
+			'"' if !escaped => in_string = !in_string,
+			'#' if !in_string => return &line[..i],
+			_ => {},
+		}
+		escaped = false;
+	}
+	line
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::dimens::*;
+	use crate::units::*;
+
+	#[test]
+	fn toml_round_trip() {
+		let mut set = ParamSet::new();
+		set.set("plain", 9.81*METER/SECOND/SECOND, "m/s^2").unwrap();
+		set.set("a = b", 2.0*METER, "m").unwrap();
+		set.set_si("quote \" and \\ # here", Unitless::from(0.5));
+		set.set("not a number", Float::NAN*METER, "m").unwrap();
+		set.set_si("ratio", Unitless::from(Float::INFINITY));
+		set.set_si("floor", Unitless::from(Float::NEG_INFINITY));
+		let toml = set.to_toml();
+		assert!(toml.contains("\"a = b\" = \"2 m\"\n"), "{}", toml);
+		assert!(toml.contains("\"not a number\" = \"nan m\"\n"), "{}", toml);
+		assert!(toml.contains("ratio = inf\n") && toml.contains("floor = -inf\n"), "{}", toml);
+
+		let loaded = ParamSet::from_toml(&toml).unwrap();
+		assert_eq!(loaded.iter().map(|(name, _)| name).collect::<Vec<_>>(), set.iter().map(|(name, _)| name).collect::<Vec<_>>());
+		let gravity: Acceleration = loaded.get("plain").unwrap();
+		assert_eq!(gravity.as_si(), 9.81);
+		let length: Length = loaded.get("a = b").unwrap();
+		assert_eq!(length.as_si(), 2.0);
+		let ratio = |name| Float::from(loaded.get::<0,0,0,0,0,0,0,0,0,1>(name).unwrap());
+		assert_eq!(ratio("quote \" and \\ # here"), 0.5);
+		assert_eq!(ratio("ratio"), Float::INFINITY);
+		assert_eq!(ratio("floor"), Float::NEG_INFINITY);
+		let length: Length = loaded.get("not a number").unwrap();
+		assert!(length.as_si().is_nan());
+	}
+
+	#[test]
+	fn toml_syntax_errors() {
+		assert!(matches!(ParamSet::from_toml("\"unterminated = 1"), Err(ParamError::Syntax { position: 1, .. })));
+		assert!(matches!(ParamSet::from_toml("ok = 1\n\"key\" 2"), Err(ParamError::Syntax { position: 2, .. })));
+	}
+}
@@ -0,0 +1,1036 @@
+//! Parsing of unit strings such as those found in `units` attributes of data files
+//!
+//! The accepted syntax follows UDUNITS: unit symbols with optional SI prefixes, combined by juxtaposition, `*`, `.` or `·` for
+//! multiplication and `/` for division.  Powers may be written `m^2`, `m**2`, `m2` or `m²`, or as a fraction in parentheses such as
+//! `Hz^(-1/2)`, parentheses group terms and numeric factors
+//! scale the unit (e.g. `1e-3 m`, `(0.1 m)^3`).  Offset temperature units (`degC`, `°F`, ...) keep their offset only when they appear alone,
+//! inside a compound unit such as `degC/s` they act as temperature differences.  Cooking measures (`cup`, `tbsp`, `tsp`, `dsp`) are
+//! US customary unless another [CookingLocale] is given to [parse_unit_with_locale] or [parse_value_with_locale].
+//!
+//! Quantities implement [FromStr] on top of [parse_value], so `"12.5 km/h".parse::<Velocity>()` gives a typed value, or a
+//! [QuantityParseError] if the string is malformed or its unit has the wrong dimension.
+//!
+//! Without the `std` feature only [ParsedUnit], [lookup_symbol] and the compile-time lookup behind the `qty!` macro are available, as
+//! parsing at runtime allocates.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+use crate::{Dimension,Float,NamedUnit,OffsetUnit,Quantity};
+use crate::dimens::Temperature;
+use crate::consts;
+use crate::units::*;
+
+/// A unit parsed from a string, described by its dimension and the affine map to SI base units
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParsedUnit {
+	scale: Float,
+	offset: Float,
+	dimension: Dimension,
+}
+
+/// Error parsing a unit string
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnitParseError {
+	/// The string contains no unit
+	Empty,
+	/// A symbol is not a known unit, with or without a prefix
+	UnknownSymbol(String),
+	/// A character which cannot appear at this position of a unit string
+	UnexpectedCharacter { character: char, position: usize },
+	/// Parentheses do not match up
+	UnbalancedParentheses,
+	/// Parentheses are nested too deeply to parse
+	TooDeeplyNested,
+	/// The powers of the unit's dimension are too large to represent
+	PowerOverflow,
+	/// A numeric factor or exponent could not be read
+	InvalidNumber(String),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for UnitParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			UnitParseError::Empty => write!(f, "empty unit string"),
+			UnitParseError::UnknownSymbol(symbol) => write!(f, "unknown unit '{}'", symbol),
+			UnitParseError::UnexpectedCharacter { character, position } =>
+				write!(f, "unexpected '{}' at position {}", character, position),
+			UnitParseError::UnbalancedParentheses => write!(f, "unbalanced parentheses"),
+			UnitParseError::TooDeeplyNested => write!(f, "parentheses nested too deeply"),
+			UnitParseError::PowerOverflow => write!(f, "unit power too large"),
+			UnitParseError::InvalidNumber(text) => write!(f, "invalid number '{}'", text),
+		}
+	}
+}
+#[cfg(feature = "std")]
+impl std::error::Error for UnitParseError {}
+
+/// A [ParsedUnit] has a different dimension than the quantity it was applied to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DimensionMismatch {
+	pub expected: Dimension,
+	pub found: Dimension,
+}
+
+impl fmt::Display for DimensionMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "expected dimension [{}], found [{}]", self.expected, self.found)
+	}
+}
+impl core::error::Error for DimensionMismatch {}
+
+/// Error parsing a quantity from a string such as `12.5 km/h`
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuantityParseError {
+	/// The number or unit could not be parsed
+	Units(UnitParseError),
+	/// The unit does not match the dimension of the quantity
+	Dimension(DimensionMismatch),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for QuantityParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			QuantityParseError::Units(e) => write!(f, "{}", e),
+			QuantityParseError::Dimension(e) => write!(f, "{}", e),
+		}
+	}
+}
+#[cfg(feature = "std")]
+impl std::error::Error for QuantityParseError {}
+
+#[cfg(feature = "std")]
+impl From<UnitParseError> for QuantityParseError {
+	fn from(e: UnitParseError) -> Self { QuantityParseError::Units(e) }
+}
+#[cfg(feature = "std")]
+impl From<DimensionMismatch> for QuantityParseError {
+	fn from(e: DimensionMismatch) -> Self { QuantityParseError::Dimension(e) }
+}
+
+impl ParsedUnit {
+	/// The dimensionless unit 1
+	pub const ONE: ParsedUnit = ParsedUnit { scale: 1.0, offset: 0.0, dimension: Dimension::NONE };
+
+	/// Unit of the given dimension which is `scale` times the SI base unit
+	pub const fn new(scale: Float, dimension: Dimension) -> ParsedUnit {
+		ParsedUnit { scale, offset: 0.0, dimension }
+	}
+	/// The SI base unit of a dimension
+	pub const fn si(dimension: Dimension) -> ParsedUnit {
+		ParsedUnit::new(1.0, dimension)
+	}
+	/// Offset unit of the given dimension, `scale` times the SI base unit with zero at `offset` in SI base units, e.g. degrees Celsius
+	pub const fn with_offset(scale: Float, offset: Float, dimension: Dimension) -> ParsedUnit {
+		ParsedUnit { scale, offset, dimension }
+	}
+
+	/// Size of this unit in SI base units
+	pub const fn scale(&self) -> Float { self.scale }
+	/// Value in SI base units corresponding to zero in this unit.  Nonzero only for offset units such as `degC`
+	pub const fn offset(&self) -> Float { self.offset }
+	pub const fn dimension(&self) -> Dimension { self.dimension }
+
+	/// Convert a numerical value in this unit to SI base units
+	pub const fn to_si(&self, value: Float) -> Float {
+		value*self.scale + self.offset
+	}
+	/// Convert a numerical value in SI base units to this unit
+	pub const fn from_si(&self, value: Float) -> Float {
+		(value - self.offset)/self.scale
+	}
+
+	/// Check this unit measures quantities of type `Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>`
+	pub fn check<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self) -> Result<(), DimensionMismatch> {
+		let expected = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		if self.dimension == expected {
+			Ok(())
+		} else {
+			Err(DimensionMismatch { expected, found: self.dimension })
+		}
+	}
+	/// Create a quantity from a numerical `value` in this unit, failing if the dimension does not match
+	pub fn quantity_of<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, value: Float) -> Result<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, DimensionMismatch> {
+		self.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+		Ok(Quantity::from_si(self.to_si(value)))
+	}
+	/// Get the numerical value of `qty` in this unit, failing if the dimension does not match
+	pub fn value_of<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, qty: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Result<Float, DimensionMismatch> {
+		self.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>()?;
+		Ok(self.from_si(qty.as_si()))
+	}
+
+	/// This unit over a radian if that has the dimension `expected`, otherwise this unit unchanged.  Units of torque such as N·m
+	/// conventionally leave out the radian of [Torque][crate::dimens::Torque], so this takes them as torque where torque is expected
+	pub const fn per_radian_as(self, expected: Dimension) -> ParsedUnit {
+		match self.dimension.checked_div(RADIAN.dimension()) {
+			Some(dimension) if dimension.equals(&expected) => ParsedUnit { dimension, ..self },
+			_ => self,
+		}
+	}
+	#[cfg(feature = "std")]
+	fn without_offset(self) -> ParsedUnit {
+		ParsedUnit::new(self.scale, self.dimension)
+	}
+	#[cfg(feature = "std")]
+	fn powi(self, power: i32) -> Result<ParsedUnit, UnitParseError> {
+		let dimension = self.dimension.checked_powi(power as isize).ok_or(UnitParseError::PowerOverflow)?;
+		Ok(ParsedUnit::new(self.scale.powi(power), dimension))
+	}
+	#[cfg(feature = "std")]
+	fn root(self, root: i32) -> Result<ParsedUnit, UnitParseError> {
+		let dimension = self.dimension.checked_root(root as isize).ok_or(UnitParseError::PowerOverflow)?;
+		Ok(ParsedUnit::new(self.scale.powf(1.0/root as Float), dimension))
+	}
+	/// [ParsedUnit::mul] as the parser needs it, failing rather than panicking if the powers overflow
+	#[cfg(feature = "std")]
+	fn checked_mul(self, rhs: ParsedUnit) -> Result<ParsedUnit, UnitParseError> {
+		let dimension = self.dimension.checked_mul(rhs.dimension).ok_or(UnitParseError::PowerOverflow)?;
+		Ok(ParsedUnit::new(self.scale*rhs.scale, dimension))
+	}
+	/// [ParsedUnit::div] as the parser needs it, failing rather than panicking if the powers overflow
+	#[cfg(feature = "std")]
+	fn checked_div(self, rhs: ParsedUnit) -> Result<ParsedUnit, UnitParseError> {
+		let dimension = self.dimension.checked_div(rhs.dimension).ok_or(UnitParseError::PowerOverflow)?;
+		Ok(ParsedUnit::new(self.scale/rhs.scale, dimension))
+	}
+	const fn mul(self, rhs: ParsedUnit) -> ParsedUnit {
+		ParsedUnit::new(self.scale*rhs.scale, self.dimension*rhs.dimension)
+	}
+	const fn div(self, rhs: ParsedUnit) -> ParsedUnit {
+		ParsedUnit::new(self.scale/rhs.scale, self.dimension/rhs.dimension)
+	}
+}
+
+/// Formats as the scale times the SI base units, e.g. `1000 kg m^-3`
+impl fmt::Display for ParsedUnit {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.dimension.is_dimensionless() {
+			write!(f, "{}", self.scale)?;
+		} else if self.scale == 1.0 {
+			write!(f, "{}", self.dimension)?;
+		} else {
+			write!(f, "{} {}", self.scale, self.dimension)?;
+		}
+		if self.offset != 0.0 {
+			write!(f, " @ {}", self.offset)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "std")]
+impl FromStr for ParsedUnit {
+	type Err = UnitParseError;
+	fn from_str(s: &str) -> Result<ParsedUnit, UnitParseError> { parse_unit(s) }
+}
+
+/// Parses a number followed by a unit as in [parse_value], e.g. `"12.5 km/h".parse::<Velocity>()`, failing if the unit does not match the
+/// dimension of the quantity.  A bare number is only accepted for dimensionless quantities.  Units which leave out the radian of a
+/// per-radian quantity are accepted for it, so `"5 N·m".parse::<Torque>()` works although [Torque][crate::dimens::Torque] is J/rad
+#[cfg(feature = "std")]
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+FromStr for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Err = QuantityParseError;
+	fn from_str(s: &str) -> Result<Self, QuantityParseError> {
+		let (value, unit) = parse_value(s)?;
+		Ok(unit.per_radian_as(Self::DIMENSION).quantity_of(value)?)
+	}
+}
+
+/// Parse a unit string such as `kg m-3`, `km/h` or `degC`
+#[cfg(feature = "std")]
+pub fn parse_unit(s: &str) -> Result<ParsedUnit, UnitParseError> {
+	parse_unit_with_locale(s, CookingLocale::Us)
+}
+
+/// Parse a unit string as [parse_unit], taking cooking measures such as `cup` or `tbsp` in the convention of `locale`
+#[cfg(feature = "std")]
+pub fn parse_unit_with_locale(s: &str, locale: CookingLocale) -> Result<ParsedUnit, UnitParseError> {
+	let mut parser = Parser { chars: s.chars().collect(), pos: 0, locale, depth: 0 };
+	let (unit, factors) = parser.expression()?;
+	match parser.peek() {
+		None => {},
+		Some(')') => return Err(UnitParseError::UnbalancedParentheses),
+		Some(c) => return Err(UnitParseError::UnexpectedCharacter { character: c, position: parser.pos }),
+	}
+	match factors {
+		0 => Err(UnitParseError::Empty),
+		1 => Ok(unit),
+		_ => Ok(unit.without_offset()),
+	}
+}
+
+/// Parse a number followed by a unit, such as `9.81 m/s^2` or `20 degC`, into the number and its unit.  A bare number is dimensionless
+#[cfg(feature = "std")]
+pub fn parse_value(s: &str) -> Result<(Float, ParsedUnit), UnitParseError> {
+	parse_value_with_locale(s, CookingLocale::Us)
+}
+
+/// Parse a number followed by a unit as [parse_value], taking cooking measures in the convention of `locale`, e.g. `"1 tbsp"` is
+/// 20 mL for [CookingLocale::Australia]
+#[cfg(feature = "std")]
+pub fn parse_value_with_locale(s: &str, locale: CookingLocale) -> Result<(Float, ParsedUnit), UnitParseError> {
+	let (number, units) = split_value(s);
+	let value = number.parse().map_err(|_| UnitParseError::InvalidNumber(number.to_string()))?;
+	let unit = if units.is_empty() { ParsedUnit::ONE } else { parse_unit_with_locale(units, locale)? };
+	Ok((value, unit))
+}
+
+/// Split a string such as `9.81 m/s^2` into its leading number and the (trimmed) unit text following it
+pub fn split_value(s: &str) -> (&str, &str) {
+	let s = s.trim();
+	let mut end = 0;
+	for (i, c) in s.char_indices() {
+		let exponent_sign = (c == '+' || c == '-') && matches!(s[..i].chars().last(), Some('e' | 'E'));
+		let exponent = (c == 'e' || c == 'E') && s[i+1..].trim_start_matches(['+','-']).starts_with(|d: char| d.is_ascii_digit());
+		if c.is_ascii_digit() || c == '.' || (i == 0 && (c == '+' || c == '-')) || exponent || exponent_sign {
+			end = i + c.len_utf8();
+		} else {
+			break;
+		}
+	}
+	(&s[..end], s[end..].trim())
+}
+
+/// Look up a single unit symbol, optionally with an SI prefix (e.g. `km`, `mbar`, `µs`)
+pub fn lookup_symbol(symbol: &str) -> Option<ParsedUnit> {
+	if let Some(entry) = SYMBOLS.iter().find(|e| e.recognizes(symbol)) {
+		return Some(entry.unit());
+	}
+	PREFIXES.iter()
+		.filter_map(|(prefix, factor)| symbol.strip_prefix(prefix).map(|rest| (rest, *factor)))
+		.find_map(|(rest, factor)| {
+			SYMBOLS.iter()
+				.find(|e| e.prefixable && e.recognizes(rest))
+				.map(|e| ParsedUnit::new(factor*e.scale, e.dimension))
+		})
+}
+
+
+/// A unit the parser recognizes, by the symbol, plural and aliases of its [NamedUnit][crate::NamedUnit]
+struct Symbol {
+	symbol: &'static str,
+	plural: Option<&'static str>,
+	aliases: &'static [&'static str],
+	scale: Float,
+	offset: Float,
+	dimension: Dimension,
+	prefixable: bool,
+}
+impl Symbol {
+	const fn recognizes(&self, s: &str) -> bool {
+		str_eq(self.symbol, s) || matches!(self.plural, Some(plural) if str_eq(plural, s)) || str_in(s, self.aliases)
+	}
+	#[cfg(feature = "std")]
+	fn names(&self) -> impl Iterator<Item = &'static str> {
+		core::iter::once(self.symbol).chain(self.plural).chain(self.aliases.iter().copied())
+	}
+	const fn unit(&self) -> ParsedUnit {
+		ParsedUnit { scale: self.scale, offset: self.offset, dimension: self.dimension }
+	}
+}
+
+macro_rules! symbols {
+	(@prefix) => { false };
+	(@prefix true) => { true };
+	($($unit:expr $(, prefix $prefixable:tt)?;)*) => {
+		&[$({
+			let unit = $unit;
+			Symbol {
+				symbol: unit.symbol(), plural: unit.plural(), aliases: unit.aliases(), scale: unit.as_si(), offset: 0.0,
+				dimension: unit.dimension(), prefixable: symbols!(@prefix $($prefixable)?),
+			}
+		},)*]
+	};
+}
+
+const PREFIXES: &[(&str, Float)] = &[
+	("Q", 1e30), ("R", 1e27), ("Y", 1e24), ("Z", 1e21), ("E", 1e18), ("P", 1e15), ("T", 1e12), ("G", 1e9), ("M", 1e6), ("k", 1e3),
+	("h", 1e2), ("da", 1e1), ("d", 1e-1), ("c", 1e-2), ("m", 1e-3), ("u", 1e-6), ("µ", 1e-6), ("μ", 1e-6), ("n", 1e-9),
+	("p", 1e-12), ("f", 1e-15), ("a", 1e-18), ("z", 1e-21), ("y", 1e-24), ("r", 1e-27), ("q", 1e-30),
+];
+
+// Every linear unit in [units][crate::units], along with a few SI units which have no constant of their own.  Not listed are
+// the cooking measures resolved by locale (cup, tbsp, tsp, dsp), the log and reciprocal units, and RAD, whose "rad" means radian
+const SYMBOLS: &[Symbol] = symbols! {
+	PERCENT;
+	NamedUnit::new(MICRO*NONE, "ppm");
+	MICROSTRAIN;
+	DOZEN;
+
+	RADIAN, prefix true;
+	DEGREE;
+	ARCMIN;
+	ARCSEC;
+	TURN;
+	GRADIAN;
+	MRAD;
+	NATO_MIL;
+	STERADIAN;
+	SQUARE_DEGREE;
+	COUNT;
+	FIT;
+
+	SECOND, prefix true;
+	MINUTE;
+	HOUR;
+	DAY;
+	YEAR;
+	HERTZ, prefix true;
+	ROOT_HERTZ;
+	RPM;
+	BPM;
+
+	METER, prefix true;
+	INCH;
+	FOOT;
+	YARD;
+	MILE;
+	FURLONG;
+	NAUTICAL_MILE;
+	KNOT;
+	KPH;
+	MPH;
+	FPM;
+	ACRE;
+	HECTARE;
+	BARN;
+
+	LITER, prefix true;
+	US_BUSHEL;
+	US_GAL;
+	US_QUART;
+	US_PINT;
+	US_FL_OZ;
+	IMP_GAL;
+	IMP_PINT;
+	IMP_FL_OZ;
+	METRIC_CUP;
+	METRIC_TBSP;
+	METRIC_TSP;
+	AU_TBSP;
+	UK_CUP;
+	UK_TBSP;
+	UK_TSP;
+	MG_PER_DL;
+	MOLAR;
+	MMOL_PER_L;
+
+	GRAM, prefix true;
+	NamedUnit::new(MEGA*GRAM, "t");
+	POUND_MASS;
+	OUNCE_MASS;
+	GRAIN;
+	SLUG;
+	TEX;
+	DECITEX;
+	DENIER;
+	GSM;
+
+	NEWTON, prefix true;
+	POUNDAL;
+	POUND_FORCE;
+	LBF_PER_INCH;
+	PASCAL, prefix true;
+	PSI;
+	MEGAPASCAL;
+	GIGAPASCAL;
+	KSI;
+	BAR, prefix true;
+	TORR;
+	IN_HG;
+	MM_HG;
+	NamedUnit::new(consts::STANDARD_ATMOSPHERE, "atm");
+	POISE, prefix true;
+	CENTIPOISE;
+	STOKES, prefix true;
+	CENTISTOKES;
+	JOULE, prefix true;
+	WATT, prefix true;
+	WATT_HOUR, prefix true;
+	ELECTRONVOLT, prefix true;
+	TON_TNT;
+	BTU;
+	FOOT_POUND;
+	NEWTON_METER;
+	POUND_FOOT;
+
+	AMPERE, prefix true;
+	COULOMB, prefix true;
+	AMPERE_HOUR, prefix true;
+	VOLT, prefix true;
+	OHM, prefix true;
+	NamedUnit::new(1.0/OHM, "S"), prefix true;
+	FARAD, prefix true;
+	HENRY, prefix true;
+	WEBER, prefix true;
+	NamedUnit::new(WEBER/(METER*METER), "T"), prefix true;
+	VOLT_PER_METER;
+	WATT_PER_SQUARE_METER;
+	MILLIWATT_PER_SQUARE_CENTIMETER;
+	RPM_PER_VOLT;
+	RAD_PER_SECOND_PER_VOLT;
+	NEWTON_METER_PER_AMPERE;
+	KWH_PER_M2_PER_DAY;
+	PEAK_SUN_HOUR;
+
+	GRAY, prefix true;
+	SIEVERT, prefix true;
+	REM, prefix true;
+	USV_PER_HOUR;
+	MREM_PER_HOUR;
+	BECQUEREL, prefix true;
+	CURIE, prefix true;
+
+	KELVIN, prefix true;
+	RANKINE;
+	KELVIN_PER_WATT;
+	JOULE_PER_KELVIN;
+
+	MOLE, prefix true;
+	KATAL, prefix true;
+	ENZYME_UNIT;
+
+	CANDELA, prefix true;
+	LUMEN, prefix true;
+	LUX, prefix true;
+	FOOT_CANDLE;
+	NIT;
+	LUX_SECOND;
+};
+
+/// Entry for an offset temperature unit such as [CELSIUS], with the scale of `scale`
+const fn offset_symbol(unit: NamedUnit<OffsetUnit<Temperature>>, scale: NamedUnit<Temperature>) -> Symbol {
+	Symbol {
+		symbol: unit.symbol(), plural: unit.plural(), aliases: unit.aliases(), scale: scale.as_si(), offset: unit.zero_qty().as_si(),
+		dimension: scale.dimension(), prefixable: false,
+	}
+}
+
+const OFFSET_SYMBOLS: &[Symbol] = &[offset_symbol(CELSIUS, KELVIN), offset_symbol(FAHRENHEIT, RANKINE)];
+
+/// Cooking measure named `symbol` in the convention of `locale`, as these are the only symbols whose size depends on a locale
+const fn cooking_symbol(symbol: &str, locale: CookingLocale) -> Option<ParsedUnit> {
+	let unit = if str_in(symbol, &["cup", "cups"]) {
+		locale.cup()
+	} else if str_in(symbol, &["tbsp", "tablespoon", "tablespoons"]) {
+		locale.tablespoon()
+	} else if str_in(symbol, &["tsp", "teaspoon", "teaspoons"]) {
+		locale.teaspoon()
+	} else if str_in(symbol, &["dsp", "dessertspoon", "dessertspoons"]) {
+		locale.dessertspoon()
+	} else {
+		return None;
+	};
+	Some(ParsedUnit::new(unit.as_si(), unit.dimension()))
+}
+
+#[cfg(feature = "std")]
+fn lookup(symbol: &str, locale: CookingLocale) -> Option<ParsedUnit> {
+	OFFSET_SYMBOLS.iter()
+		.find(|e| e.recognizes(symbol))
+		.map(Symbol::unit)
+		.or_else(|| cooking_symbol(symbol, locale))
+		.or_else(|| lookup_symbol(symbol))
+}
+
+/// Longest unit name containing spaces, such as `fl oz`, at the start of `text`, with its length in characters
+#[cfg(feature = "std")]
+fn spaced_symbol(text: &str) -> Option<(usize, ParsedUnit)> {
+	SYMBOLS.iter()
+		.flat_map(|e| e.names().map(move |name| (name, e)))
+		.filter(|(name, _)| name.contains(' ') && text.starts_with(name) && !text[name.len()..].starts_with(is_symbol_char))
+		.max_by_key(|(name, _)| name.len())
+		.map(|(name, e)| (name.chars().count(), e.unit()))
+}
+
+// Compile-time lookup backing the `qty!` macro, which cannot use the iterator-based lookup above in const context
+
+const fn str_eq(a: &str, b: &str) -> bool {
+	let (a, b) = (a.as_bytes(), b.as_bytes());
+	if a.len() != b.len() {
+		return false;
+	}
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+	true
+}
+
+const fn str_in(s: &str, options: &[&str]) -> bool {
+	let mut i = 0;
+	while i < options.len() {
+		if str_eq(s, options[i]) {
+			return true;
+		}
+		i += 1;
+	}
+	false
+}
+
+const fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+	if s.len() < prefix.len() {
+		return None;
+	}
+	let (head, rest) = s.as_bytes().split_at(prefix.len());
+	match (core::str::from_utf8(head), core::str::from_utf8(rest)) {
+		(Ok(head), Ok(rest)) if str_eq(head, prefix) => Some(rest),
+		_ => None,
+	}
+}
+
+/// Const equivalent of [lookup], with US customary cooking measures
+const fn const_lookup(symbol: &str) -> Option<ParsedUnit> {
+	if let Some(unit) = cooking_symbol(symbol, CookingLocale::Us) {
+		return Some(unit);
+	}
+	let mut i = 0;
+	while i < OFFSET_SYMBOLS.len() {
+		if OFFSET_SYMBOLS[i].recognizes(symbol) {
+			return Some(OFFSET_SYMBOLS[i].unit());
+		}
+		i += 1;
+	}
+	let mut i = 0;
+	while i < SYMBOLS.len() {
+		if SYMBOLS[i].recognizes(symbol) {
+			return Some(SYMBOLS[i].unit());
+		}
+		i += 1;
+	}
+	let mut p = 0;
+	while p < PREFIXES.len() {
+		let (prefix, factor) = PREFIXES[p];
+		if let Some(rest) = strip_prefix(symbol, prefix) {
+			let mut i = 0;
+			while i < SYMBOLS.len() {
+				if SYMBOLS[i].prefixable && SYMBOLS[i].recognizes(rest) {
+					return Some(ParsedUnit::new(factor*SYMBOLS[i].scale, SYMBOLS[i].dimension));
+				}
+				i += 1;
+			}
+		}
+		p += 1;
+	}
+	None
+}
+
+/// Unit of a single symbol, failing compilation if it is unknown.  Used by the `qty!` macro
+#[doc(hidden)]
+pub const fn symbol_unit(symbol: &str) -> ParsedUnit {
+	match const_lookup(symbol) {
+		Some(unit) => unit,
+		None => panic!("unknown unit symbol in qty!"),
+	}
+}
+
+/// Product of two units, dropping any offset.  Used by the `qty!` macro
+#[doc(hidden)]
+pub const fn unit_product(a: ParsedUnit, b: ParsedUnit) -> ParsedUnit {
+	a.mul(b)
+}
+
+/// Quotient of two units, dropping any offset.  Used by the `qty!` macro
+#[doc(hidden)]
+pub const fn unit_quotient(a: ParsedUnit, b: ParsedUnit) -> ParsedUnit {
+	a.div(b)
+}
+
+/// Integer power of a unit, dropping any offset.  Used by the `qty!` macro
+#[doc(hidden)]
+pub const fn unit_power(unit: ParsedUnit, power: i32) -> ParsedUnit {
+	// Repeated multiplication, as `powi` is not const
+	let mut result = ParsedUnit::ONE;
+	let mut i = 0;
+	while i < power.unsigned_abs() {
+		result = result.mul(unit);
+		i += 1;
+	}
+	if power < 0 { ParsedUnit::ONE.div(result) } else { result }
+}
+
+
+#[cfg(feature = "std")]
+struct Parser {
+	chars: Vec<char>,
+	pos: usize,
+	locale: CookingLocale,
+	/// Number of parentheses the parser is inside
+	depth: usize,
+}
+
+/// Characters of input considered when matching unit names containing spaces, more than the longest such name
+#[cfg(feature = "std")]
+const MAX_SPACED_SYMBOL: usize = 32;
+
+/// Deepest nesting of parentheses accepted, so hostile input cannot overflow the stack of the recursive parser
+#[cfg(feature = "std")]
+const MAX_DEPTH: usize = 32;
+
+#[cfg(feature = "std")]
+impl Parser {
+	fn peek(&self) -> Option<char> { self.chars.get(self.pos).copied() }
+	fn peek_at(&self, offset: usize) -> Option<char> { self.chars.get(self.pos+offset).copied() }
+	fn skip_spaces(&mut self) {
+		while self.peek().is_some_and(char::is_whitespace) {
+			self.pos += 1;
+		}
+	}
+	fn unexpected(&self) -> UnitParseError {
+		match self.peek() {
+			Some(character) => UnitParseError::UnexpectedCharacter { character, position: self.pos },
+			None => UnitParseError::Empty,
+		}
+	}
+
+	/// Product of factors up to the end of input or a closing parenthesis, along with the number of factors
+	fn expression(&mut self) -> Result<(ParsedUnit, usize), UnitParseError> {
+		let mut unit = ParsedUnit::ONE;
+		let mut factors = 0;
+		loop {
+			self.skip_spaces();
+			let divide = match self.peek() {
+				None | Some(')') => break,
+				Some('*') => {
+					self.pos += if self.peek_at(1) == Some('*') { 2 } else { 1 };
+					false
+				},
+				Some('.' | '·' | '⋅') if factors > 0 => { self.pos += 1; false },
+				Some('/') => { self.pos += 1; true },
+				Some(_) => false,
+			};
+			self.skip_spaces();
+			let factor = self.factor()?;
+			unit = if factors == 0 && !divide {
+				factor
+			} else if divide {
+				unit.checked_div(factor)?
+			} else {
+				unit.checked_mul(factor)?
+			};
+			factors += 1;
+		}
+		Ok((unit, factors))
+	}
+
+	/// A symbol, number or parenthesized expression with an optional exponent
+	fn factor(&mut self) -> Result<ParsedUnit, UnitParseError> {
+		let (base, implicit_exponent) = match self.peek() {
+			Some('(') if self.depth >= MAX_DEPTH => return Err(UnitParseError::TooDeeplyNested),
+			Some('(') => {
+				self.pos += 1;
+				self.depth += 1;
+				let (inner, factors) = self.expression()?;
+				self.depth -= 1;
+				if self.peek() != Some(')') {
+					return Err(UnitParseError::UnbalancedParentheses);
+				}
+				self.pos += 1;
+				if factors == 0 {
+					return Err(UnitParseError::Empty);
+				}
+				(if factors == 1 { inner } else { inner.without_offset() }, true)
+			},
+			Some(c) if c.is_ascii_digit() || (c == '.' && self.peek_at(1).is_some_and(|d| d.is_ascii_digit())) => {
+				(ParsedUnit::new(self.number()?, Dimension::NONE), false)
+			},
+			Some(c) if is_symbol_char(c) => {
+				let text: String = self.chars[self.pos..].iter().take(MAX_SPACED_SYMBOL).collect();
+				if let Some((len, unit)) = spaced_symbol(&text) {
+					self.pos += len;
+					(unit, true)
+				} else {
+					let start = self.pos;
+					while self.peek().is_some_and(is_symbol_char) {
+						self.pos += 1;
+					}
+					let symbol: String = self.chars[start..self.pos].iter().collect();
+					(lookup(&symbol, self.locale).ok_or(UnitParseError::UnknownSymbol(symbol))?, true)
+				}
+			},
+			_ => return Err(self.unexpected()),
+		};
+		let (power, root) = self.exponent(implicit_exponent)?;
+		Ok(match (power, root) {
+			(1, 1) => base,
+			(_, 1) => base.without_offset().powi(power)?,
+			_ => base.without_offset().powi(power)?.root(root)?,
+		})
+	}
+
+	/// Numeric factor such as `1000`, `0.5` or `1e-3`
+	fn number(&mut self) -> Result<Float, UnitParseError> {
+		let start = self.pos;
+		while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+			self.pos += 1;
+		}
+		if matches!(self.peek(), Some('e' | 'E')) {
+			let digits_at = if matches!(self.peek_at(1), Some('+' | '-')) { 2 } else { 1 };
+			if self.peek_at(digits_at).is_some_and(|c| c.is_ascii_digit()) {
+				self.pos += digits_at;
+				while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+					self.pos += 1;
+				}
+			}
+		}
+		let text: String = self.chars[start..self.pos].iter().collect();
+		text.parse().map_err(|_| UnitParseError::InvalidNumber(text))
+	}
+
+	/// Exponent following a factor as a numerator and denominator: `^n`, `**n`, `^(n/m)`, superscript digits, or (when `implicit`)
+	/// a bare signed integer
+	fn exponent(&mut self, implicit: bool) -> Result<(i32, i32), UnitParseError> {
+		let explicit = match (self.peek(), self.peek_at(1)) {
+			(Some('^'), _) => 1,
+			(Some('*'), Some('*')) => 2,
+			(Some(c), _) if superscript_digit(c).is_some() || c == '⁻' => return Ok((self.superscript()?, 1)),
+			(Some(c), _) if implicit && c.is_ascii_digit() => return Ok((self.integer()?, 1)),
+			(Some('+' | '-'), Some(d)) if implicit && d.is_ascii_digit() => return Ok((self.integer()?, 1)),
+			_ => return Ok((1, 1)),
+		};
+		self.pos += explicit;
+		if self.peek() != Some('(') {
+			return Ok((self.integer()?, 1));
+		}
+		self.pos += 1;
+		let power = self.integer()?;
+		let root = if self.peek() == Some('/') {
+			self.pos += 1;
+			self.integer()?
+		} else {
+			1
+		};
+		if self.peek() != Some(')') {
+			return Err(UnitParseError::UnbalancedParentheses);
+		}
+		self.pos += 1;
+		if root <= 0 {
+			return Err(UnitParseError::InvalidNumber(root.to_string()));
+		}
+		Ok((power, root))
+	}
+
+	fn integer(&mut self) -> Result<i32, UnitParseError> {
+		let start = self.pos;
+		if matches!(self.peek(), Some('+' | '-')) {
+			self.pos += 1;
+		}
+		while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+			self.pos += 1;
+		}
+		let text: String = self.chars[start..self.pos].iter().collect();
+		text.parse().map_err(|_| UnitParseError::InvalidNumber(text))
+	}
+
+	fn superscript(&mut self) -> Result<i32, UnitParseError> {
+		let negative = self.peek() == Some('⁻');
+		if negative {
+			self.pos += 1;
+		}
+		let start = self.pos;
+		let mut value: Option<i32> = None;
+		while let Some(digit) = self.peek().and_then(superscript_digit) {
+			value = value.unwrap_or(0).checked_mul(10).and_then(|v| v.checked_add(digit));
+			if value.is_none() {
+				let text = self.chars[start..].iter().take_while(|&&c| superscript_digit(c).is_some()).collect();
+				return Err(UnitParseError::InvalidNumber(text));
+			}
+			self.pos += 1;
+		}
+		match value {
+			Some(v) => Ok(if negative { -v } else { v }),
+			None => Err(UnitParseError::InvalidNumber("⁻".to_string())),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+fn is_symbol_char(c: char) -> bool {
+	(c.is_alphabetic() && superscript_digit(c).is_none()) || matches!(c, '°' | 'µ' | 'Ω' | '%' | '_' | '′' | '″' | '√')
+}
+
+#[cfg(feature = "std")]
+fn superscript_digit(c: char) -> Option<i32> {
+	match c {
+		'⁰' => Some(0), '¹' => Some(1), '²' => Some(2), '³' => Some(3), '⁴' => Some(4),
+		'⁵' => Some(5), '⁶' => Some(6), '⁷' => Some(7), '⁸' => Some(8), '⁹' => Some(9),
+		_ => None,
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use super::*;
+
+	fn assert_unit(s: &str, scale: Float, dimension: Dimension) {
+		let unit = parse_unit(s).unwrap_or_else(|e| panic!("'{}' failed to parse: {}", s, e));
+		assert!((unit.scale() - scale).abs() <= 64.0*Float::EPSILON*scale.abs(), "'{}' has scale {}, expected {}", s, unit.scale(), scale);
+		assert_eq!(unit.dimension(), dimension, "'{}'", s);
+	}
+
+	#[test]
+	fn prefixes() {
+		let length = Dimension::new(0, 1, 0, 0, 0, 0, 0, 0, 0);
+		assert_unit("km", 1e3, length);
+		assert_unit("mm", 1e-3, length);
+		assert_unit("µm", 1e-6, length);
+		assert_unit("um", 1e-6, length);
+		assert_unit("mbar", 100.0, Dimension::new(-2, -1, 1, 0, 0, 0, 0, 0, 0));
+		// Whole symbols take precedence over a prefix and a shorter symbol
+		assert_unit("h", 3600.0, Dimension::new(1, 0, 0, 0, 0, 0, 0, 0, 0));
+		assert_unit("min", 60.0, Dimension::new(1, 0, 0, 0, 0, 0, 0, 0, 0));
+	}
+
+	#[test]
+	fn powers() {
+		let acceleration = Dimension::new(-2, 1, 0, 0, 0, 0, 0, 0, 0);
+		let per_second_squared = Dimension::new(-2, 0, 0, 0, 0, 0, 0, 0, 0);
+		let second_squared = Dimension::new(2, 0, 0, 0, 0, 0, 0, 0, 0);
+		assert_unit("m/s^2", 1.0, acceleration);
+		assert_unit("m s-2", 1.0, acceleration);
+		assert_unit("m·s⁻²", 1.0, acceleration);
+		assert_unit("s^-2", 1.0, per_second_squared);
+		assert_unit("s**2", 1.0, second_squared);
+		assert_unit("s2", 1.0, second_squared);
+		assert_unit("s²", 1.0, second_squared);
+		assert_unit("km^2", 1e6, Dimension::new(0, 2, 0, 0, 0, 0, 0, 0, 0));
+		assert_unit("(0.1 m)^3", 1e-3, Dimension::new(0, 3, 0, 0, 0, 0, 0, 0, 0));
+		assert_unit("Hz^(-1/2)", 1.0, Dimension::new(1, 0, 0, 0, 0, 0, 0, 0, 0).root(2));
+	}
+
+	#[test]
+	fn named_units() {
+		let tolerance = 16.0*Float::EPSILON;
+		let torque = (JOULE/RADIAN).dimension();
+		for entry in SYMBOLS.iter().chain(OFFSET_SYMBOLS) {
+			for name in entry.names() {
+				let unit = parse_unit(name).unwrap_or_else(|e| panic!("'{}' failed to parse: {}", name, e));
+				assert!((unit.scale() - entry.scale).abs() <= tolerance*entry.scale, "'{}' has scale {}, expected {}", name, unit.scale(), entry.scale);
+				assert_eq!(unit.offset(), entry.offset, "'{}'", name);
+				// Torque is energy per radian, which symbols such as N·m leave out.  They parse as torque through FromStr instead
+				if !(entry.dimension/unit.dimension()).equals(&(torque/JOULE.dimension())) {
+					assert_eq!(unit.dimension(), entry.dimension, "'{}'", name);
+				}
+			}
+		}
+		assert_unit("3 fl oz", 3.0*US_FL_OZ.as_si(), US_FL_OZ.dimension());
+		assert_unit("imp fl oz/s", IMP_FL_OZ.as_si(), (IMP_FL_OZ/SECOND).dimension());
+		assert_unit("feet", FOOT.as_si(), FOOT.dimension());
+		assert_unit("kohms", 1e3, OHM.dimension());
+	}
+
+	#[test]
+	fn unique_names() {
+		let entries = || SYMBOLS.iter().chain(OFFSET_SYMBOLS);
+		let names: Vec<&str> = entries().flat_map(Symbol::names).collect();
+		for (i, name) in names.iter().enumerate() {
+			assert!(!names[..i].contains(name), "'{}' names more than one unit", name);
+			assert!(cooking_symbol(name, CookingLocale::Us).is_none(), "'{}' is also a cooking measure", name);
+		}
+		// A prefixed unit must not be spelled the same as a different unit, which would hide it
+		for entry in entries() {
+			for name in entry.names() {
+				for (prefix, factor) in PREFIXES {
+					if let Some(rest) = name.strip_prefix(prefix)
+						&& let Some(base) = SYMBOLS.iter().find(|e| e.prefixable && e.recognizes(rest)) {
+						let same = base.dimension == entry.dimension && (factor*base.scale - entry.scale).abs() <= 1e-6*entry.scale;
+						assert!(same, "'{}' is both a unit and a prefixed '{}'", name, rest);
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn offset_units() {
+		let (value, unit) = parse_value("5 degC").unwrap();
+		assert_eq!(value, 5.0);
+		assert!((unit.to_si(value) - 278.15).abs() < 1e-9);
+		assert!((unit.from_si(373.15) - 100.0).abs() < 1e-9);
+		// Inside a compound unit an offset unit is a temperature difference
+		let rate = parse_unit("degC/s").unwrap();
+		assert_eq!(rate.offset(), 0.0);
+		assert_eq!(rate.to_si(5.0), 5.0);
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(parse_unit("furlongs"), Err(UnitParseError::UnknownSymbol("furlongs".to_string())));
+		assert_eq!(parse_unit(""), Err(UnitParseError::Empty));
+		assert_eq!(parse_unit("(m/s"), Err(UnitParseError::UnbalancedParentheses));
+		assert_eq!(parse_unit("m/s)"), Err(UnitParseError::UnbalancedParentheses));
+		assert!(matches!(parse_unit("m/s#"), Err(UnitParseError::UnexpectedCharacter { character: '#', .. })));
+		assert!(matches!(parse_value("1.2.3 m"), Err(UnitParseError::InvalidNumber(_))));
+		assert!(matches!(parse_unit("m¹¹¹¹¹¹¹¹¹¹¹¹"), Err(UnitParseError::InvalidNumber(_))));
+		assert!(matches!(parse_unit("m^99999999999"), Err(UnitParseError::InvalidNumber(_))));
+		let nested = |depth: usize| "(".repeat(depth) + "m" + &")".repeat(depth);
+		assert!(parse_unit(&nested(MAX_DEPTH)).is_ok());
+		assert_eq!(parse_unit(&nested(MAX_DEPTH + 1)), Err(UnitParseError::TooDeeplyNested));
+		assert_eq!(parse_unit(&nested(1_000_000)), Err(UnitParseError::TooDeeplyNested));
+		let nested_roots = "(".repeat(8) + "m" + &")^(1/1000)".repeat(8);
+		assert_eq!(parse_unit(&nested_roots), Err(UnitParseError::PowerOverflow));
+		assert_eq!(parse_unit("((m^2000000000)^2000000000)^2000000000"), Err(UnitParseError::PowerOverflow));
+		assert_eq!(parse_unit("m^(1/2000000000)/s^(1/1999999999)/K^(1/1999999997)"), Err(UnitParseError::PowerOverflow));
+	}
+
+	#[test]
+	fn quantity_from_str() {
+		use crate::dimens::*;
+		let speed: Velocity = "12.5 km/h".parse().unwrap();
+		assert!((speed.as_unit(METER/SECOND) - 12.5/3.6).abs() < 1e-12);
+		let temperature: Temperature = "5 degC".parse().unwrap();
+		assert!((temperature.as_unit(KELVIN) - 278.15).abs() < 1e-9);
+		let ratio: Float = "0.5".parse::<Quantity<0,0,0,0,0,0,0,0,0>>().unwrap().into();
+		assert_eq!(ratio, 0.5);
+
+		let mismatch = "3 kg".parse::<Length>().unwrap_err();
+		assert_eq!(mismatch, QuantityParseError::Dimension(DimensionMismatch {
+			expected: Length::DIMENSION, found: Mass::DIMENSION,
+		}));
+		assert!(matches!("3".parse::<Length>(), Err(QuantityParseError::Dimension(_))));
+		assert!(matches!("3 parsecs".parse::<Length>(), Err(QuantityParseError::Units(UnitParseError::UnknownSymbol(_)))));
+		assert!(matches!("fast".parse::<Velocity>(), Err(QuantityParseError::Units(UnitParseError::InvalidNumber(_)))));
+
+		// Torque units leave out the radian of Torque, but still parse as torque
+		let torque: Torque = "5 N·m".parse().unwrap();
+		assert!((torque.as_unit(NEWTON_METER) - 5.0).abs() < 1e-12);
+		let torque: Torque = "30 lbf·ft".parse().unwrap();
+		assert!((torque.as_unit(POUND_FOOT) - 30.0).abs() < 1e-12);
+		let energy: Energy = "5 N·m".parse().unwrap();
+		assert_eq!(energy.as_unit(JOULE), 5.0);
+		for (torque, unit) in [(12.5*NEWTON_METER, NEWTON_METER), (40.0*POUND_FOOT, POUND_FOOT)] {
+			let text = torque.display_as(unit).to_string();
+			let parsed: Torque = text.parse().unwrap_or_else(|e| panic!("'{}' failed to parse: {}", text, e));
+			assert!((parsed.as_si() - torque.as_si()).abs() <= 1e-12*torque.as_si(), "'{}'", text);
+		}
+	}
+
+	#[test]
+	fn cooking_locales() {
+		let ml = |s: &str, locale| {
+			let (value, unit) = parse_value_with_locale(s, locale).unwrap();
+			unit.to_si(value)*1e6
+		};
+		assert!((ml("3 cup", CookingLocale::Us) - 709.765).abs() < 1e-3);
+		let close = |a: Float, b: Float| (a - b).abs() <= 64.0*Float::EPSILON*b;
+		assert!(close(ml("3 cups", CookingLocale::Metric), 750.0));
+		assert!((ml("1 tbsp", CookingLocale::Us) - 14.787).abs() < 1e-3);
+		assert!(close(ml("1 tbsp", CookingLocale::Metric), 15.0));
+		assert!(close(ml("1 tablespoon", CookingLocale::Australia), 20.0));
+		assert!(close(ml("2 tsp", CookingLocale::UkImperial), 2.0*UK_TSP.as_si()*1e6));
+		assert!(close(ml("1 dsp", CookingLocale::Metric), 10.0));
+		// Without a locale cooking measures are US customary, also inside compound units
+		let (value, unit) = parse_value("1 tbsp").unwrap();
+		assert_eq!(unit.to_si(value), US_TBSP.as_si());
+		assert!((parse_unit("g/cup").unwrap().scale() - 1e-3/US_CUP.as_si()).abs() < 1e-9);
+	}
+}
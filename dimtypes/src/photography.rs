@@ -0,0 +1,123 @@
+//! Photography and lighting helpers
+//!
+//! Exposure settings are combined into the quantity N²/t (f-number squared over shutter time), whose base 2 logarithm is the
+//! familiar EV number.  The [EXPOSURE_VALUE][crate::units::EXPOSURE_VALUE] log unit gives the EV at ISO 100 of the scene luminance
+//! a reflected light meter calibrates to those settings.  Each stop is a factor of two, so ratios between exposures can be shown
+//! with the [STOP][crate::units::STOP] log unit.
+//!
+//! Exposure at the sensor is a [LuminousExposure] H = E·t in lux seconds.  The ISO 12232 and ISO 2720 relations tie it to the
+//! sensitivity (ISO speed) of the sensor and to the settings an incident light meter recommends for a scene illuminance.
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::{HERTZ,LUX_SECOND,MIRED,NIT,SECOND};
+
+/// Incident light meter calibration constant C in lux seconds for a flat receptor (ISO 2720 allows 240 to 400)
+pub const INCIDENT_CALIBRATION: Float = 250.0;
+
+/// Reflected light meter calibration constant K in cd·s/m² (ISO 2720 allows 10.6 to 13.4), as used by
+/// [EXPOSURE_VALUE][crate::units::EXPOSURE_VALUE]
+pub const REFLECTED_CALIBRATION: Float = 12.5;
+
+/// Mired shift needed to convert light of correlated color temperature `source` to `target`.  Positive shifts warm the light (amber
+/// filters/gels), negative shifts cool it (blue filters/gels)
+pub fn mired_shift(source: Temperature, target: Temperature) -> Float {
+	target.as_unit(MIRED) - source.as_unit(MIRED)
+}
+
+/// Color temperature resulting from applying a filter with the given mired `shift` to light of color temperature `source`
+pub fn apply_mired_shift(source: Temperature, shift: Float) -> Temperature {
+	(source.as_unit(MIRED) + shift)*MIRED
+}
+
+/// Exposure setting N²/t of an `aperture` f-number and `shutter` time, whose base 2 logarithm in s<sup>-1</sup> is the exposure value
+pub fn exposure_settings(aperture: Unitless, shutter: Time) -> Frequency {
+	aperture*aperture/shutter
+}
+
+/// Exposure value EV = log<sub>2</sub>(N²/t) of an `aperture` f-number and `shutter` time
+pub fn exposure_value(aperture: Unitless, shutter: Time) -> Float {
+	Float::log2(exposure_settings(aperture, shutter).as_unit(HERTZ))
+}
+
+/// Exposure settings N²/t with exposure value `ev`
+fn settings_for(ev: Float) -> Frequency {
+	Float::exp2(ev)*HERTZ
+}
+
+/// Shutter time giving exposure value `ev` at the given `aperture` f-number
+pub fn shutter_for(ev: Float, aperture: Unitless) -> Time {
+	aperture*aperture/settings_for(ev)
+}
+
+/// Aperture f-number giving exposure value `ev` at the given `shutter` time
+pub fn aperture_for(ev: Float, shutter: Time) -> Unitless {
+	(settings_for(ev)*shutter).root::<2>()
+}
+
+/// Exposure settings N²/t recommended by a reflected light meter for a scene of average `luminance` at ISO sensitivity `iso`, from
+/// the reciprocity relation N²/t = L·S/K with K = [REFLECTED_CALIBRATION].  At ISO 100 its exposure value is
+/// <code>luminance.as_unit([EXPOSURE_VALUE][crate::units::EXPOSURE_VALUE])</code>
+pub fn reflected_settings(luminance: Luminance, iso: Float) -> Frequency {
+	luminance*iso/(REFLECTED_CALIBRATION*NIT*SECOND)
+}
+
+/// Exposure value at ISO sensitivity `iso` equivalent to `ev100` metered at ISO 100.  Each doubling of sensitivity adds one stop
+pub fn ev_at_iso(ev100: Float, iso: Float) -> Float {
+	ev100 + Float::log2(iso/100.0)
+}
+
+/// Luminous exposure H = E·t of a sensor illuminated at `illuminance` for `shutter`
+pub fn luminous_exposure(illuminance: Illuminance, shutter: Time) -> LuminousExposure {
+	illuminance*shutter
+}
+
+/// Standard output sensitivity (ISO 12232) of a sensor that reaches its standard output level at exposure `exposure`, S = 10/H
+pub fn sos_sensitivity(exposure: LuminousExposure) -> Float {
+	10.0/exposure.as_unit(LUX_SECOND)
+}
+
+/// Saturation based ISO speed (ISO 12232) of a sensor that saturates at exposure `exposure`, S = 78/H
+pub fn saturation_sensitivity(exposure: LuminousExposure) -> Float {
+	78.0/exposure.as_unit(LUX_SECOND)
+}
+
+/// Exposure at which a sensor of standard output sensitivity `iso` reaches its standard output level
+pub fn exposure_for_sensitivity(iso: Float) -> LuminousExposure {
+	10.0/iso*LUX_SECOND
+}
+
+/// Exposure settings N²/t recommended by an incident light meter for a scene `illuminance` at ISO sensitivity `iso`, from the
+/// reciprocity relation N²/t = E·S/C with C = [INCIDENT_CALIBRATION]
+pub fn incident_settings(illuminance: Illuminance, iso: Float) -> Frequency {
+	illuminance*iso/(INCIDENT_CALIBRATION*LUX_SECOND)
+}
+
+/// Shutter time recommended for a scene `illuminance` at ISO sensitivity `iso` and `aperture` f-number
+pub fn shutter_for_illuminance(illuminance: Illuminance, iso: Float, aperture: Unitless) -> Time {
+	aperture*aperture/incident_settings(illuminance, iso)
+}
+
+/// Scene illuminance for which `aperture` f-number and `shutter` time give correct exposure at ISO sensitivity `iso`
+pub fn illuminance_for(aperture: Unitless, shutter: Time, iso: Float) -> Illuminance {
+	exposure_settings(aperture, shutter)*INCIDENT_CALIBRATION*LUX_SECOND/iso
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn exposure_value_of_settings_and_luminance() {
+		// f/8 at 1/125 s is EV 13
+		let ev = exposure_value(Unitless::from(8.0), 0.008*SECOND);
+		assert!((ev - 12.966).abs() < 1e-3);
+		assert!((shutter_for(ev, Unitless::from(8.0)).as_unit(SECOND) - 0.008).abs() <= 64.0*Float::EPSILON*0.008);
+		// A reflected meter gives the same EV at ISO 100 for the luminance it calibrates to those settings
+		let luminance = 4000.0*NIT;
+		let shutter = Unitless::from(64.0)/reflected_settings(luminance, 100.0);
+		assert!((exposure_value(Unitless::from(8.0), shutter) - luminance.as_unit(EXPOSURE_VALUE)).abs() <= 64.0*Float::EPSILON*ev);
+		assert!((0.125*NIT).as_unit(EXPOSURE_VALUE).abs() <= 64.0*Float::EPSILON);
+	}
+}
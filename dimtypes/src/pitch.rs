@@ -0,0 +1,125 @@
+//! Musical pitch: MIDI note numbers, note names and tuning deviations
+//!
+//! Pitch is logarithmic in frequency, so the units here are [LogUnit][crate::LogUnit]s of frequency ratios: [OCTAVE][crate::units::OCTAVE], [SEMITONE] and
+//! [CENT] measure intervals, and [MIDI_NOTE] measures absolute pitch from note 0 (C-1).  A [Tuning] sets the frequency of A4 for
+//! ensembles tuned away from 440 Hz, and converts between frequencies, note numbers and note names in scientific pitch notation,
+//! e.g. `"C#4"` or `"Bb3"`.
+
+use std::fmt;
+use crate::{Float,Unit};
+use crate::dimens::*;
+use crate::units::{HERTZ,MIDI_NOTE,SEMITONE,CENT};
+
+/// Names of the pitch classes from C, with sharps for the black keys
+const NOTE_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+
+/// Note name that could not be parsed by [parse_note]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoteParseError(pub String);
+
+impl fmt::Display for NoteParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid note name \"{}\"", self.0)
+	}
+}
+
+impl std::error::Error for NoteParseError {}
+
+/// Name of MIDI note `note` in scientific pitch notation, e.g. `"A4"` for 69 and `"C-1"` for 0, using sharps for the black keys
+pub fn note_name(note: i32) -> String {
+	format!("{}{}", NOTE_NAMES[note.rem_euclid(12) as usize], note.div_euclid(12) - 1)
+}
+
+/// MIDI note number of a note name in scientific pitch notation, e.g. `"C4"`, `"F#3"`, `"Bb-1"` or `"E♭5"`
+pub fn parse_note(name: &str) -> Result<i32, NoteParseError> {
+	let error = || NoteParseError(name.to_string());
+	let mut chars = name.trim().chars();
+	let class = match chars.next().map(|c| c.to_ascii_uppercase()) {
+		Some('C') => 0, Some('D') => 2, Some('E') => 4, Some('F') => 5, Some('G') => 7, Some('A') => 9, Some('B') => 11,
+		_ => return Err(error()),
+	};
+	let rest = chars.as_str();
+	let accidental = match rest.chars().next() {
+		Some('#' | '♯') => 1,
+		Some('b' | '♭') => -1,
+		_ => 0,
+	};
+	let octave = if accidental == 0 { rest } else { &rest[rest.chars().next().map_or(0, char::len_utf8)..] };
+	let octave: i32 = octave.parse().map_err(|_| error())?;
+	octave.checked_add(1).and_then(|o| o.checked_mul(12)).and_then(|n| n.checked_add(class + accidental)).ok_or_else(error)
+}
+
+/// Equal-tempered tuning with A4 at a reference frequency
+#[derive(Clone, Copy, Debug)]
+pub struct Tuning {
+	a4: Frequency,
+}
+
+impl Tuning {
+	/// Standard concert pitch, A4 = 440 Hz, matching [MIDI_NOTE]
+	pub const STANDARD: Tuning = Tuning { a4: Frequency::from_si(440.0) };
+
+	/// Tuning with A4 at `a4`, e.g. 442 Hz for many orchestras or 415 Hz for baroque pitch
+	pub const fn new(a4: Frequency) -> Tuning {
+		Tuning { a4 }
+	}
+
+	pub const fn a4(&self) -> Frequency {
+		self.a4
+	}
+
+	/// Frequency of `note`, which may be fractional for pitches between notes
+	pub fn frequency(&self, note: Float) -> Frequency {
+		MIDI_NOTE.val_to_qty(note)*Float::from(self.a4/(440.0*HERTZ))
+	}
+
+	/// Note number of `frequency`, fractional for pitches between notes
+	pub fn note(&self, frequency: Frequency) -> Float {
+		MIDI_NOTE.qty_to_val(frequency*Float::from(440.0*HERTZ/self.a4))
+	}
+
+	/// Nearest note to `frequency` and the deviation from it in cents, e.g. `(69, 3.9)` for 441 Hz, as a tuner displays it
+	pub fn nearest(&self, frequency: Frequency) -> (i32, Float) {
+		let note = self.note(frequency);
+		let nearest = note.round();
+		(nearest as i32, (note - nearest)*100.0)
+	}
+
+	/// Frequency of the note named `name`, e.g. `"A4"`
+	pub fn frequency_of(&self, name: &str) -> Result<Frequency, NoteParseError> {
+		Ok(self.frequency(parse_note(name)? as Float))
+	}
+}
+
+impl Default for Tuning {
+	fn default() -> Tuning {
+		Tuning::STANDARD
+	}
+}
+
+/// Interval from `from` to `to` in cents, positive if `to` is higher
+pub fn interval_cents(from: Frequency, to: Frequency) -> Float {
+	CENT.qty_to_val(to/from)
+}
+
+/// Frequency `semitones` (fractional semitones allowed) above `frequency`, below for negative values
+pub fn transpose(frequency: Frequency, semitones: Float) -> Frequency {
+	frequency*SEMITONE.val_to_qty(semitones)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn note_names() {
+		assert_eq!(parse_note("A4"), Ok(69));
+		assert_eq!(parse_note("C-1"), Ok(0));
+		assert_eq!(parse_note("Cb-1"), Ok(-1));
+		assert_eq!(parse_note("e♭5"), Ok(75));
+		assert_eq!(note_name(parse_note("F#3").unwrap()), "F#3");
+		for name in ["", "H4", "C", "C#", "A99999999999", "A178956970", "C-178956972"] {
+			assert_eq!(parse_note(name), Err(NoteParseError(name.to_string())), "{}", name);
+		}
+	}
+}
@@ -0,0 +1,29 @@
+//! Property testing with proptest, enabled with the `proptest` feature
+//!
+//! Every [Quantity] implements proptest's [Arbitrary], generating finite values of any sign and magnitude in SI base units, so
+//! `proptest!` tests can take typed arguments directly, e.g. `fn energy_is_positive(m: Mass, v: Velocity)`.  [in_range] restricts
+//! values to a physically meaningful range, which most tests of real formulas want.
+
+use ::proptest::arbitrary::Arbitrary;
+use ::proptest::strategy::{Map,Strategy};
+use crate::{AnyQuantity,Float,Quantity};
+
+#[cfg(not(feature = "f32"))]
+use ::proptest::num::f64 as float;
+#[cfg(feature = "f32")]
+use ::proptest::num::f32 as float;
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Arbitrary for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	type Parameters = ();
+	type Strategy = Map<float::Any, fn(Float) -> Self>;
+
+	fn arbitrary_with(_: ()) -> Self::Strategy {
+		(float::POSITIVE | float::NEGATIVE | float::NORMAL | float::SUBNORMAL | float::ZERO).prop_map(Quantity::from_si)
+	}
+}
+
+/// Strategy generating quantities from `low` to `high` inclusive, e.g. `in_range(-40.0*CELSIUS, 125.0*CELSIUS)`
+pub fn in_range<Q: AnyQuantity>(low: Q, high: Q) -> impl Strategy<Value=Q> {
+	(low.as_si()..=high.as_si()).prop_map(Q::from_si)
+}
@@ -0,0 +1,38 @@
+//! Matter waves and black-body radiation, derived from the constants in [consts][crate::consts]
+//!
+//! Every result is computed from [PLANK_CONSTANT], [SPEED_OF_LIGHT] and [BOLTZMANN_CONSTANT] with their dimensions carried through,
+//! so e.g. the fifth power of wavelength in Planck's law is checked against the [SpectralRadiance] it produces.
+
+use crate::{Float,Quantity};
+use crate::consts::{PLANK_CONSTANT,SPEED_OF_LIGHT,BOLTZMANN_CONSTANT};
+use crate::dimens::*;
+use crate::units::STERADIAN;
+
+/// Root of x = 5(1 - e<sup>-x</sup>), giving the peak of Planck's law in wavelength at x = hc/λkT
+const WIEN_WAVELENGTH_ROOT: Float = 4.965114231744276;
+
+/// Wien's displacement constant b = hc/(k x), the product of black-body temperature and peak wavelength (about 2.898 mm K)
+pub const WIEN_DISPLACEMENT_CONSTANT: Quantity<0,1,0,0,1,0,0,0,0> = PLANK_CONSTANT*SPEED_OF_LIGHT/BOLTZMANN_CONSTANT/WIEN_WAVELENGTH_ROOT;
+
+/// de Broglie wavelength h/p of a particle of momentum `p`.  For fast particles take `p` from
+/// [relativity::momentum][crate::relativity::momentum]
+pub fn de_broglie_wavelength(p: Momentum) -> Length {
+	PLANK_CONSTANT/p
+}
+
+/// Energy hc/λ of a photon of wavelength `wavelength`
+pub fn photon_energy(wavelength: Length) -> Energy {
+	PLANK_CONSTANT*SPEED_OF_LIGHT/wavelength
+}
+
+/// Wavelength at which the spectral radiance of a black body at temperature `t` peaks, by Wien's displacement law
+pub fn wien_peak_wavelength(t: Temperature) -> Length {
+	WIEN_DISPLACEMENT_CONSTANT/t
+}
+
+/// Spectral radiance of a black body at temperature `t` and wavelength `wavelength` by Planck's law,
+/// B<sub>λ</sub> = (2hc²/λ⁵)/(e<sup>hc/λkT</sup> - 1) per steradian
+pub fn planck_spectral_radiance(t: Temperature, wavelength: Length) -> SpectralRadiance {
+	let x: Float = (photon_energy(wavelength)/(BOLTZMANN_CONSTANT*t)).into();
+	2.0*PLANK_CONSTANT*SPEED_OF_LIGHT.pow::<2>()/wavelength.pow::<5>()/STERADIAN/x.exp_m1()
+}
@@ -0,0 +1,31 @@
+//! Property testing with quickcheck, enabled with the `quickcheck` feature
+//!
+//! Every [Quantity] implements quickcheck's [Arbitrary], generating finite values in SI base units and shrinking towards zero, so
+//! `quickcheck!` properties can take typed arguments directly.  quickcheck has no strategies to restrict a type to a range, so
+//! [in_range] draws a value between two bounds from a [Gen] for use in an [Arbitrary] impl of a test's own input type.
+
+use ::quickcheck::{Arbitrary,Gen};
+use crate::{AnyQuantity,Float,Quantity};
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Arbitrary for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn arbitrary(g: &mut Gen) -> Self {
+		// quickcheck's floats include NaN and infinities, which most properties of physical formulas do not hold for
+		loop {
+			let value = Float::arbitrary(g);
+			if value.is_finite() {
+				return Quantity::from_si(value);
+			}
+		}
+	}
+
+	fn shrink(&self) -> Box<dyn Iterator<Item=Self>> {
+		Box::new(self.as_si().shrink().filter(|v| v.is_finite()).map(Quantity::from_si))
+	}
+}
+
+/// Quantity from `low` to `high` inclusive drawn from `g`, e.g. `in_range(g, -40.0*CELSIUS, 125.0*CELSIUS)`
+pub fn in_range<Q: AnyQuantity>(g: &mut Gen, low: Q, high: Q) -> Q {
+	let fraction = (u64::arbitrary(g) >> 11) as Float/((1u64 << 53) - 1) as Float;
+	Q::from_si(low.as_si() + fraction.min(1.0)*(high.as_si() - low.as_si()))
+}
@@ -0,0 +1,35 @@
+//! Dose-rate and exposure-time helpers for radiation safety calculations
+//!
+//! Dose rates can be entered and displayed in whichever convention is at hand, e.g. `2.5*USV_PER_HOUR` and
+//! <code>rate.as_unit([MREM_PER_HOUR][crate::units::MREM_PER_HOUR])</code>, since 1 µSv/h = 0.1 mrem/h.
+
+use crate::Float;
+use crate::dimens::*;
+
+/// Dose accumulated over an exposure of duration `time` at a constant dose `rate`
+pub fn accumulated_dose(rate: DoseRate, time: Time) -> Dose {
+	rate*time
+}
+
+/// Time of exposure at a constant dose `rate` before reaching the dose `limit`
+pub fn time_to_limit(limit: Dose, rate: DoseRate) -> Time {
+	limit/rate
+}
+
+/// Dose rate at `distance` from a point source given the dose rate `reference_rate` measured at `reference_distance` (inverse-square law)
+pub fn rate_at_distance(reference_rate: DoseRate, reference_distance: Length, distance: Length) -> DoseRate {
+	let ratio: Float = (reference_distance/distance).into();
+	reference_rate*(ratio*ratio)
+}
+
+/// Distance from a point source at which the dose rate falls to `target_rate`, given `reference_rate` measured at `reference_distance`
+pub fn distance_for_rate(reference_rate: DoseRate, reference_distance: Length, target_rate: DoseRate) -> Length {
+	let ratio: Float = (reference_rate/target_rate).into();
+	reference_distance*ratio.sqrt()
+}
+
+/// Dose rate behind a shield of the given `thickness` of material with half-value layer `half_value_layer`
+pub fn shielded_rate(rate: DoseRate, thickness: Length, half_value_layer: Length) -> DoseRate {
+	let layers: Float = (thickness/half_value_layer).into();
+	rate*Float::exp2(-layers)
+}
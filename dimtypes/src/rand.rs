@@ -0,0 +1,64 @@
+//! Random quantities with rand, enabled with the `rand` feature
+//!
+//! [Uniform] and [Normal] are parameterized by quantities and implement rand's [Distribution] for the same quantity type, so
+//! `rng.sample(Normal::new(20.0*CELSIUS, 0.5*KELVIN)?)` gives typed temperatures for a Monte Carlo simulation.  Both sample in SI base
+//! units, which for offset units such as degrees Celsius means the standard deviation or width is a difference and the mean an absolute
+//! value.  For quick studies without rand, [uncertainty][crate::uncertainty] has its own generator and distributions.
+
+use core::marker::PhantomData;
+use ::rand::Rng;
+use ::rand::distr::Distribution;
+use ::rand::distr::uniform::Error as UniformError;
+use ::rand_distr::NormalError;
+use crate::{AnyQuantity,Float};
+
+/// Uniform distribution of quantities of type `Q` between two bounds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform<Q> {
+	low: Q,
+	high: Q,
+	inner: ::rand::distr::Uniform<Float>,
+}
+
+impl<Q: AnyQuantity> Uniform<Q> {
+	/// Distribution over `low..high`.  Fails unless `low < high` and both are finite
+	pub fn new(low: Q, high: Q) -> Result<Uniform<Q>, UniformError> {
+		Ok(Uniform { low, high, inner: ::rand::distr::Uniform::new(low.as_si(), high.as_si())? })
+	}
+	/// Distribution over `low..=high`.  Fails unless `low <= high` and both are finite
+	pub fn new_inclusive(low: Q, high: Q) -> Result<Uniform<Q>, UniformError> {
+		Ok(Uniform { low, high, inner: ::rand::distr::Uniform::new_inclusive(low.as_si(), high.as_si())? })
+	}
+
+	pub fn low(&self) -> Q { self.low }
+	pub fn high(&self) -> Q { self.high }
+}
+
+impl<Q: AnyQuantity> Distribution<Q> for Uniform<Q> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Q {
+		Q::from_si(self.inner.sample(rng))
+	}
+}
+
+/// Normal distribution of quantities of type `Q` with a given mean and standard deviation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal<Q> {
+	inner: ::rand_distr::Normal<Float>,
+	quantity: PhantomData<Q>,
+}
+
+impl<Q: AnyQuantity> Normal<Q> {
+	/// Fails if `std_dev` is negative or NaN
+	pub fn new(mean: Q, std_dev: Q) -> Result<Normal<Q>, NormalError> {
+		Ok(Normal { inner: ::rand_distr::Normal::new(mean.as_si(), std_dev.as_si())?, quantity: PhantomData })
+	}
+
+	pub fn mean(&self) -> Q { Q::from_si(self.inner.mean()) }
+	pub fn std_dev(&self) -> Q { Q::from_si(self.inner.std_dev()) }
+}
+
+impl<Q: AnyQuantity> Distribution<Q> for Normal<Q> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Q {
+		Q::from_si(self.inner.sample(rng))
+	}
+}
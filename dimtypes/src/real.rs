@@ -0,0 +1,58 @@
+//! Floating point functions for `#![no_std]` builds, where [Float] has no inherent `sqrt`, `sin`, etc.
+//!
+//! Modules built without the `std` feature import [Real] under `#[cfg(not(feature = "std"))]`, so the same calls such as
+//! `x.powi(2)` or `Float::sin(x)` resolve to the inherent methods with `std` and to libm without it.
+
+use crate::Float;
+
+type Libm = libm::Libm<Float>;
+
+/// The subset of the inherent [Float] methods used by the `no_std` modules, computed by libm
+#[cfg_attr(feature = "stable", allow(dead_code))]
+pub(crate) trait Real {
+	fn powi(self, n: i32) -> Self;
+	fn powf(self, n: Self) -> Self;
+	fn exp(self) -> Self;
+	fn exp2(self) -> Self;
+	fn ln(self) -> Self;
+	fn log2(self) -> Self;
+	fn log10(self) -> Self;
+	fn round(self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn tan(self) -> Self;
+	fn asin(self) -> Self;
+	fn acos(self) -> Self;
+	fn atan(self) -> Self;
+	fn atan2(self, other: Self) -> Self;
+	fn sinh(self) -> Self;
+	fn cosh(self) -> Self;
+	fn tanh(self) -> Self;
+	fn asinh(self) -> Self;
+	fn acosh(self) -> Self;
+	fn atanh(self) -> Self;
+}
+
+impl Real for Float {
+	fn powi(self, n: i32) -> Float { Libm::pow(self, n as Float) }
+	fn powf(self, n: Float) -> Float { Libm::pow(self, n) }
+	fn exp(self) -> Float { Libm::exp(self) }
+	fn exp2(self) -> Float { Libm::exp2(self) }
+	fn ln(self) -> Float { Libm::log(self) }
+	fn log2(self) -> Float { Libm::log2(self) }
+	fn log10(self) -> Float { Libm::log10(self) }
+	fn round(self) -> Float { Libm::round(self) }
+	fn sin(self) -> Float { Libm::sin(self) }
+	fn cos(self) -> Float { Libm::cos(self) }
+	fn tan(self) -> Float { Libm::tan(self) }
+	fn asin(self) -> Float { Libm::asin(self) }
+	fn acos(self) -> Float { Libm::acos(self) }
+	fn atan(self) -> Float { Libm::atan(self) }
+	fn atan2(self, other: Float) -> Float { Libm::atan2(self, other) }
+	fn sinh(self) -> Float { Libm::sinh(self) }
+	fn cosh(self) -> Float { Libm::cosh(self) }
+	fn tanh(self) -> Float { Libm::tanh(self) }
+	fn asinh(self) -> Float { Libm::asinh(self) }
+	fn acosh(self) -> Float { Libm::acosh(self) }
+	fn atanh(self) -> Float { Libm::atanh(self) }
+}
@@ -0,0 +1,84 @@
+//! Special relativistic kinematics of massive particles
+//!
+//! Speeds are given as velocities and compared to [SPEED_OF_LIGHT] internally.  Kinetic energy and related quantities are computed in
+//! forms which avoid cancellation, so they approach their Newtonian values accurately for slow particles.  Near the speed of light a
+//! velocity carries little information about γ (a 7 TeV proton has 1 - β ≈ 9e-9, leaving about 8 significant digits), so for
+//! ultra-relativistic particles work from energies, momenta or rapidities where possible.  Rapidity is additive under boosts along
+//! one axis, which makes it the natural variable for composing velocities.
+
+use crate::Float;
+use crate::consts::SPEED_OF_LIGHT;
+use crate::dimens::*;
+
+/// Speed as a fraction of the speed of light, β = v/c
+pub fn beta(v: Velocity) -> Unitless {
+	v/SPEED_OF_LIGHT
+}
+
+/// Lorentz factor γ = 1/√(1 - β²) of a particle moving at `v`.  Infinite at the speed of light, and NaN above it where
+/// no real Lorentz factor exists
+pub fn gamma(v: Velocity) -> Unitless {
+	let b: Float = beta(v).into();
+	Unitless::from(1.0/((1.0 - b)*(1.0 + b)).sqrt())
+}
+
+/// Rest energy mc² of a particle of mass `m`
+pub fn rest_energy(m: Mass) -> Energy {
+	m*SPEED_OF_LIGHT*SPEED_OF_LIGHT
+}
+
+/// Total energy γmc² of a particle of mass `m` moving at `v`
+pub fn total_energy(m: Mass, v: Velocity) -> Energy {
+	rest_energy(m)*gamma(v)
+}
+
+/// Kinetic energy (γ - 1)mc² of a particle of mass `m` moving at `v`, which approaches ½mv² at low speed
+pub fn kinetic_energy(m: Mass, v: Velocity) -> Energy {
+	let b: Float = beta(v).into();
+	let g: Float = gamma(v).into();
+	rest_energy(m)*(b*b*g*g/(g + 1.0))
+}
+
+/// Momentum γmv of a particle of mass `m` moving at `v`
+pub fn momentum(m: Mass, v: Velocity) -> Momentum {
+	m*v*gamma(v)
+}
+
+/// Speed of a particle of mass `m` with kinetic energy `kinetic`, the inverse of [kinetic_energy]
+pub fn speed_from_kinetic_energy(m: Mass, kinetic: Energy) -> Velocity {
+	let k: Float = (kinetic/rest_energy(m)).into();
+	// β = √(γ² - 1)/γ with γ = 1 + k, expanded so slow particles do not lose precision
+	SPEED_OF_LIGHT*((k*(k + 2.0)).sqrt()/(1.0 + k))
+}
+
+/// Invariant (rest) mass of a particle or system with total energy `energy` and momentum `momentum`, from E² = (pc)² + (mc²)²
+pub fn invariant_mass(energy: Energy, momentum: Momentum) -> Mass {
+	// E² - (pc)² = (E - pc)(E + pc), factored so a nearly massless particle does not lose precision
+	let x: Float = ((energy - momentum*SPEED_OF_LIGHT)/energy).into();
+	energy*(x*(2.0 - x)).sqrt()/(SPEED_OF_LIGHT*SPEED_OF_LIGHT)
+}
+
+/// Rapidity artanh(β) of a particle moving at `v`
+pub fn rapidity(v: Velocity) -> Unitless {
+	Unitless::from(Float::atanh(beta(v).into()))
+}
+
+/// Velocity of a particle of rapidity `w`, the inverse of [rapidity]
+pub fn velocity_from_rapidity(w: Unitless) -> Velocity {
+	SPEED_OF_LIGHT*Float::tanh(w.into())
+}
+
+/// Relativistic sum (u + v)/(1 + uv/c²) of collinear velocities, e.g. of a particle moving at `v` in a frame moving at `u`
+pub fn add_velocities(u: Velocity, v: Velocity) -> Velocity {
+	velocity_from_rapidity(rapidity(u) + rapidity(v))
+}
+
+/// Time elapsed for a stationary observer while a clock moving at `v` measures `proper_time`
+pub fn dilated_time(proper_time: Time, v: Velocity) -> Time {
+	proper_time*gamma(v)
+}
+
+/// Length measured by a stationary observer of an object of `proper_length` moving at `v` along its length
+pub fn contracted_length(proper_length: Length, v: Velocity) -> Length {
+	proper_length/gamma(v)
+}
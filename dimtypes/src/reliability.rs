@@ -0,0 +1,102 @@
+//! Reliability and availability from failure rates, MTBF and MTTR
+//!
+//! Failure rates are [FailureRate] quantities, failures per unit time, entered in [FIT][crate::units::FIT] for electronic components
+//! or as the reciprocal of a mean time between failures.  Failures are a [Count], so a failure rate cannot be mixed up with a
+//! [Frequency].  Rates are taken as constant (the flat bottom of the bathtub curve), so the reliability over a mission is
+//! exponential and the failure rates of components in series add.  [series] and [parallel] combine the availabilities or
+//! reliabilities of the blocks of a reliability block diagram.
+
+use crate::Float;
+use crate::dimens::*;
+use crate::math::{exp,log10};
+use crate::units::COUNT;
+
+/// Failure rate of a unit with mean time between failures `mtbf`, λ = 1/MTBF
+pub fn failure_rate(mtbf: Time) -> FailureRate {
+	COUNT/mtbf
+}
+
+/// Mean time between failures of a unit with failure rate `rate`
+pub fn mtbf(rate: FailureRate) -> Time {
+	COUNT/rate
+}
+
+/// Probability of a unit with failure rate `rate` surviving `mission` without failure, R = e<sup>-λt</sup>
+pub fn reliability(rate: FailureRate, mission: Time) -> Unitless {
+	exp(-(rate*mission/COUNT))
+}
+
+/// Failure rate of components in series, any one of which failing fails the system
+pub fn series_failure_rate(rates: &[FailureRate]) -> FailureRate {
+	rates.iter().fold(FailureRate::from_si(0.0), |total, &rate| total + rate)
+}
+
+/// Availability of blocks in series, all of which must be up.  Also combines reliabilities over the same mission
+pub fn series(availabilities: &[Unitless]) -> Unitless {
+	availabilities.iter().fold(Unitless::from(1.0), |total, &a| total*a)
+}
+
+/// Availability of redundant blocks in parallel, any one of which keeps the system up, 1 - Π(1 - A).  Also combines reliabilities over
+/// the same mission
+pub fn parallel(availabilities: &[Unitless]) -> Unitless {
+	let down = availabilities.iter().fold(1.0, |total, &a| total*(1.0 - Float::from(a)));
+	Unitless::from(1.0 - down)
+}
+
+/// Availability of `n` identical blocks of availability `availability`, of which at least `k` must be up
+pub fn k_of_n(k: usize, n: usize, availability: Unitless) -> Unitless {
+	if k > n {
+		return Unitless::from(0.0);
+	}
+	let a: Float = availability.into();
+	let mut total = 0.0;
+	// Binomial coefficient C(n, i), updated incrementally from C(n, k)
+	let mut ways = (0..k).fold(1.0, |c, j| c*(n - j) as Float/(j + 1) as Float);
+	for i in k..=n {
+		total += ways*a.powi(i as i32)*(1.0 - a).powi((n - i) as i32);
+		ways *= (n - i) as Float/(i + 1) as Float;
+	}
+	Unitless::from(total)
+}
+
+/// Number of nines of an availability, e.g. 3 for 99.9%
+pub fn nines(availability: Unitless) -> Float {
+	-Float::from(log10(1.0 - availability))
+}
+
+/// Repairable unit, described by its mean time between failures and mean time to repair
+#[derive(Clone, Copy, Debug)]
+pub struct Repairable {
+	pub mtbf: Time,
+	pub mttr: Time,
+}
+
+impl Repairable {
+	pub fn new(mtbf: Time, mttr: Time) -> Repairable {
+		Repairable { mtbf, mttr }
+	}
+
+	/// Unit failing at `rate` and taking `mttr` on average to repair
+	pub fn from_failure_rate(rate: FailureRate, mttr: Time) -> Repairable {
+		Repairable { mtbf: mtbf(rate), mttr }
+	}
+
+	pub fn failure_rate(&self) -> FailureRate {
+		failure_rate(self.mtbf)
+	}
+
+	/// Repairs completed per unit of time spent under repair, μ = 1/MTTR
+	pub fn repair_rate(&self) -> CountRate {
+		COUNT/self.mttr
+	}
+
+	/// Steady-state (inherent) availability, MTBF/(MTBF + MTTR)
+	pub fn availability(&self) -> Unitless {
+		self.mtbf/(self.mtbf + self.mttr)
+	}
+
+	/// Expected time down over `period`, e.g. minutes per year
+	pub fn downtime(&self, period: Time) -> Time {
+		period*(1.0 - self.availability())
+	}
+}
@@ -0,0 +1,85 @@
+//! Radiated field and transmission line helpers for link budgets and EMC calculations
+//!
+//! Gains and losses are taken as [Unitless] power ratios, so they can be written in decibels with the [DECIBEL][crate::units::DECIBEL] or
+//! [DBI][crate::units::DBI] log units (e.g. `6.0*DBI`).  Losses are ratios above one, so a return loss of `14.0*DECIBEL` corresponds to a
+//! reflection coefficient of about 0.2.  Field strengths and power flux densities read out in [VOLT_PER_METER][crate::units::VOLT_PER_METER],
+//! [DBUV_PER_M][crate::units::DBUV_PER_M], [WATT_PER_SQUARE_METER][crate::units::WATT_PER_SQUARE_METER] or
+//! [MILLIWATT_PER_SQUARE_CENTIMETER][crate::units::MILLIWATT_PER_SQUARE_CENTIMETER].
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::OHM;
+
+/// Gain of a lossless half-wave dipole relative to an isotropic radiator (2.15 dBi), the reference antenna for ERP
+pub const HALF_WAVE_DIPOLE_GAIN: Unitless = Unitless::from(1.640922);
+
+/// Effective isotropic radiated power of a transmitter with output `tx_power` into an antenna with gain `antenna_gain` (vs isotropic)
+/// after feedline/connector `losses`
+pub fn eirp(tx_power: Power, antenna_gain: Unitless, losses: Unitless) -> Power {
+	tx_power*antenna_gain/losses
+}
+
+/// Effective radiated power (relative to a half-wave dipole) of a transmitter with output `tx_power` into an antenna with gain
+/// `antenna_gain` (vs isotropic) after feedline/connector `losses`
+pub fn erp(tx_power: Power, antenna_gain: Unitless, losses: Unitless) -> Power {
+	eirp_to_erp(eirp(tx_power, antenna_gain, losses))
+}
+
+/// Convert an EIRP to the equivalent ERP (2.15 dB lower)
+pub fn eirp_to_erp(eirp: Power) -> Power { eirp/HALF_WAVE_DIPOLE_GAIN }
+
+/// Convert an ERP to the equivalent EIRP (2.15 dB higher)
+pub fn erp_to_eirp(erp: Power) -> Power { erp*HALF_WAVE_DIPOLE_GAIN }
+
+/// Far-field power flux density at `distance` from an isotropic source radiating `eirp`
+pub fn power_flux_density(eirp: Power, distance: Length) -> PowerFlux {
+	eirp/(4.0*crate::float_consts::PI*distance*distance)
+}
+
+/// Far-field RMS electric field strength at `distance` from an isotropic source radiating `eirp`, using the free-space relation
+/// E = √(30 Ω · EIRP) / d
+pub fn field_strength(eirp: Power, distance: Length) -> ElectricFieldStrength {
+	(30.0*OHM*eirp).root::<2>()/distance
+}
+
+/// EIRP required to produce the RMS field strength `field` at `distance` in free space.  Inverse of [field_strength]
+pub fn eirp_for_field_strength(field: ElectricFieldStrength, distance: Length) -> Power {
+	(field*distance).pow::<2>()/(30.0*OHM)
+}
+
+/// Magnitude of the reflection coefficient |Γ| = |Z<sub>L</sub> - Z<sub>0</sub>| / (Z<sub>L</sub> + Z<sub>0</sub>) of a resistive `load`
+/// on a line of characteristic impedance `z0`
+pub fn reflection_coefficient(load: Resistance, z0: Resistance) -> Unitless {
+	Unitless::from(Float::from((load - z0)/(load + z0)).abs())
+}
+
+/// Voltage standing wave ratio (1 + |Γ|)/(1 - |Γ|) for a reflection coefficient `gamma`
+pub fn vswr(gamma: Unitless) -> Unitless {
+	let gamma = Unitless::from(Float::from(gamma).abs());
+	(1.0 + gamma)/(1.0 - gamma)
+}
+
+/// Magnitude of the reflection coefficient for a standing wave ratio `vswr`
+pub fn reflection_from_vswr(vswr: Unitless) -> Unitless {
+	(vswr - 1.0)/(vswr + 1.0)
+}
+
+/// Return loss 1/|Γ|² for a reflection coefficient `gamma`, as a power ratio
+pub fn return_loss(gamma: Unitless) -> Unitless {
+	1.0/(gamma*gamma)
+}
+
+/// Magnitude of the reflection coefficient for a `return_loss` power ratio
+pub fn reflection_from_return_loss(return_loss: Unitless) -> Unitless {
+	1.0/return_loss.root::<2>()
+}
+
+/// Mismatch loss 1/(1 - |Γ|²), the reduction in delivered power due to reflection, for a reflection coefficient `gamma`
+pub fn mismatch_loss(gamma: Unitless) -> Unitless {
+	1.0/(1.0 - gamma*gamma)
+}
+
+/// Power reflected back towards the source when `incident` power meets a reflection coefficient `gamma`
+pub fn reflected_power(incident: Power, gamma: Unitless) -> Power {
+	incident*gamma*gamma
+}
@@ -0,0 +1,81 @@
+//! Dynamics of rigid bodies rotating about a fixed axis
+//!
+//! Angles are a dimension in this crate, so rotational quantities carry powers of [RADIAN]: [Torque] is per radian, and
+//! [MomentOfInertia], [RotationalStiffness] and [RotationalDamping] per radian squared.  With these the rotational forms of Newton's laws
+//! (τ = Iα, L = Iω, E = ½Iω²) check like the linear ones.  Moments of inertia of common shapes are in [geometry][crate::geometry].
+
+use crate::dimens::*;
+use crate::units::RADIAN;
+
+/// Torque τ = Iα needed to give a body of moment of inertia `inertia` angular acceleration `acceleration`
+pub fn torque(inertia: MomentOfInertia, acceleration: AngularAcceleration) -> Torque {
+	inertia*acceleration
+}
+
+/// Angular acceleration α = τ/I of a body of moment of inertia `inertia` under `torque`
+pub fn angular_acceleration(torque: Torque, inertia: MomentOfInertia) -> AngularAcceleration {
+	torque/inertia
+}
+
+/// Angular momentum L = Iω of a body of moment of inertia `inertia` spinning at `speed`
+pub fn angular_momentum(inertia: MomentOfInertia, speed: AngularVelocity) -> AngularMomentum {
+	inertia*speed
+}
+
+/// Kinetic energy ½Iω² of a body of moment of inertia `inertia` spinning at `speed`
+pub fn kinetic_energy(inertia: MomentOfInertia, speed: AngularVelocity) -> Energy {
+	0.5*inertia*speed*speed
+}
+
+/// Time for a constant `torque` to change the speed of a body of moment of inertia `inertia` by `speed_change`
+pub fn spin_up_time(inertia: MomentOfInertia, speed_change: AngularVelocity, torque: Torque) -> Time {
+	angular_momentum(inertia, speed_change)/torque
+}
+
+/// Radius at which the whole `mass` of a body of moment of inertia `inertia` would have the same moment of inertia
+pub fn radius_of_gyration(inertia: MomentOfInertia, mass: Mass) -> Length {
+	(inertia*RADIAN*RADIAN/mass).root::<2>()
+}
+
+/// Torsional stiffness GJ/L of a shaft of `length` with polar second moment of area `polar_second_moment` (see
+/// [polar_second_moment][crate::structures::polar_second_moment]) in a material of shear modulus `shear_modulus`
+pub fn shaft_stiffness(shear_modulus: ShearModulus, polar_second_moment: SecondMomentOfArea, length: Length) -> RotationalStiffness {
+	shear_modulus*polar_second_moment/length/RADIAN/RADIAN
+}
+
+/// Restoring torque of a torsional spring of stiffness `stiffness` twisted through `angle`
+pub fn spring_torque(stiffness: RotationalStiffness, angle: Angle) -> Torque {
+	stiffness*angle
+}
+
+/// Undamped natural angular frequency ω<sub>n</sub> = √(κ/I) of a body of moment of inertia `inertia` on a torsional spring of
+/// stiffness `stiffness`, as an [AngularVelocity] like [natural_frequency][crate::mechanics::natural_frequency]
+pub fn natural_frequency(stiffness: RotationalStiffness, inertia: MomentOfInertia) -> AngularVelocity {
+	(stiffness/inertia).root::<2>()*RADIAN
+}
+
+/// Damping ratio ζ = c/(2√(κI)) of a torsional oscillator with damping coefficient `damping`
+pub fn damping_ratio(damping: RotationalDamping, stiffness: RotationalStiffness, inertia: MomentOfInertia) -> Unitless {
+	let critical: RotationalDamping = 2.0*(stiffness*inertia).root::<2>();
+	damping/critical
+}
+
+/// Speed of a body of moment of inertia `inertia` after a spring of stiffness `stiffness`, wound up by `angle`, is fully released
+pub fn release_speed(stiffness: RotationalStiffness, angle: Angle, inertia: MomentOfInertia) -> AngularVelocity {
+	angle*natural_frequency(stiffness, inertia)/RADIAN
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn natural_frequency_is_angular() {
+		let (stiffness, inertia) = (RotationalStiffness::from_si(4.0), MomentOfInertia::from_si(1.0));
+		let omega = natural_frequency(stiffness, inertia);
+		assert!((omega.as_unit(RADIAN/SECOND) - 2.0).abs() < 1e-12);
+		assert!((omega.as_unit(TURN/SECOND) - 1.0/crate::float_consts::PI).abs() < 1e-12);
+		assert!((release_speed(stiffness, 0.5*RADIAN, inertia).as_unit(RADIAN/SECOND) - 1.0).abs() < 1e-12);
+	}
+}
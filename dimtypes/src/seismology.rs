@@ -0,0 +1,39 @@
+//! Earthquake magnitude scales
+//!
+//! Magnitudes are logarithmic scales over physical quantities, so they are provided as [LogUnit]s and can be used like any other unit
+//! (e.g. `6.5*MOMENT_MAGNITUDE` or `m0.as_unit(MOMENT_MAGNITUDE)`).
+
+use crate::Float;
+use crate::LogUnit;
+use crate::dimens::*;
+
+/// Moment magnitude scale M<sub>w</sub> = ⅔(log<sub>10</sub> M<sub>0</sub> − 9.1) over seismic moment M<sub>0</sub> in N·m (IASPEI standard form)
+pub const MOMENT_MAGNITUDE: LogUnit<Energy> = LogUnit::base10(2.0/3.0, Energy::from_si(1.2589254117941673e9));
+
+/// Gutenberg–Richter energy magnitude relation log<sub>10</sub> E = 1.5 M + 4.8 over radiated seismic energy E in joules
+pub const ENERGY_MAGNITUDE: LogUnit<Energy> = LogUnit::base10(2.0/3.0, Energy::from_si(6.309573444801933e4));
+
+/// Moment magnitude of an earthquake with seismic moment `m0`
+pub fn moment_magnitude(m0: Energy) -> Unitless {
+	Unitless::from(m0.as_unit(MOMENT_MAGNITUDE))
+}
+
+/// Seismic moment of an earthquake with moment magnitude `mw`
+pub fn seismic_moment(mw: Unitless) -> Energy {
+	Float::from(mw)*MOMENT_MAGNITUDE
+}
+
+/// Radiated seismic energy of an earthquake of magnitude `m` from the Gutenberg–Richter relation
+pub fn radiated_energy(m: Unitless) -> Energy {
+	Float::from(m)*ENERGY_MAGNITUDE
+}
+
+/// Magnitude of an earthquake radiating seismic `energy` from the Gutenberg–Richter relation
+pub fn energy_magnitude(energy: Energy) -> Unitless {
+	Unitless::from(energy.as_unit(ENERGY_MAGNITUDE))
+}
+
+/// Ratio of radiated energy between two earthquakes of magnitudes `m1` and `m2` (about 31.6× per magnitude unit)
+pub fn energy_ratio(m1: Unitless, m2: Unitless) -> Unitless {
+	radiated_energy(m1)/radiated_energy(m2)
+}
@@ -0,0 +1,110 @@
+//! Scaling of analog transmitter signals such as 4–20 mA loops and 0–5 V outputs
+//!
+//! A [LinearSensor] maps its output range linearly onto a measuring range given by a zero (lower range value) and a span, as
+//! configured on the transmitter, e.g. a pressure transmitter ranged 0 to 10 bar over 4–20 mA.  Signals outside the output range
+//! are reported as [RangeError]s, since in a current loop they indicate a saturated or failed transmitter rather than a reading.
+//! [LinearSensor::limits] widens the accepted band, e.g. to the NAMUR NE 43 limits of 3.8 mA and 20.5 mA within which a transmitter
+//! may still be measuring.  For sensors read as raw ADC codes see [AdcSensor][crate::embedded::AdcSensor].
+
+use std::fmt;
+use crate::{AnyQuantity,Float};
+use crate::dimens::*;
+use crate::units::{AMPERE,MILLI};
+
+/// Signal outside the range a [LinearSensor] accepts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeError {
+	/// Below the low limit, e.g. a broken wire or unpowered transmitter in a current loop
+	Underrange,
+	/// Above the high limit, e.g. a short circuit or a transmitter signalling a fault
+	Overrange,
+}
+
+impl fmt::Display for RangeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			RangeError::Underrange => write!(f, "signal is below the sensor's output range"),
+			RangeError::Overrange => write!(f, "signal is above the sensor's output range"),
+		}
+	}
+}
+
+impl std::error::Error for RangeError {}
+
+/// Sensor whose output signal of type `S` is linear in a quantity of type `Q` over its measuring range
+#[derive(Clone, Copy, Debug)]
+pub struct LinearSensor<Q, S = Voltage> {
+	/// Quantity at the low end of the output range
+	zero: Q,
+	/// Change in the quantity over the output range
+	span: Q,
+	output: (S, S),
+	limits: (S, S),
+}
+
+impl<Q: AnyQuantity, S: AnyQuantity> LinearSensor<Q, S> {
+	/// Sensor reading `zero` at the low end of `output` and `zero + span` at its high end.  A negative span gives a reverse acting
+	/// sensor
+	pub fn new(zero: Q, span: Q, output: (S, S)) -> LinearSensor<Q, S> {
+		LinearSensor { zero, span, output, limits: output }
+	}
+
+	/// Accept signals between `low` and `high` rather than only those within the output range, extrapolating the measurement
+	pub fn limits(self, low: S, high: S) -> LinearSensor<Q, S> {
+		LinearSensor { limits: (low, high), ..self }
+	}
+
+	/// Fraction of the output range at which `signal` lies, failing if it is outside the limits
+	fn fraction(&self, signal: S) -> Result<Float, RangeError> {
+		let (low, high) = (self.output.0.as_si(), self.output.1.as_si());
+		let s = signal.as_si();
+		if s < self.limits.0.as_si() {
+			return Err(RangeError::Underrange);
+		}
+		if s > self.limits.1.as_si() {
+			return Err(RangeError::Overrange);
+		}
+		Ok((s - low)/(high - low))
+	}
+
+	/// Measured quantity at `signal`
+	pub fn read(&self, signal: S) -> Result<Q, RangeError> {
+		Ok(Q::from_si(self.zero.as_si() + self.span.as_si()*self.fraction(signal)?))
+	}
+
+	/// Measured quantity at `signal`, clamped to the measuring range for signals outside the limits.  For displays and control
+	/// loops which must keep running on a faulty sensor
+	pub fn read_clamped(&self, signal: S) -> Q {
+		let fraction = match self.fraction(signal) {
+			Ok(fraction) => fraction,
+			Err(RangeError::Underrange) => 0.0,
+			Err(RangeError::Overrange) => 1.0,
+		};
+		Q::from_si(self.zero.as_si() + self.span.as_si()*fraction)
+	}
+
+	/// Signal output at `value`, e.g. for an analog output module or simulating the transmitter, failing if it falls outside the limits
+	pub fn signal(&self, value: Q) -> Result<S, RangeError> {
+		let (low, high) = (self.output.0.as_si(), self.output.1.as_si());
+		let s = low + (high - low)*(value.as_si() - self.zero.as_si())/self.span.as_si();
+		if s < self.limits.0.as_si() {
+			Err(RangeError::Underrange)
+		} else if s > self.limits.1.as_si() {
+			Err(RangeError::Overrange)
+		} else {
+			Ok(S::from_si(s))
+		}
+	}
+
+	/// Quantity at the low and high ends of the output range
+	pub fn range(&self) -> (Q, Q) {
+		(self.zero, Q::from_si(self.zero.as_si() + self.span.as_si()))
+	}
+}
+
+impl<Q: AnyQuantity> LinearSensor<Q, Current> {
+	/// Transmitter on a 4–20 mA current loop
+	pub fn current_loop(zero: Q, span: Q) -> LinearSensor<Q, Current> {
+		LinearSensor::new(zero, span, (4.0*MILLI*AMPERE, 20.0*MILLI*AMPERE))
+	}
+}
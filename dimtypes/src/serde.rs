@@ -0,0 +1,101 @@
+//! Serialization of quantities with serde, enabled with the `serde` feature
+//!
+//! Human-readable formats such as JSON, TOML and YAML represent a [Quantity] as a string of its value and SI base units, e.g.
+//! `"3.5 m s^-1"`, or a plain number if dimensionless.  On deserialization the string may use any unit [parse_value] understands,
+//! e.g. `"12.5 km/h"` or `"20 degC"`, and its dimension is checked against the quantity's, so a configuration file giving a speed in
+//! meters fails to load rather than being misread.  A bare number is also accepted and taken to be in SI base units.  Binary formats
+//! use the compact form, the value in SI base units alone.  To use the compact form in a human-readable format, annotate a field with
+//! `#[serde(with = "dimtypes::serde::si")]`.
+
+use std::fmt;
+use ::serde::{Deserialize,Deserializer,Serialize,Serializer};
+use ::serde::de::{self,Visitor};
+use crate::{AnyQuantity,Dimension,Float,Quantity};
+use crate::control::Gain;
+use crate::parse::{parse_value,DimensionMismatch};
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Serialize for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() && !Self::DIMENSION.is_dimensionless() {
+			serializer.collect_str(self)
+		} else {
+			self.as_si().serialize(serializer)
+		}
+	}
+}
+
+impl<'de, const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Deserialize<'de> for Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(SiVisitor(Self::DIMENSION)).map(Quantity::from_si)
+		} else {
+			Float::deserialize(deserializer).map(Quantity::from_si)
+		}
+	}
+}
+
+/// A [Gain] is written like a quantity of the dimension of its output per input, e.g. `"2.5e-3 kg m^2 s^-3 A^-1 K^-1"` for a volt
+/// per kelvin, and read back from any unit of that dimension such as `"2.5 mV/K"`
+impl<P: AnyQuantity, O: AnyQuantity> Serialize for Gain<P, O> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let dimension = O::DIMENSION/P::DIMENSION;
+		if serializer.is_human_readable() && !dimension.is_dimensionless() {
+			serializer.collect_str(&format_args!("{} {}", self.as_si(), dimension))
+		} else {
+			self.as_si().serialize(serializer)
+		}
+	}
+}
+
+impl<'de, P: AnyQuantity, O: AnyQuantity> Deserialize<'de> for Gain<P, O> {
+	fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(SiVisitor(O::DIMENSION/P::DIMENSION)).map(Gain::from_si)
+		} else {
+			Float::deserialize(deserializer).map(Gain::from_si)
+		}
+	}
+}
+
+/// Accepts a number in SI base units or a string of a number and unit of the given dimension, giving the value in SI base units
+struct SiVisitor(Dimension);
+
+impl Visitor<'_> for SiVisitor {
+	type Value = Float;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "a number in SI base units or a string of a number and unit of dimension [{}]", self.0)
+	}
+
+	fn visit_f64<E: de::Error>(self, value: f64) -> Result<Float, E> {
+		Ok(value as Float)
+	}
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Float, E> {
+		Ok(value as Float)
+	}
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Float, E> {
+		Ok(value as Float)
+	}
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<Float, E> {
+		let (number, unit) = parse_value(value).map_err(E::custom)?;
+		if unit.dimension() != self.0 {
+			return Err(E::custom(DimensionMismatch { expected: self.0, found: unit.dimension() }));
+		}
+		Ok(unit.to_si(number))
+	}
+}
+
+/// Compact representation as the value in SI base units in every format, for use with `#[serde(with = "dimtypes::serde::si")]`
+pub mod si {
+	use super::*;
+
+	pub fn serialize<Q: AnyQuantity, S: Serializer>(value: &Q, serializer: S) -> Result<S::Ok, S::Error> {
+		value.as_si().serialize(serializer)
+	}
+
+	pub fn deserialize<'de, Q: AnyQuantity, De: Deserializer<'de>>(deserializer: De) -> Result<Q, De::Error> {
+		Float::deserialize(deserializer).map(Q::from_si)
+	}
+}
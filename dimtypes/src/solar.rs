@@ -0,0 +1,48 @@
+//! Photovoltaic and solar resource helpers
+//!
+//! Solar resource data is quoted interchangeably as average irradiance (W/m²), daily insolation (kWh/m²/day) and peak sun hours.
+//! All three are the same physical information, so these helpers just move between the conventions using typed quantities.
+
+use crate::dimens::*;
+use crate::units::{DAY,METER,WATT};
+
+/// Standard test condition (STC) irradiance of 1000 W/m² used to rate photovoltaic modules
+pub const STC_IRRADIANCE: Irradiance = 1000.0*WATT/METER/METER;
+
+/// Equivalent number of hours at [STC_IRRADIANCE] delivering the given `insolation`
+pub fn peak_sun_hours(insolation: Insolation) -> Time {
+	insolation/STC_IRRADIANCE
+}
+
+/// Insolation delivered by `hours` of sun at [STC_IRRADIANCE]
+pub fn insolation_of_peak_sun_hours(hours: Time) -> Insolation {
+	hours*STC_IRRADIANCE
+}
+
+/// Insolation received in one day at the given day-averaged irradiance (e.g. in [KWH_PER_M2_PER_DAY][crate::units::KWH_PER_M2_PER_DAY])
+pub fn daily_insolation(average_irradiance: Irradiance) -> Insolation {
+	average_irradiance*DAY
+}
+
+/// Day-averaged irradiance corresponding to a daily `insolation`
+pub fn average_irradiance(daily_insolation: Insolation) -> Irradiance {
+	daily_insolation/DAY
+}
+
+/// Energy produced by an array with STC nameplate rating `rated_power` receiving `insolation` on the array plane,
+/// after a lumped `derate` factor covering temperature, soiling, wiring and inverter losses (typically 0.75–0.85)
+pub fn array_energy(rated_power: Power, insolation: Insolation, derate: Unitless) -> Energy {
+	rated_power*peak_sun_hours(insolation)*derate
+}
+
+/// Energy produced by an array of collecting `area` and module `efficiency` receiving `insolation` on the array plane,
+/// after a lumped `derate` factor
+pub fn array_energy_from_area(area: Area, efficiency: Unitless, insolation: Insolation, derate: Unitless) -> Energy {
+	area*insolation*efficiency*derate
+}
+
+/// STC nameplate rating needed to produce `energy` from `insolation` on the array plane after a lumped `derate` factor.
+/// Inverse of [array_energy]
+pub fn required_array_power(energy: Energy, insolation: Insolation, derate: Unitless) -> Power {
+	energy/peak_sun_hours(insolation)/derate
+}
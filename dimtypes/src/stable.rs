@@ -0,0 +1,368 @@
+//! Quantities on stable Rust, enabled with the `stable` feature
+//!
+//! The main `Quantity` computes the dimensions of products and quotients from its const generics, which needs nightly
+//! `generic_const_exprs`.  This backend instead encodes the power of each base dimension as a [typenum] integer in a type-level
+//! array, which stable Rust can add and subtract through trait impls.  Arithmetic is dimension checked at compile time just the same,
+//! but the feature set is reduced:
+//!
+//! * Powers are integers, so [Quantity::root] only compiles where every power divides evenly
+//! * Units and constants are plain quantities, without the symbols, offset units (°C) and logarithmic units (dB) of the main backend
+//! * [dimens] covers the common mechanical, electrical and thermal quantities
+//! * The unit parser, the `qty!` and `check_dims!` macros and the domain modules are not available
+//!
+//! With the `stable` feature this module takes the place of the main backend at the crate root, so code written against
+//! [Quantity], [dimens], [units] and [consts] with `KILO*METER`-style arithmetic builds on either.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add,Sub,Mul,Div,Neg,AddAssign,SubAssign,MulAssign,DivAssign};
+use typenum::{ATerm,Diff,Integer,Negate,PartialDiv,PartialQuot,Prod,Sum,TArr,Z0};
+use crate::Float;
+#[cfg(not(feature = "std"))]
+use crate::real::Real;
+
+/// Re-exported for the type-level integers taken by [Quantity::pow] and [Quantity::root], e.g. `typenum::P2`
+pub use typenum;
+
+/// Type-level array of the powers of time, length, mass, current, temperature, amount of substance, luminous intensity, angle and
+/// count, in the order of the const generics of the main backend
+pub type Dim<T, L, M, I, TEMP, MOL, LUM, ANG, CNT> =
+	TArr<T, TArr<L, TArr<M, TArr<I, TArr<TEMP, TArr<MOL, TArr<LUM, TArr<ANG, TArr<CNT, ATerm>>>>>>>>>;
+
+/// Dimension of a [Quantity] as runtime values, for formatting
+pub trait Dimension {
+	/// Power of each base dimension, in the order of [Dim]
+	const POWERS: [isize; 9];
+}
+
+impl<T: Integer, L: Integer, M: Integer, I: Integer, TEMP: Integer, MOL: Integer, LUM: Integer, ANG: Integer, CNT: Integer>
+Dimension for Dim<T,L,M,I,TEMP,MOL,LUM,ANG,CNT> {
+	const POWERS: [isize; 9] = [T::ISIZE, L::ISIZE, M::ISIZE, I::ISIZE, TEMP::ISIZE, MOL::ISIZE, LUM::ISIZE, ANG::ISIZE, CNT::ISIZE];
+}
+
+/// Physical quantity of dimension `D`, a [Dim] of [typenum] integers, stored as its value in SI base units
+pub struct Quantity<D> {
+	value_si: Float,
+	dimension: PhantomData<D>,
+}
+
+impl<D> Quantity<D> {
+	/// Create a quantity from its numerical value in SI base units
+	pub const fn from_si(value_si: Float) -> Quantity<D> {
+		Quantity { value_si, dimension: PhantomData }
+	}
+
+	/// Numerical value of this quantity in SI base units
+	pub const fn as_si(&self) -> Float {
+		self.value_si
+	}
+
+	/// Numerical value of this quantity in `unit`, e.g. `d.as_unit(MILE)`
+	pub fn as_unit(&self, unit: Quantity<D>) -> Float {
+		self.value_si/unit.value_si
+	}
+
+	/// Raise to the integer power `P`, e.g. `x.pow::<P2>()`
+	pub fn pow<P: Integer>(self) -> Quantity<Prod<D, P>> where
+		D: Mul<P>
+	{
+		Quantity::from_si(self.value_si.powi(P::I32))
+	}
+
+	/// Take the `R`th root, e.g. `area.root::<P2>()`.  Only compiles where every power of the dimension is divisible by `R`
+	pub fn root<R: Integer>(self) -> Quantity<PartialQuot<D, R>> where
+		D: PartialDiv<R>
+	{
+		Quantity::from_si(self.value_si.powf(1.0/R::I32 as Float))
+	}
+}
+
+impl<D> Clone for Quantity<D> {
+	fn clone(&self) -> Quantity<D> { *self }
+}
+impl<D> Copy for Quantity<D> {}
+
+impl<D> Default for Quantity<D> {
+	fn default() -> Quantity<D> { Quantity::from_si(0.0) }
+}
+
+impl<D> PartialEq for Quantity<D> {
+	fn eq(&self, other: &Quantity<D>) -> bool { self.value_si == other.value_si }
+}
+impl<D> PartialOrd for Quantity<D> {
+	fn partial_cmp(&self, other: &Quantity<D>) -> Option<core::cmp::Ordering> { self.value_si.partial_cmp(&other.value_si) }
+}
+
+/// Write the SI base units of dimension `D` after a value, e.g. ` kg m^2 s^-2`
+fn write_units<D: Dimension>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+	let p = D::POWERS;
+	for (power, symbol) in [(p[2],"kg"), (p[1],"m"), (p[0],"s"), (p[3],"A"), (p[4],"K"), (p[5],"mol"), (p[6],"cd"), (p[7],"rad"), (p[8],"count")] {
+		if power != 0 {
+			write!(f, " {}", symbol)?;
+		}
+		if power != 0 && power != 1 {
+			write!(f, "^{}", power)?;
+		}
+	}
+	Ok(())
+}
+
+/// Formats as the value in SI base units followed by the units, e.g. `1500 kg m^2 s^-2`, as the main backend
+impl<D: Dimension> fmt::Display for Quantity<D> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.value_si, f)?;
+		write_units::<D>(f)
+	}
+}
+impl<D: Dimension> fmt::LowerExp for Quantity<D> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::LowerExp::fmt(&self.value_si, f)?;
+		write_units::<D>(f)
+	}
+}
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Display::fmt(self, f) }
+}
+
+
+// Arithmetic
+
+impl<D> Add for Quantity<D> {
+	type Output = Quantity<D>;
+	fn add(self, rhs: Quantity<D>) -> Quantity<D> { Quantity::from_si(self.value_si + rhs.value_si) }
+}
+impl<D> Sub for Quantity<D> {
+	type Output = Quantity<D>;
+	fn sub(self, rhs: Quantity<D>) -> Quantity<D> { Quantity::from_si(self.value_si - rhs.value_si) }
+}
+impl<D> Neg for Quantity<D> {
+	type Output = Quantity<D>;
+	fn neg(self) -> Quantity<D> { Quantity::from_si(-self.value_si) }
+}
+impl<D> AddAssign for Quantity<D> {
+	fn add_assign(&mut self, rhs: Quantity<D>) { self.value_si += rhs.value_si; }
+}
+impl<D> SubAssign for Quantity<D> {
+	fn sub_assign(&mut self, rhs: Quantity<D>) { self.value_si -= rhs.value_si; }
+}
+
+/// Multiplication adds the powers of each dimension
+impl<D1: Add<D2>, D2> Mul<Quantity<D2>> for Quantity<D1> {
+	type Output = Quantity<Sum<D1, D2>>;
+	fn mul(self, rhs: Quantity<D2>) -> Self::Output { Quantity::from_si(self.value_si*rhs.value_si) }
+}
+/// Division subtracts the powers of each dimension
+impl<D1: Sub<D2>, D2> Div<Quantity<D2>> for Quantity<D1> {
+	type Output = Quantity<Diff<D1, D2>>;
+	fn div(self, rhs: Quantity<D2>) -> Self::Output { Quantity::from_si(self.value_si/rhs.value_si) }
+}
+
+impl<D> Mul<Float> for Quantity<D> {
+	type Output = Quantity<D>;
+	fn mul(self, rhs: Float) -> Quantity<D> { Quantity::from_si(self.value_si*rhs) }
+}
+impl<D> Div<Float> for Quantity<D> {
+	type Output = Quantity<D>;
+	fn div(self, rhs: Float) -> Quantity<D> { Quantity::from_si(self.value_si/rhs) }
+}
+impl<D> MulAssign<Float> for Quantity<D> {
+	fn mul_assign(&mut self, rhs: Float) { self.value_si *= rhs; }
+}
+impl<D> DivAssign<Float> for Quantity<D> {
+	fn div_assign(&mut self, rhs: Float) { self.value_si /= rhs; }
+}
+impl<D> Mul<Quantity<D>> for Float {
+	type Output = Quantity<D>;
+	fn mul(self, rhs: Quantity<D>) -> Quantity<D> { Quantity::from_si(self*rhs.value_si) }
+}
+impl<D: Neg> Div<Quantity<D>> for Float {
+	type Output = Quantity<Negate<D>>;
+	fn div(self, rhs: Quantity<D>) -> Self::Output { Quantity::from_si(self/rhs.value_si) }
+}
+
+type Dimensionless = Dim<Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0>;
+
+impl From<Float> for Quantity<Dimensionless> {
+	fn from(value: Float) -> Quantity<Dimensionless> { Quantity::from_si(value) }
+}
+impl From<Quantity<Dimensionless>> for Float {
+	fn from(value: Quantity<Dimensionless>) -> Float { value.value_si }
+}
+
+
+pub mod dimens {
+	//! Type aliases for the [Quantity] variants corresponding to named physical properties, a subset of those of the main backend
+
+	use typenum::{N1,N2,N3,P1,P2,P3,P4,Z0};
+	use super::{Dim,Quantity};
+
+	pub type Unitless =		Quantity<Dim<Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Angle =		Quantity<Dim<Z0,Z0,Z0,Z0,Z0,Z0,Z0,P1,Z0>>;
+	pub type Count =		Quantity<Dim<Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0,P1>>;
+	pub type CountRate =	Quantity<Dim<N1,Z0,Z0,Z0,Z0,Z0,Z0,Z0,P1>>;
+
+	pub type Time =			Quantity<Dim<P1,Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Length =		Quantity<Dim<Z0,P1,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Area =			Quantity<Dim<Z0,P2,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Volume =		Quantity<Dim<Z0,P3,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Mass =			Quantity<Dim<Z0,Z0,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Density =		Quantity<Dim<Z0,N3,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type MassFlowRate =	Quantity<Dim<N1,Z0,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type VolumetricFlowRate =	Quantity<Dim<N1,P3,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type AmountOfSubstance =	Quantity<Dim<Z0,Z0,Z0,Z0,Z0,P1,Z0,Z0,Z0>>;
+	pub type MolarMass =	Quantity<Dim<Z0,Z0,P1,Z0,Z0,N1,Z0,Z0,Z0>>;
+	pub type Concentration =	Quantity<Dim<Z0,N3,Z0,Z0,Z0,P1,Z0,Z0,Z0>>;
+	pub type Current =		Quantity<Dim<Z0,Z0,Z0,P1,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Temperature =	Quantity<Dim<Z0,Z0,Z0,Z0,P1,Z0,Z0,Z0,Z0>>;
+	pub type LuminousIntensity =	Quantity<Dim<Z0,Z0,Z0,Z0,Z0,Z0,P1,Z0,Z0>>;
+
+	pub type Frequency =	Quantity<Dim<N1,Z0,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Velocity =		Quantity<Dim<N1,P1,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Acceleration =	Quantity<Dim<N2,P1,Z0,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	/// Rotational speed in radians per second.  Distinct from [Frequency] in cycles per second
+	pub type AngularVelocity =	Quantity<Dim<N1,Z0,Z0,Z0,Z0,Z0,Z0,P1,Z0>>;
+	pub type Momentum =		Quantity<Dim<N1,P1,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Force =		Quantity<Dim<N2,P1,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Pressure =		Quantity<Dim<N2,N1,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Energy =		Quantity<Dim<N2,P2,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+	/// Torque, as energy per radian of rotation so that [Torque] times [Angle] is work
+	pub type Torque =		Quantity<Dim<N2,P2,P1,Z0,Z0,Z0,Z0,N1,Z0>>;
+	pub type Power =		Quantity<Dim<N3,P2,P1,Z0,Z0,Z0,Z0,Z0,Z0>>;
+
+	pub type Charge =		Quantity<Dim<P1,Z0,Z0,P1,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Voltage =		Quantity<Dim<N3,P2,P1,N1,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Resistance =	Quantity<Dim<N3,P2,P1,N2,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Capacitance =	Quantity<Dim<P4,N2,N1,P2,Z0,Z0,Z0,Z0,Z0>>;
+	pub type Inductance =	Quantity<Dim<N2,P2,P1,N2,Z0,Z0,Z0,Z0,Z0>>;
+}
+
+pub mod units {
+	//! Constants for common units, a subset of those of the main backend.  Without const arithmetic on stable they are defined by
+	//! their size in SI base units
+
+	use crate::Float;
+	use super::dimens::*;
+
+	pub const QUECTO: Unitless = Unitless::from_si(1.0e-30);
+	pub const RONTO: Unitless = Unitless::from_si(1.0e-27);
+	pub const YOCTO: Unitless = Unitless::from_si(1.0e-24);
+	pub const ZEPTO: Unitless = Unitless::from_si(1.0e-21);
+	pub const ATTO: Unitless = Unitless::from_si(1.0e-18);
+	pub const FEMPTO: Unitless = Unitless::from_si(1.0e-15);
+	pub const PICO: Unitless = Unitless::from_si(1.0e-12);
+	pub const NANO: Unitless = Unitless::from_si(1.0e-9);
+	pub const MICRO: Unitless = Unitless::from_si(1.0e-6);
+	pub const MILLI: Unitless = Unitless::from_si(1.0e-3);
+	pub const CENTI: Unitless = Unitless::from_si(1.0e-2);
+	pub const DECI: Unitless = Unitless::from_si(1.0e-1);
+	pub const DECA: Unitless = Unitless::from_si(1.0e1);
+	pub const HECTO: Unitless = Unitless::from_si(1.0e2);
+	pub const KILO: Unitless = Unitless::from_si(1.0e3);
+	pub const MEGA: Unitless = Unitless::from_si(1.0e6);
+	pub const GIGA: Unitless = Unitless::from_si(1.0e9);
+	pub const TERA: Unitless = Unitless::from_si(1.0e12);
+	pub const PETA: Unitless = Unitless::from_si(1.0e15);
+	pub const EXA: Unitless = Unitless::from_si(1.0e18);
+	pub const ZETTA: Unitless = Unitless::from_si(1.0e21);
+	pub const YOTTA: Unitless = Unitless::from_si(1.0e24);
+	pub const RONNA: Unitless = Unitless::from_si(1.0e27);
+	pub const QUETTA: Unitless = Unitless::from_si(1.0e30);
+
+	pub const NONE: Unitless = Unitless::from_si(1.0);
+	pub const PERCENT: Unitless = Unitless::from_si(0.01);
+
+	// Angle Units
+	pub const RADIAN: Angle = Angle::from_si(1.0);
+	pub const DEGREE: Angle = Angle::from_si(crate::float_consts::PI/180.0);
+	pub const TURN: Angle = Angle::from_si(crate::float_consts::TAU);
+
+	// Count Units
+	pub const COUNT: Count = Count::from_si(1.0);
+
+	// Time Units
+	pub const SECOND: Time = Time::from_si(1.0);
+	pub const MINUTE: Time = Time::from_si(60.0);
+	pub const HOUR: Time = Time::from_si(3600.0);
+	pub const DAY: Time = Time::from_si(86400.0);
+	pub const YEAR: Time = Time::from_si(365.25*86400.0);
+	pub const HERTZ: Frequency = Frequency::from_si(1.0);
+	pub const RPM: AngularVelocity = AngularVelocity::from_si(crate::float_consts::TAU/60.0);
+
+	// Length Units
+	pub const METER: Length = Length::from_si(1.0);
+	pub const INCH: Length = Length::from_si(0.0254);
+	pub const FOOT: Length = Length::from_si(0.3048);
+	pub const YARD: Length = Length::from_si(0.9144);
+	pub const MILE: Length = Length::from_si(1609.344);
+	pub const FURLONG: Length = Length::from_si(201.168);
+	pub const NAUTICAL_MILE: Length = Length::from_si(1852.0);
+	pub const KNOT: Velocity = Velocity::from_si(1852.0/3600.0);
+	pub const KPH: Velocity = Velocity::from_si(1000.0/3600.0);
+	pub const MPH: Velocity = Velocity::from_si(1609.344/3600.0);
+	pub const HECTARE: Area = Area::from_si(1.0e4);
+	pub const LITER: Volume = Volume::from_si(1.0e-3);
+	pub const US_GAL: Volume = Volume::from_si(3.785411784e-3);
+
+	// Mass Units
+	pub const GRAM: Mass = Mass::from_si(1.0e-3);
+	pub const POUND_MASS: Mass = Mass::from_si(0.45359237);
+	pub const OUNCE_MASS: Mass = Mass::from_si(0.45359237/16.0);
+
+	// Mechanical Units
+	pub const NEWTON: Force = Force::from_si(1.0);
+	pub const POUND_FORCE: Force = Force::from_si(0.45359237*9.80665);
+	pub const PASCAL: Pressure = Pressure::from_si(1.0);
+	pub const BAR: Pressure = Pressure::from_si(1.0e5);
+	pub const PSI: Pressure = Pressure::from_si(0.45359237*9.80665/(0.0254*0.0254));
+	pub const JOULE: Energy = Energy::from_si(1.0);
+	pub const WATT: Power = Power::from_si(1.0);
+	pub const WATT_HOUR: Energy = Energy::from_si(3600.0);
+	pub const ELECTRONVOLT: Energy = Energy::from_si(1.602176634e-19);
+	pub const NEWTON_METER: Torque = Torque::from_si(1.0);
+
+	// Electrical Units
+	pub const AMPERE: Current = Current::from_si(1.0);
+	pub const COULOMB: Charge = Charge::from_si(1.0);
+	pub const AMPERE_HOUR: Charge = Charge::from_si(3600.0);
+	pub const VOLT: Voltage = Voltage::from_si(1.0);
+	pub const OHM: Resistance = Resistance::from_si(1.0);
+	pub const FARAD: Capacitance = Capacitance::from_si(1.0);
+	pub const HENRY: Inductance = Inductance::from_si(1.0);
+
+	// Thermal, Chemical and Photometric Units
+	pub const KELVIN: Temperature = Temperature::from_si(1.0);
+	pub const RANKINE: Temperature = Temperature::from_si(5.0/9.0);
+	pub const MOLE: AmountOfSubstance = AmountOfSubstance::from_si(1.0);
+	pub const MOLAR: Concentration = Concentration::from_si(1.0e3);
+	pub const CANDELA: LuminousIntensity = LuminousIntensity::from_si(1.0);
+
+	/// Temperature of `celsius` degrees Celsius.  Offset units have no constant on this backend
+	pub fn celsius(celsius: Float) -> Temperature {
+		Temperature::from_si(celsius + 273.15)
+	}
+	/// Value of `temperature` in degrees Celsius
+	pub fn as_celsius(temperature: Temperature) -> Float {
+		temperature.as_si() - 273.15
+	}
+}
+
+pub mod consts {
+	//! Unit-aware physical constants, a subset of those of the main backend
+	#![allow(clippy::type_complexity)]
+
+	use typenum::{N1,N2,N3,P1,P2,P3,P4,Z0};
+	use super::{Dim,Quantity};
+	use super::dimens::*;
+
+	pub const PLANK_CONSTANT: Quantity<Dim<N1,P2,P1,Z0,Z0,Z0,Z0,Z0,Z0>> = Quantity::from_si(6.62607015e-34);
+	pub const SPEED_OF_LIGHT: Velocity = Velocity::from_si(299792458.0);
+	pub const ELEMENTARY_CHARGE: Charge = Charge::from_si(1.602176634e-19);
+	pub const BOLTZMANN_CONSTANT: Quantity<Dim<N2,P2,P1,Z0,N1,Z0,Z0,Z0,Z0>> = Quantity::from_si(1.380649e-23);
+	pub const AVOGADRO_CONSTANT: Quantity<Dim<Z0,Z0,Z0,Z0,Z0,N1,Z0,Z0,Z0>> = Quantity::from_si(6.02214076e23);
+	pub const MOLAR_GAS_CONSTANT: Quantity<Dim<N2,P2,P1,Z0,N1,N1,Z0,Z0,Z0>> = Quantity::from_si(1.380649e-23*6.02214076e23);
+	pub const STANDARD_GRAVITY: Acceleration = Acceleration::from_si(9.80665);
+	pub const STANDARD_ATMOSPHERE: Pressure = Pressure::from_si(101325.0);
+	pub const GRAVITIONAL_CONSTANT: Quantity<Dim<N2,P3,N1,Z0,Z0,Z0,Z0,Z0,Z0>> = Quantity::from_si(6.67430e-11);
+	pub const VACUUM_PERMITTIVITY: Quantity<Dim<P4,N3,N1,P2,Z0,Z0,Z0,Z0,Z0>> = Quantity::from_si(8.8541878188e-12);
+	pub const ELECTRON_MASS: Mass = Mass::from_si(9.1093837015e-31);
+}
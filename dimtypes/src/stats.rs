@@ -0,0 +1,112 @@
+//! Descriptive statistics over slices of dimensioned values
+//!
+//! Every statistic keeps the dimension of its inputs (or the appropriate power of it for [variance]), so post-processing of
+//! simulation or measurement results stays unit-checked.
+
+use std::collections::HashMap;
+use crate::{Float,Power,Quantity};
+use crate::dimens::{Time,Unitless};
+
+/// Arithmetic mean of `values`.  Returns NaN for an empty slice
+pub fn mean<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	let sum: Float = values.iter().map(|v| v.as_si()).sum();
+	Quantity::from_si(sum/(values.len() as Float))
+}
+
+/// Probability-weighted expectation of `values`, where `weights` need not be normalized.  Panics if the slices differ in length
+pub fn expectation<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], weights: &[Unitless]) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	assert_eq!(values.len(), weights.len(), "values and weights must have the same length");
+	let (weighted, total) = values.iter().zip(weights).fold((0.0, 0.0), |(sum, total), (v, &w)| {
+		let w = Float::from(w);
+		(sum + w*v.as_si(), total + w)
+	});
+	Quantity::from_si(weighted/total)
+}
+
+/// Sample variance (with Bessel's correction) of `values`.  The result has the squared dimension of the inputs
+pub fn variance<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Power<2,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	let mu = mean(values).as_si();
+	let ss: Float = values.iter().map(|v| (v.as_si() - mu).powi(2)).sum();
+	Quantity::from_si(ss/((values.len() as Float) - 1.0))
+}
+
+/// Sample standard deviation (with Bessel's correction) of `values`
+pub fn std_dev<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> where
+	Power<2,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+{
+	Quantity::from_si(variance(values).as_si().sqrt())
+}
+
+/// The `p`th percentile (0 to 100) of `values`, linearly interpolating between the closest ranks.  Returns NaN for an empty slice
+pub fn percentile<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], p: Float) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	let mut sorted: Vec<Float> = values.iter().map(|v| v.as_si()).collect();
+	sorted.sort_by(Float::total_cmp);
+	if sorted.is_empty() {
+		return Quantity::from_si(Float::NAN);
+	}
+	let rank = (p/100.0).clamp(0.0, 1.0)*((sorted.len() - 1) as Float);
+	let lower = rank.floor() as usize;
+	let upper = rank.ceil() as usize;
+	let frac = rank - lower as Float;
+	Quantity::from_si(sorted[lower] + frac*(sorted[upper] - sorted[lower]))
+}
+
+/// Median of `values`
+pub fn median<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+	percentile(values, 50.0)
+}
+
+/// Center of the most populated histogram bin when `values` are binned with width `bin_width` (bins are aligned to zero).
+/// Ties go to the lowest bin.  Returns [None] for an empty slice
+pub fn mode_bin<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], bin_width: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Option<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	let width = bin_width.as_si();
+	let mut counts: HashMap<i64, usize> = HashMap::new();
+	for v in values {
+		*counts.entry((v.as_si()/width).floor() as i64).or_default() += 1;
+	}
+	counts.into_iter()
+		.max_by(|(bin_a, count_a), (bin_b, count_b)| count_a.cmp(count_b).then(bin_b.cmp(bin_a)))
+		.map(|(bin, _)| Quantity::from_si((bin as Float + 0.5)*width))
+}
+
+/// Overlapping Allan deviation of evenly spaced `samples` (rates or fractional frequencies) at an averaging time of `m` sample intervals.
+/// Returns [None] unless there are more than 2·`m` samples
+pub fn allan_deviation_at<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(samples: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], m: usize) -> Option<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	let n = samples.len();
+	if m == 0 || n <= 2*m {
+		return None;
+	}
+	// Cumulative sums turn each τ-average into a difference of two entries
+	let mut phase = Vec::with_capacity(n + 1);
+	phase.push(0.0);
+	for s in samples {
+		phase.push(phase.last().copied().unwrap_or(0.0) + s.as_si());
+	}
+	let terms = n + 1 - 2*m;
+	let sum: Float = (0..terms).map(|k| (phase[k + 2*m] - 2.0*phase[k + m] + phase[k]).powi(2)).sum();
+	let m = m as Float;
+	Some(Quantity::from_si(Float::sqrt(sum/(2.0*m*m*terms as Float))))
+}
+
+/// Overlapping Allan deviation of `samples` taken every `tau0`, at octave-spaced averaging times τ<sub>0</sub>, 2τ<sub>0</sub>, 4τ<sub>0</sub>, ...
+/// up to a third of the record length.  Returns (τ, σ(τ)) pairs
+pub fn allan_deviation<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+	(samples: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>], tau0: Time) -> Vec<(Time, Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>)> {
+	let mut out = Vec::new();
+	let mut m = 1;
+	while 3*m <= samples.len() {
+		if let Some(deviation) = allan_deviation_at(samples, m) {
+			out.push(((m as Float)*tau0, deviation));
+		}
+		m *= 2;
+	}
+	out
+}
@@ -0,0 +1,224 @@
+//! Timing code with results in [Time] quantities
+//!
+//! A [Stopwatch] measures wall-clock time with [Instant] and reports laps and totals as [Time], so timings combine directly with the
+//! rest of a dimensioned program, e.g. dividing the number of samples processed by a lap time gives a [Frequency].  A
+//! [Profiler] accumulates the time spent in named sections over many calls.  Both display their results with
+//! [Time::human][crate::Quantity::human].
+//!
+//! [Time] converts from a std [Duration] with `From`, and back with [Time::to_duration], which fails for negative times since a
+//! [Duration] cannot be negative.  [since] and [between] measure [Instant]s directly in [Time].
+
+use std::fmt;
+use std::time::{Duration,Instant};
+use crate::Float;
+use crate::dimens::*;
+
+/// Error converting a [Time] to a [Duration]
+#[derive(Clone, Copy, Debug)]
+pub enum DurationError {
+	/// The time is negative
+	Negative(Time),
+	/// The time is NaN, infinite or too long for a [Duration], or added to an [Instant] gives a time the platform cannot represent
+	OutOfRange(Time),
+}
+
+impl fmt::Display for DurationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			DurationError::Negative(time) => write!(f, "negative time {} has no duration", time),
+			DurationError::OutOfRange(time) => write!(f, "time {} is out of the range of a duration", time),
+		}
+	}
+}
+
+impl std::error::Error for DurationError {}
+
+impl From<Duration> for Time {
+	fn from(duration: Duration) -> Time {
+		Time::from_si(duration.as_secs_f64() as Float)
+	}
+}
+
+impl Time {
+	/// This time as a [Duration], rounded to the nanosecond.  Fails if the time is negative (other than `-0`), NaN or too long
+	#[allow(clippy::unnecessary_cast)]
+	pub fn to_duration(self) -> Result<Duration, DurationError> {
+		if self.as_si() < 0.0 {
+			return Err(DurationError::Negative(self));
+		}
+		Duration::try_from_secs_f64(self.as_si() as f64).map_err(|_| DurationError::OutOfRange(self))
+	}
+
+	/// The instant this time after `instant`, or before it if the time is negative
+	pub fn after(self, instant: Instant) -> Result<Instant, DurationError> {
+		let (magnitude, negative) = (Time::from_si(self.as_si().abs()), self.as_si() < 0.0);
+		let duration = magnitude.to_duration().map_err(|_| DurationError::OutOfRange(self))?;
+		let later = if negative { instant.checked_sub(duration) } else { instant.checked_add(duration) };
+		later.ok_or(DurationError::OutOfRange(self))
+	}
+}
+
+/// Time elapsed since `instant`
+pub fn since(instant: Instant) -> Time {
+	Time::from(instant.elapsed())
+}
+
+/// Time from `earlier` to `later`, negative if `later` is in fact earlier
+pub fn between(earlier: Instant, later: Instant) -> Time {
+	match later.checked_duration_since(earlier) {
+		Some(duration) => Time::from(duration),
+		None => -Time::from(earlier.duration_since(later)),
+	}
+}
+
+/// Time taken to run `f`, with its result
+pub fn time<R>(f: impl FnOnce() -> R) -> (R, Time) {
+	let start = Instant::now();
+	let result = f();
+	(result, since(start))
+}
+
+/// Wall-clock stopwatch recording laps
+#[derive(Clone, Debug)]
+pub struct Stopwatch {
+	start: Instant,
+	lap_start: Instant,
+	laps: Vec<Time>,
+}
+
+impl Stopwatch {
+	/// Stopwatch started now
+	pub fn start() -> Stopwatch {
+		let now = Instant::now();
+		Stopwatch { start: now, lap_start: now, laps: Vec::new() }
+	}
+
+	/// Time since the stopwatch started
+	pub fn elapsed(&self) -> Time {
+		since(self.start)
+	}
+
+	/// Time since the last lap (or the start) without ending the lap
+	pub fn current_lap(&self) -> Time {
+		since(self.lap_start)
+	}
+
+	/// End the current lap and start the next, returning the time of the lap just ended
+	pub fn lap(&mut self) -> Time {
+		let now = Instant::now();
+		let lap = Time::from(now.duration_since(self.lap_start));
+		self.lap_start = now;
+		self.laps.push(lap);
+		lap
+	}
+
+	/// Times of the completed laps
+	pub fn laps(&self) -> &[Time] {
+		&self.laps
+	}
+
+	/// Mean time of the completed laps, or `None` before the first lap
+	pub fn mean_lap(&self) -> Option<Time> {
+		if self.laps.is_empty() {
+			return None;
+		}
+		Some(self.laps.iter().fold(Time::from_si(0.0), |total, &lap| total + lap)/(self.laps.len() as Float))
+	}
+
+	/// Shortest and longest completed laps, or `None` before the first lap
+	pub fn lap_range(&self) -> Option<(Time, Time)> {
+		let first = *self.laps.first()?;
+		Some(self.laps.iter().fold((first, first), |(low, high), &lap| {
+			(if lap.as_si() < low.as_si() { lap } else { low }, if lap.as_si() > high.as_si() { lap } else { high })
+		}))
+	}
+
+	/// Clear the laps and start again from now
+	pub fn restart(&mut self) {
+		*self = Stopwatch::start();
+	}
+}
+
+/// Formats as the elapsed time, followed by a summary of the laps if there are any, e.g.
+/// `2.51 s (10 laps, mean 251 ms, min 248 ms, max 260 ms)`
+impl fmt::Display for Stopwatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.elapsed().human())?;
+		if let (Some(mean), Some((low, high))) = (self.mean_lap(), self.lap_range()) {
+			write!(f, " ({} laps, mean {}, min {}, max {})", self.laps.len(), mean.human(), low.human(), high.human())?;
+		}
+		Ok(())
+	}
+}
+
+/// Total time and number of calls of one section timed by a [Profiler]
+#[derive(Clone, Debug)]
+pub struct Section {
+	pub name: String,
+	pub total: Time,
+	pub calls: usize,
+}
+
+impl Section {
+	/// Mean time per call
+	pub fn mean(&self) -> Time {
+		self.total/(self.calls.max(1) as Float)
+	}
+}
+
+/// Accumulates the time spent in named sections of code
+#[derive(Clone, Debug, Default)]
+pub struct Profiler {
+	sections: Vec<Section>,
+}
+
+impl Profiler {
+	pub fn new() -> Profiler {
+		Profiler::default()
+	}
+
+	/// Run `f`, adding the time it takes to the section `name`
+	pub fn measure<R>(&mut self, name: &str, f: impl FnOnce() -> R) -> R {
+		let (result, elapsed) = time(f);
+		self.record(name, elapsed);
+		result
+	}
+
+	/// Add `elapsed` to the section `name`, e.g. a time measured elsewhere
+	pub fn record(&mut self, name: &str, elapsed: Time) {
+		match self.sections.iter_mut().find(|s| s.name == name) {
+			Some(section) => {
+				section.total += elapsed;
+				section.calls += 1;
+			},
+			None => self.sections.push(Section { name: name.to_string(), total: elapsed, calls: 1 }),
+		}
+	}
+
+	/// Sections in the order they were first timed
+	pub fn sections(&self) -> &[Section] {
+		&self.sections
+	}
+
+	/// Total time over all sections
+	pub fn total(&self) -> Time {
+		self.sections.iter().fold(Time::from_si(0.0), |total, s| total + s.total)
+	}
+}
+
+/// Formats as a table of the sections, most time consuming first, with their total and mean times, call counts and share of the total
+impl fmt::Display for Profiler {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut sections: Vec<&Section> = self.sections.iter().collect();
+		sections.sort_by(|a, b| b.total.as_si().total_cmp(&a.total.as_si()));
+		let total = self.total().as_si();
+		let width = sections.iter().map(|s| s.name.chars().count()).max().unwrap_or(0).max("Section".len());
+		write!(f, "{:<width$} | {:>16} | {:>10} | {:>16} | {:>6}", "Section", "Total", "Calls", "Mean", "Share", width = width)?;
+		for s in sections {
+			let share = if total > 0.0 { 100.0*s.total.as_si()/total } else { 0.0 };
+			write!(f, "\n{:<width$} | {:>16} | {:>10} | {:>16} | {:>5.1}%", s.name, s.total.human().to_string(), s.calls,
+				s.mean().human().to_string(), share, width = width)?;
+		}
+		Ok(())
+	}
+}
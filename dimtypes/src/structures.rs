@@ -0,0 +1,91 @@
+//! Section properties and bending of slender beams
+//!
+//! Deflections and moments follow Euler–Bernoulli beam theory for prismatic beams of a linear elastic material.
+
+use crate::float_consts::PI;
+use crate::dimens::*;
+
+/// Second moment of area of a solid rectangle `width` wide and `height` deep, about its horizontal centroidal axis (b h³/12)
+pub fn rectangle_second_moment(width: Length, height: Length) -> SecondMomentOfArea {
+	width*height*height*height/12.0
+}
+
+/// Second moment of area of a hollow rectangle with outer dimensions `width` × `height` and walls of thickness `wall`
+pub fn hollow_rectangle_second_moment(width: Length, height: Length, wall: Length) -> SecondMomentOfArea {
+	rectangle_second_moment(width, height) - rectangle_second_moment(width - 2.0*wall, height - 2.0*wall)
+}
+
+/// Second moment of area of a solid circle of diameter `diameter` about a diameter (π d⁴/64)
+pub fn circle_second_moment(diameter: Length) -> SecondMomentOfArea {
+	PI/64.0*diameter*diameter*diameter*diameter
+}
+
+/// Second moment of area of a tube with outer diameter `outer` and inner diameter `inner`
+pub fn tube_second_moment(outer: Length, inner: Length) -> SecondMomentOfArea {
+	circle_second_moment(outer) - circle_second_moment(inner)
+}
+
+/// Polar second moment of area of a tube with outer diameter `outer` and inner diameter `inner` (use zero for a solid shaft)
+pub fn polar_second_moment(outer: Length, inner: Length) -> SecondMomentOfArea {
+	2.0*tube_second_moment(outer, inner)
+}
+
+/// Elastic section modulus I/c of a section with second moment `second_moment`, whose extreme fibre is `extreme_fibre` from the neutral axis
+pub fn section_modulus(second_moment: SecondMomentOfArea, extreme_fibre: Length) -> SectionModulus {
+	second_moment/extreme_fibre
+}
+
+/// Maximum bending stress M/S for a bending moment `moment` on a section with section modulus `section_modulus`
+pub fn bending_stress(moment: BendingMoment, section_modulus: SectionModulus) -> Stress {
+	moment/section_modulus
+}
+
+/// Flexural rigidity E·I of a beam
+pub fn flexural_rigidity(modulus: YoungsModulus, second_moment: SecondMomentOfArea) -> FlexuralRigidity {
+	modulus*second_moment
+}
+
+/// How a beam is supported at its ends
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Support {
+	/// Fixed at one end and free at the other
+	Cantilever,
+	/// Pinned at both ends
+	SimplySupported,
+	/// Fixed at both ends
+	FixedFixed,
+}
+
+/// Load applied to a beam
+#[derive(Clone, Copy, Debug)]
+pub enum Load {
+	/// A single force, at the free end of a cantilever or at midspan otherwise
+	Point(Force),
+	/// A load distributed uniformly over the whole span
+	Uniform(PerLength<Force>),
+}
+
+/// Maximum deflection of a beam of span `length` and flexural rigidity `rigidity` under `load`
+pub fn max_deflection(support: Support, load: Load, length: Length, rigidity: FlexuralRigidity) -> Length {
+	let l3 = length*length*length;
+	match (support, load) {
+		(Support::Cantilever, Load::Point(p)) => p*l3/(3.0*rigidity),
+		(Support::Cantilever, Load::Uniform(w)) => w*length*l3/(8.0*rigidity),
+		(Support::SimplySupported, Load::Point(p)) => p*l3/(48.0*rigidity),
+		(Support::SimplySupported, Load::Uniform(w)) => 5.0*w*length*l3/(384.0*rigidity),
+		(Support::FixedFixed, Load::Point(p)) => p*l3/(192.0*rigidity),
+		(Support::FixedFixed, Load::Uniform(w)) => w*length*l3/(384.0*rigidity),
+	}
+}
+
+/// Maximum bending moment in a beam of span `length` under `load`
+pub fn max_moment(support: Support, load: Load, length: Length) -> BendingMoment {
+	match (support, load) {
+		(Support::Cantilever, Load::Point(p)) => p*length,
+		(Support::Cantilever, Load::Uniform(w)) => w*length*length/2.0,
+		(Support::SimplySupported, Load::Point(p)) => p*length/4.0,
+		(Support::SimplySupported, Load::Uniform(w)) => w*length*length/8.0,
+		(Support::FixedFixed, Load::Point(p)) => p*length/8.0,
+		(Support::FixedFixed, Load::Uniform(w)) => w*length*length/12.0,
+	}
+}
@@ -0,0 +1,198 @@
+//! Parameter sweeps over ranges of quantities, collecting typed results into a table
+//!
+//! A [Sweep] runs a model over every combination of values of its parameters, e.g. every pipe diameter in [linspace]`(10 mm, 50 mm,
+//! 5)` at every flow rate of a list, and records the parameters and the outputs the model sets as the channels of a
+//! [SampleBuffer].  The model reads the parameters of the current point from the [RowWriter] it is given and sets its outputs on it,
+//! so both stay typed through their [Channel] handles.  The resulting [SweepTable] shows each column in a unit chosen for the report,
+//! as an aligned text table through [Display][fmt::Display] or as CSV.
+
+use std::fmt;
+use crate::{AnyQuantity,Dimension,Float,Quantity,UnitSymbol};
+use crate::buffer::{Channel,ChannelError,RowWriter,SampleBuffer};
+use crate::parse::{parse_unit,ParsedUnit,UnitParseError};
+
+/// `n` evenly spaced quantities from `start` to `end`, both included.  Empty for `n = 0` and `start` alone for `n = 1`
+pub fn linspace<Q: AnyQuantity>(start: Q, end: Q, n: usize) -> Vec<Q> {
+	let (start, end) = (start.as_si(), end.as_si());
+	let step = if n > 1 { (end - start)/((n - 1) as Float) } else { 0.0 };
+	// The last point is set exactly rather than accumulated, so it is `end` without rounding error
+	(0..n).map(|i| Q::from_si(if i + 1 == n && n > 1 { end } else { start + step*(i as Float) })).collect()
+}
+
+/// Error building a [Sweep] or choosing the units of a [SweepTable]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SweepError {
+	Channel(ChannelError),
+	/// A display unit could not be parsed
+	Units(UnitParseError),
+}
+
+impl fmt::Display for SweepError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SweepError::Channel(e) => write!(f, "{}", e),
+			SweepError::Units(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl std::error::Error for SweepError {}
+
+impl From<ChannelError> for SweepError {
+	fn from(e: ChannelError) -> Self { SweepError::Channel(e) }
+}
+impl From<UnitParseError> for SweepError {
+	fn from(e: UnitParseError) -> Self { SweepError::Units(e) }
+}
+
+/// Parameters and outputs of a study, run over the cartesian product of the parameter values
+#[derive(Clone, Debug, Default)]
+pub struct Sweep {
+	buffer: SampleBuffer,
+	/// Channel index and values in SI base units of each parameter, in the order they were added
+	params: Vec<(usize, Vec<Float>)>,
+}
+
+impl Sweep {
+	pub fn new() -> Sweep {
+		Sweep::default()
+	}
+
+	/// Add a parameter called `name` taking each of `values` in turn.  Parameters added later vary faster, as in nested loops
+	pub fn param<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, values: Vec<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Result<Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, SweepError> {
+		let channel = self.buffer.add_channel(name)?;
+		self.params.push((channel.index(), values.iter().map(|v| v.as_si()).collect()));
+		Ok(channel)
+	}
+
+	/// Add an output called `name`, set by the model at each point.  Outputs the model leaves unset are NaN
+	pub fn output<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str) -> Result<Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, SweepError> {
+		Ok(self.buffer.add_channel(name)?)
+	}
+
+	/// Number of points the sweep runs, the product of the numbers of values of the parameters, or 1 without parameters
+	pub fn points(&self) -> usize {
+		self.params.iter().map(|(_, values)| values.len()).product()
+	}
+
+	/// Run `model` at every point, with the parameters of the point set on the [RowWriter] it is given, and collect a row for each
+	pub fn run(mut self, mut model: impl FnMut(&mut RowWriter)) -> SweepTable {
+		let points = self.points();
+		// Position of each parameter in its values, counting like the digits of a number with the last parameter least significant
+		let mut position = vec![0; self.params.len()];
+		for _ in 0..points {
+			let params = &self.params;
+			self.buffer.push_row(|row| {
+				for ((index, values), &i) in params.iter().zip(&position) {
+					row.set_raw(*index, values[i]);
+				}
+				model(row);
+			});
+			for (digit, (_, values)) in position.iter_mut().zip(&self.params).rev() {
+				*digit += 1;
+				if *digit < values.len() {
+					break;
+				}
+				*digit = 0;
+			}
+		}
+		let units = vec![None; self.buffer.channels().count()];
+		SweepTable { buffer: self.buffer, units }
+	}
+}
+
+/// Results of a [Sweep], one row per point, with a display unit for each column
+#[derive(Clone, Debug)]
+pub struct SweepTable {
+	buffer: SampleBuffer,
+	/// Unit and symbol each column is shown in, or `None` for SI base units
+	units: Vec<Option<(ParsedUnit, String)>>,
+}
+
+impl SweepTable {
+	/// The parameters and outputs of every point, read back through the [Channel]s of the [Sweep]
+	pub fn buffer(&self) -> &SampleBuffer {
+		&self.buffer
+	}
+
+	/// Show the column of `channel` in `unit`, e.g. `table.display_unit(speed, KPH)`
+	pub fn display_unit<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, channel: Channel<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, unit: impl UnitSymbol<Dimen=Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) {
+		// Display units are affine, so two points give their scale and offset
+		let offset = unit.val_to_qty(0.0).as_si();
+		let scale = unit.val_to_qty(1.0).as_si() - offset;
+		let parsed = ParsedUnit::with_offset(scale, offset, Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION);
+		self.units[channel.index()] = Some((parsed, unit.symbol().to_string()));
+	}
+
+	/// Show the column called `name` in `units`, a unit string [parse_unit] understands, checking it has the dimension of the column
+	pub fn display_units(&mut self, name: &str, units: &str) -> Result<(), SweepError> {
+		let (index, dimension) = self.buffer.channels().enumerate().find(|(_, (n, _))| *n == name).map(|(i, (_, d))| (i, d))
+			.ok_or_else(|| ChannelError::UnknownChannel(name.to_string()))?;
+		let unit = parse_unit(units)?;
+		if unit.dimension() != dimension {
+			return Err(ChannelError::DimensionMismatch { name: name.to_string(), expected: unit.dimension(), found: dimension }.into());
+		}
+		self.units[index] = Some((unit, units.to_string()));
+		Ok(())
+	}
+
+	/// Header of each column, its name followed by its unit in brackets unless dimensionless.  Columns without a display unit name
+	/// their SI derived unit where there is one
+	fn headers(&self) -> Vec<String> {
+		self.buffer.channels().zip(&self.units).map(|((name, dimension), unit)| match unit {
+			Some((_, symbol)) => format!("{} [{}]", name, symbol),
+			None if dimension == Dimension::NONE => name.to_string(),
+			None => format!("{} [{:#}]", name, dimension),
+		}).collect()
+	}
+
+	/// Values of each column in its display unit
+	fn columns(&self) -> Vec<Vec<Float>> {
+		self.buffer.channels().zip(&self.units).map(|((name, _), unit)| {
+			let (values, _) = self.buffer.raw_column(name).expect("channels of the buffer have columns");
+			values.iter().map(|&v| unit.as_ref().map_or(v, |(unit, _)| unit.from_si(v))).collect()
+		}).collect()
+	}
+
+	/// Comma separated values with a header row, each column in its display unit
+	pub fn to_csv(&self) -> String {
+		let quote = |s: &str| if s.contains([',', '"', '\n']) { format!("\"{}\"", s.replace('"', "\"\"")) } else { s.to_string() };
+		let mut out = self.headers().iter().map(|h| quote(h)).collect::<Vec<_>>().join(",");
+		let columns = self.columns();
+		for row in 0..self.buffer.len() {
+			out.push('\n');
+			out.push_str(&columns.iter().map(|c| c[row].to_string()).collect::<Vec<_>>().join(","));
+		}
+		out.push('\n');
+		out
+	}
+}
+
+/// Formats as a table with a header row and the values of each column right-aligned in its display unit.  Precision sets the
+/// decimal places of the values
+impl fmt::Display for SweepTable {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let headers = self.headers();
+		let cells: Vec<Vec<String>> = self.columns().iter().map(|column| column.iter().map(|v| match f.precision() {
+			Some(digits) => format!("{:.*}", digits, v),
+			None => v.to_string(),
+		}).collect()).collect();
+		let widths: Vec<usize> = headers.iter().zip(&cells)
+			.map(|(h, c)| c.iter().map(|s| s.chars().count()).max().unwrap_or(0).max(h.chars().count())).collect();
+		let line = |f: &mut fmt::Formatter<'_>, row: &mut dyn Iterator<Item=&String>| -> fmt::Result {
+			for (i, (cell, width)) in row.zip(&widths).enumerate() {
+				write!(f, "{}{:>width$}", if i == 0 { "" } else { " | " }, cell, width = width)?;
+			}
+			Ok(())
+		};
+		line(f, &mut headers.iter())?;
+		for row in 0..self.buffer.len() {
+			writeln!(f)?;
+			line(f, &mut cells.iter().map(|c| &c[row]))?;
+		}
+		Ok(())
+	}
+}
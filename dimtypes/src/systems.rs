@@ -0,0 +1,132 @@
+//! Alternative systems of units for natural-unit calculations
+//!
+//! Quantities are always stored in SI, but theoretical work is often done in systems where selected constants are set to one
+//! (c = ħ = 1, atomic units, Planck units).  A [UnitSystem] records the SI size of the unit of each base dimension in such a system,
+//! which is enough to convert any [Quantity] to and from its numerical value in that system.  None of the provided systems rescale
+//! amount of substance, luminous intensity, angle or count, which stay in moles, candelas, radians and counts.
+
+use crate::float_consts::PI;
+use crate::{Dimension,Float,Quantity};
+use crate::consts::*;
+use crate::dimens::*;
+use crate::units::{CANDELA,COUNT,MOLE,RADIAN};
+
+/// System of units defined by the SI size of its unit of each base dimension
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSystem {
+	pub name: &'static str,
+	pub time: Time,
+	pub length: Length,
+	pub mass: Mass,
+	pub current: Current,
+	pub temperature: Temperature,
+	pub amount: AmountOfSubstance,
+	pub luminous_intensity: LuminousIntensity,
+	pub angle: Angle,
+	pub count: Count,
+}
+
+impl UnitSystem {
+	/// The SI base units themselves
+	pub const SI: UnitSystem = UnitSystem {
+		name: "SI",
+		time: Time::from_si(1.0),
+		length: Length::from_si(1.0),
+		mass: Mass::from_si(1.0),
+		current: Current::from_si(1.0),
+		temperature: Temperature::from_si(1.0),
+		amount: AmountOfSubstance::from_si(1.0),
+		luminous_intensity: LuminousIntensity::from_si(1.0),
+		angle: Angle::from_si(1.0),
+		count: Count::from_si(1.0),
+	};
+
+	/// Natural units of particle physics (c = ħ = k<sub>B</sub> = ε<sub>0</sub> = 1, Heaviside–Lorentz) with `energy` as the unit of
+	/// energy, e.g. <code>UnitSystem::natural([GIGA][crate::units::GIGA]*[ELECTRONVOLT][crate::units::ELECTRONVOLT])</code>
+	pub fn natural(energy: Energy) -> UnitSystem {
+		let time = REDUCED_PLANCK_CONSTANT/energy;
+		let charge = (VACUUM_PERMITTIVITY*REDUCED_PLANCK_CONSTANT*SPEED_OF_LIGHT).root::<2>();
+		UnitSystem {
+			name: "natural",
+			time,
+			length: SPEED_OF_LIGHT*time,
+			mass: energy/SPEED_OF_LIGHT.pow::<2>(),
+			current: charge/time,
+			temperature: energy/BOLTZMANN_CONSTANT,
+			amount: MOLE.unit(),
+			luminous_intensity: CANDELA.unit(),
+			angle: RADIAN.unit(),
+			count: COUNT.unit(),
+		}
+	}
+
+	/// Hartree atomic units (ħ = m<sub>e</sub> = e = 4πε<sub>0</sub> = k<sub>B</sub> = 1)
+	pub fn atomic() -> UnitSystem {
+		let coulomb = 4.0*PI*VACUUM_PERMITTIVITY;
+		let length: Length = coulomb*REDUCED_PLANCK_CONSTANT.pow::<2>()/(ELECTRON_MASS*ELEMENTARY_CHARGE.pow::<2>());
+		let energy: Energy = REDUCED_PLANCK_CONSTANT.pow::<2>()/(ELECTRON_MASS*length.pow::<2>());
+		let time = REDUCED_PLANCK_CONSTANT/energy;
+		UnitSystem {
+			name: "atomic",
+			time,
+			length,
+			mass: ELECTRON_MASS,
+			current: ELEMENTARY_CHARGE/time,
+			temperature: energy/BOLTZMANN_CONSTANT,
+			amount: MOLE.unit(),
+			luminous_intensity: CANDELA.unit(),
+			angle: RADIAN.unit(),
+			count: COUNT.unit(),
+		}
+	}
+
+	/// Planck units (c = ħ = G = k<sub>B</sub> = 4πε<sub>0</sub> = 1)
+	pub fn planck() -> UnitSystem {
+		let length = (REDUCED_PLANCK_CONSTANT*GRAVITIONAL_CONSTANT/SPEED_OF_LIGHT.pow::<3>()).root::<2>();
+		let time = length/SPEED_OF_LIGHT;
+		let mass = (REDUCED_PLANCK_CONSTANT*SPEED_OF_LIGHT/GRAVITIONAL_CONSTANT).root::<2>();
+		let charge = (4.0*PI*VACUUM_PERMITTIVITY*REDUCED_PLANCK_CONSTANT*SPEED_OF_LIGHT).root::<2>();
+		UnitSystem {
+			name: "Planck",
+			time,
+			length,
+			mass,
+			current: charge/time,
+			temperature: mass*SPEED_OF_LIGHT.pow::<2>()/BOLTZMANN_CONSTANT,
+			amount: MOLE.unit(),
+			luminous_intensity: CANDELA.unit(),
+			angle: RADIAN.unit(),
+			count: COUNT.unit(),
+		}
+	}
+
+	/// SI size of the unit of quantities of `dimension` in this system
+	pub fn scale(&self, dimension: Dimension) -> Float {
+		let scale = self.time.as_si().powi(dimension.time as i32)
+			* self.length.as_si().powi(dimension.length as i32)
+			* self.mass.as_si().powi(dimension.mass as i32)
+			* self.current.as_si().powi(dimension.current as i32)
+			* self.temperature.as_si().powi(dimension.temperature as i32)
+			* self.amount.as_si().powi(dimension.amount as i32)
+			* self.luminous_intensity.as_si().powi(dimension.luminous_intensity as i32)
+			* self.angle.as_si().powi(dimension.angle as i32)
+			* self.count.as_si().powi(dimension.count as i32);
+		if dimension.denominator == 1 { scale } else { scale.powf(1.0/dimension.denominator as Float) }
+	}
+
+	/// The quantity with value 1 in this system.  Since [Quantity] implements [Unit][crate::Unit] this can be used directly with
+	/// [Quantity::as_unit] or multiplied by a value to enter quantities in this system
+	pub fn unit<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.scale(Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION))
+	}
+
+	/// Numerical value of `qty` in this system
+	pub fn value_of<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, qty: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Float {
+		qty.as_si()/self.scale(Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION)
+	}
+
+	/// Quantity with numerical value `value` in this system
+	pub fn quantity<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>(&self, value: Float) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(value*self.scale(Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION))
+	}
+}
@@ -0,0 +1,208 @@
+//! Telemetry messages where every field carries its own unit
+//!
+//! A [Schema] declares the fields an application expects, each with a dimension and a canonical unit.  Messages are flat JSON objects
+//! mapping field names to `{"value": <number>, "unit": "<unit string>"}`, suitable as MQTT payloads.  On ingest each unit is
+//! [parsed][crate::parse] and checked against the declared dimension, so a device reporting `"degF"` and another reporting `"K"` both
+//! land in the same typed field.
+
+use std::fmt;
+use std::marker::PhantomData;
+use crate::{Dimension,Float,Quantity};
+use crate::json;
+use crate::parse::{parse_unit,ParsedUnit,UnitParseError};
+
+/// Typed handle to a field of a [Schema]
+#[derive(Debug)]
+pub struct Field<Q> {
+	index: usize,
+	kind: PhantomData<Q>,
+}
+impl<Q> Clone for Field<Q> {
+	fn clone(&self) -> Self { *self }
+}
+impl<Q> Copy for Field<Q> {}
+
+/// Error declaring a [Schema] or ingesting a message
+#[derive(Clone, Debug, PartialEq)]
+pub enum TelemetryError {
+	/// A field with this name was already declared
+	DuplicateField(String),
+	/// The unit of a field could not be parsed
+	InvalidUnit { field: String, error: UnitParseError },
+	/// The unit of a field has a different dimension than declared
+	DimensionMismatch { field: String, expected: Dimension, found: Dimension },
+	/// A required field is absent from the message
+	MissingField(String),
+	/// A field is present but not of the form `{"value": <number>, "unit": "<string>"}`
+	MalformedField(String),
+	/// The message is not valid JSON
+	Json { message: String, position: usize },
+}
+
+impl fmt::Display for TelemetryError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TelemetryError::DuplicateField(name) => write!(f, "a field named '{}' already exists", name),
+			TelemetryError::InvalidUnit { field, error } => write!(f, "field '{}': {}", field, error),
+			TelemetryError::DimensionMismatch { field, expected, found } =>
+				write!(f, "field '{}' has dimension [{}], not [{}]", field, found, expected),
+			TelemetryError::MissingField(name) => write!(f, "missing required field '{}'", name),
+			TelemetryError::MalformedField(name) => write!(f, "field '{}' must be an object with a numeric value and a unit string", name),
+			TelemetryError::Json { message, position } => write!(f, "invalid JSON at byte {}: {}", position, message),
+		}
+	}
+}
+
+impl std::error::Error for TelemetryError {}
+
+impl From<json::Error> for TelemetryError {
+	fn from(e: json::Error) -> Self { TelemetryError::Json { message: e.message, position: e.position } }
+}
+
+#[derive(Clone, Debug)]
+struct FieldSpec {
+	name: String,
+	units: String,
+	unit: ParsedUnit,
+	required: bool,
+}
+
+/// Declaration of the fields of a telemetry message
+#[derive(Clone, Debug, Default)]
+pub struct Schema {
+	fields: Vec<FieldSpec>,
+}
+
+impl Schema {
+	pub fn new() -> Schema {
+		Schema::default()
+	}
+
+	/// Declare a required field called `name` holding quantities of type `Q`, published and read back in `units`
+	pub fn add_field<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, units: &str) -> Result<Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, TelemetryError> {
+		self.add::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>(name, units, true)
+	}
+	/// Declare a field which may be absent from messages
+	pub fn add_optional_field<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, units: &str) -> Result<Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, TelemetryError> {
+		self.add::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>(name, units, false)
+	}
+
+	fn add<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, units: &str, required: bool) -> Result<Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, TelemetryError> {
+		if self.fields.iter().any(|f| f.name == name) {
+			return Err(TelemetryError::DuplicateField(name.to_string()));
+		}
+		let unit = parse_unit(units).map_err(|error| TelemetryError::InvalidUnit { field: name.to_string(), error })?;
+		unit.check::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>().map_err(|e| TelemetryError::DimensionMismatch { field: name.to_string(), expected: e.expected, found: e.found })?;
+		self.fields.push(FieldSpec { name: name.to_string(), units: units.to_string(), unit, required });
+		Ok(Field { index: self.fields.len() - 1, kind: PhantomData })
+	}
+
+	/// Names and canonical unit strings of each field, in the order they were declared
+	pub fn fields(&self) -> impl Iterator<Item=(&str, &str)> {
+		self.fields.iter().map(|f| (f.name.as_str(), f.units.as_str()))
+	}
+
+	/// Encode a message as JSON.  `fill` sets the value of each field through the provided [MessageWriter]; fields it leaves unset
+	/// are omitted.  Values are written in each field's canonical unit
+	pub fn encode(&self, fill: impl FnOnce(&mut MessageWriter)) -> String {
+		let mut writer = MessageWriter { values: vec![None; self.fields.len()] };
+		fill(&mut writer);
+		let mut out = String::from("{");
+		for (spec, value) in self.fields.iter().zip(writer.values) {
+			if let Some(value) = value {
+				if out.len() > 1 {
+					out.push(',');
+				}
+				let value = spec.unit.from_si(value);
+				let value = if value.is_finite() { value.to_string() } else { "null".to_string() };
+				out.push_str(&format!("{}:{{\"value\":{},\"unit\":{}}}", json::quote(&spec.name), value, json::quote(&spec.units)));
+			}
+		}
+		out.push('}');
+		out
+	}
+
+	/// Decode and validate a JSON message.  Fields not declared in the schema are ignored, and a `null` value is treated as absent
+	pub fn ingest(&self, message: &str) -> Result<Message, TelemetryError> {
+		let members = match json::parse(message)? {
+			json::Value::Object(members) => members,
+			_ => return Err(TelemetryError::Json { message: "expected an object".to_string(), position: 0 }),
+		};
+		let mut values = vec![None; self.fields.len()];
+		for (spec, slot) in self.fields.iter().zip(values.iter_mut()) {
+			let field = members.iter().rev().find(|(name,_)| *name == spec.name).map(|(_,v)| v);
+			*slot = match field {
+				None | Some(json::Value::Null) => None,
+				Some(json::Value::Object(parts)) => read_field(spec, parts)?,
+				Some(_) => return Err(TelemetryError::MalformedField(spec.name.clone())),
+			};
+			if slot.is_none() && spec.required {
+				return Err(TelemetryError::MissingField(spec.name.clone()));
+			}
+		}
+		Ok(Message { values })
+	}
+
+	/// Numerical value of `field` in `message`, expressed in the field's canonical unit
+	pub fn canonical_value<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, message: &Message, field: Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Option<Float> {
+		message.values[field.index].map(|v| self.fields[field.index].unit.from_si(v))
+	}
+
+	/// Decode a message from raw bytes, such as an MQTT payload
+	pub fn ingest_bytes(&self, payload: &[u8]) -> Result<Message, TelemetryError> {
+		let text = std::str::from_utf8(payload)
+			.map_err(|e| TelemetryError::Json { message: "payload is not UTF-8".to_string(), position: e.valid_up_to() })?;
+		self.ingest(text)
+	}
+}
+
+fn read_field(spec: &FieldSpec, parts: &[(String, json::Value)]) -> Result<Option<Float>, TelemetryError> {
+	let get = |key: &str| parts.iter().find(|(k,_)| k == key).map(|(_,v)| v);
+	let value = match get("value") {
+		Some(json::Value::Number(v)) => *v,
+		Some(json::Value::Null) => return Ok(None),
+		_ => return Err(TelemetryError::MalformedField(spec.name.clone())),
+	};
+	let units = match get("unit") {
+		Some(json::Value::String(u)) => u,
+		_ => return Err(TelemetryError::MalformedField(spec.name.clone())),
+	};
+	let unit = parse_unit(units).map_err(|error| TelemetryError::InvalidUnit { field: spec.name.clone(), error })?;
+	if unit.dimension() != spec.unit.dimension() {
+		return Err(TelemetryError::DimensionMismatch { field: spec.name.clone(), expected: spec.unit.dimension(), found: unit.dimension() });
+	}
+	Ok(Some(unit.to_si(value)))
+}
+
+/// Values for a message being encoded by [Schema::encode]
+pub struct MessageWriter {
+	values: Vec<Option<Float>>,
+}
+
+impl MessageWriter {
+	/// Set the value of `field` in this message
+	pub fn set<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, field: Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>, value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		self.values[field.index] = Some(value.as_si());
+		self
+	}
+}
+
+/// A validated message ingested by [Schema::ingest]
+#[derive(Clone, Debug)]
+pub struct Message {
+	values: Vec<Option<Float>>,
+}
+
+impl Message {
+	/// Value of `field`, or [None] if it was absent from the message
+	pub fn get<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, field: Field<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Option<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		self.values[field.index].map(Quantity::from_si)
+	}
+}
+
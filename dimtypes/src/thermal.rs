@@ -0,0 +1,213 @@
+//! Heat transfer helpers
+//!
+//! [RcNetwork] models lumped thermal networks of heat capacities joined by thermal resistances, as used for semiconductor junction
+//! temperature estimates.
+
+use crate::Float;
+use crate::consts::STEFAN_BOLTZMANN_CONSTANT;
+use crate::dimens::*;
+
+/// Heat flux conducted through a slab of thickness `thickness` and thermal conductivity `conductivity` with face temperatures `t_hot` and `t_cold`
+pub fn conduction_flux(conductivity: ThermalConductivity, thickness: Length, t_hot: Temperature, t_cold: Temperature) -> HeatFlux {
+	conductivity*(t_hot - t_cold)/thickness
+}
+
+/// Heat flux from a surface at `t_surface` to a fluid at `t_fluid` with convective heat transfer coefficient `h`
+pub fn convection_flux(h: HeatTransferCoefficient, t_surface: Temperature, t_fluid: Temperature) -> HeatFlux {
+	h*(t_surface - t_fluid)
+}
+
+/// Emissive power σT⁴ of a black body at temperature `t`
+pub fn blackbody_emissive_power(t: Temperature) -> HeatFlux {
+	STEFAN_BOLTZMANN_CONSTANT*t.pow::<4>()
+}
+
+/// Net radiated heat flux q = ε·σ·(T₁⁴ − T₂⁴) from a gray surface at `t_surface` with emissivity `emissivity` to large surroundings at `t_surroundings`
+pub fn radiative_exchange(emissivity: Emissivity, t_surface: Temperature, t_surroundings: Temperature) -> HeatFlux {
+	emissivity*STEFAN_BOLTZMANN_CONSTANT*(t_surface.pow::<4>() - t_surroundings.pow::<4>())
+}
+
+/// Net radiated heat flux between two infinite parallel gray plates at `t1` and `t2` with emissivities `e1` and `e2`
+pub fn parallel_plate_exchange(e1: Emissivity, t1: Temperature, e2: Emissivity, t2: Temperature) -> HeatFlux {
+	let effective = 1.0/(1.0/e1 + 1.0/e2 - 1.0);
+	effective*STEFAN_BOLTZMANN_CONSTANT*(t1.pow::<4>() - t2.pow::<4>())
+}
+
+/// Linearized radiative heat transfer coefficient h<sub>r</sub> = ε·σ·(T₁² + T₂²)(T₁ + T₂) for a gray surface at `t_surface` exchanging with surroundings at `t_surroundings`.
+/// This lets radiation be combined with [convection_flux] as an equivalent film coefficient
+pub fn radiative_coefficient(emissivity: Emissivity, t_surface: Temperature, t_surroundings: Temperature) -> HeatTransferCoefficient {
+	emissivity*STEFAN_BOLTZMANN_CONSTANT*(t_surface.pow::<2>() + t_surroundings.pow::<2>())*(t_surface + t_surroundings)
+}
+
+/// Thermal diffusivity of a material with thermal conductivity `conductivity`, `density` and specific heat `heat_capacity`
+pub fn diffusivity(conductivity: ThermalConductivity, density: Density, heat_capacity: SpecificHeat) -> ThermalDiffusivity {
+	conductivity/(density*heat_capacity)
+}
+
+/// Thermal effusivity of a material with thermal conductivity `conductivity`, `density` and specific heat `heat_capacity`
+pub fn effusivity(conductivity: ThermalConductivity, density: Density, heat_capacity: SpecificHeat) -> ThermalEffusivity {
+	(conductivity*density*heat_capacity).root::<2>()
+}
+
+/// Depth a temperature change at the surface penetrates into a semi-infinite body of diffusivity `diffusivity` after `time`, √(αt)
+pub fn penetration_depth(diffusivity: ThermalDiffusivity, time: Time) -> Length {
+	(diffusivity*time).root::<2>()
+}
+
+/// Interface temperature when two semi-infinite bodies at `t1` and `t2` with effusivities `e1` and `e2` are brought into contact,
+/// e.g. how hot a metal or wooden surface feels to the touch
+pub fn contact_temperature(t1: Temperature, e1: ThermalEffusivity, t2: Temperature, e2: ThermalEffusivity) -> Temperature {
+	(t1*e1 + t2*e2)/(e1 + e2)
+}
+
+/// Handle to a node of an [RcNetwork]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node(usize);
+
+/// Lumped thermal RC network: nodes with heat capacities, dissipating power, joined to each other and to a fixed ambient temperature
+/// by thermal resistances
+#[derive(Clone, Debug)]
+pub struct RcNetwork {
+	ambient: Temperature,
+	capacitances: Vec<ThermalCapacitance>,
+	temperatures: Vec<Temperature>,
+	powers: Vec<Power>,
+	/// Conductances between pairs of nodes, or between a node and ambient
+	links: Vec<(usize, Option<usize>, ThermalConductance)>,
+}
+
+impl RcNetwork {
+	/// Empty network referenced to an `ambient` temperature
+	pub fn new(ambient: Temperature) -> RcNetwork {
+		RcNetwork { ambient, capacitances: Vec::new(), temperatures: Vec::new(), powers: Vec::new(), links: Vec::new() }
+	}
+
+	/// Add a node of heat capacity `capacitance`, starting at the ambient temperature.  Panics unless `capacitance` is positive
+	pub fn add_node(&mut self, capacitance: ThermalCapacitance) -> Node {
+		assert!(capacitance.as_si() > 0.0, "node heat capacity must be positive");
+		self.capacitances.push(capacitance);
+		self.temperatures.push(self.ambient);
+		self.powers.push(Power::from_si(0.0));
+		Node(self.capacitances.len() - 1)
+	}
+	/// Join nodes `a` and `b` by a thermal `resistance`
+	pub fn connect(&mut self, a: Node, b: Node, resistance: ThermalResistance) -> &mut Self {
+		self.links.push((a.0, Some(b.0), 1.0/resistance));
+		self
+	}
+	/// Join `node` to ambient by a thermal `resistance`
+	pub fn connect_ambient(&mut self, node: Node, resistance: ThermalResistance) -> &mut Self {
+		self.links.push((node.0, None, 1.0/resistance));
+		self
+	}
+
+	/// Set the power dissipated in `node`
+	pub fn set_power(&mut self, node: Node, power: Power) -> &mut Self {
+		self.powers[node.0] = power;
+		self
+	}
+	pub fn set_ambient(&mut self, ambient: Temperature) -> &mut Self {
+		self.ambient = ambient;
+		self
+	}
+	pub fn set_temperature(&mut self, node: Node, temperature: Temperature) -> &mut Self {
+		self.temperatures[node.0] = temperature;
+		self
+	}
+	pub fn temperature(&self, node: Node) -> Temperature {
+		self.temperatures[node.0]
+	}
+
+	/// Advance the network by `interval` with the implicit (backward) Euler method, which is stable for any step size
+	pub fn step(&mut self, interval: Time) {
+		let storage: Vec<Float> = self.capacitances.iter().map(|&c| (c/interval).as_si()).collect();
+		let rhs: Vec<Float> = self.temperatures.iter().zip(&storage).map(|(t, s)| t.as_si()*s).collect();
+		let solution = self.solve(&storage, rhs).expect("a network of positive heat capacities is never singular");
+		self.temperatures = solution;
+	}
+
+	/// Advance the network by `duration` in steps of at most `interval`, returning the temperature of `node` after each step.  Panics
+	/// unless `interval` is positive
+	pub fn simulate(&mut self, duration: Time, interval: Time, node: Node) -> Vec<Temperature> {
+		assert!(interval.as_si() > 0.0, "simulation interval must be positive");
+		let steps = Float::from(duration/interval).ceil().max(0.0) as usize;
+		let interval = if steps > 0 { duration/(steps as Float) } else { interval };
+		(0..steps).map(|_| { self.step(interval); self.temperature(node) }).collect()
+	}
+
+	/// Temperatures every node settles to under the current powers, or [None] if some node has no thermal path to ambient
+	pub fn steady_state(&self) -> Option<Vec<Temperature>> {
+		self.solve(&vec![0.0; self.capacitances.len()], vec![0.0; self.capacitances.len()])
+	}
+
+	/// Solve (diag(storage) + G)·T = rhs + P + G<sub>amb</sub>·T<sub>amb</sub> for the node temperatures
+	fn solve(&self, storage: &[Float], mut rhs: Vec<Float>) -> Option<Vec<Temperature>> {
+		let n = storage.len();
+		let mut matrix = vec![vec![0.0; n]; n];
+		for i in 0..n {
+			matrix[i][i] = storage[i];
+			rhs[i] += self.powers[i].as_si();
+		}
+		for &(a, b, g) in &self.links {
+			let g = g.as_si();
+			matrix[a][a] += g;
+			match b {
+				Some(b) => {
+					matrix[b][b] += g;
+					matrix[a][b] -= g;
+					matrix[b][a] -= g;
+				},
+				None => rhs[a] += g*self.ambient.as_si(),
+			}
+		}
+		// Gaussian elimination with partial pivoting
+		let scale = matrix.iter().flatten().fold(0.0 as Float, |m, v| m.max(v.abs()));
+		for col in 0..n {
+			let pivot = (col..n).max_by(|&x, &y| matrix[x][col].abs().total_cmp(&matrix[y][col].abs()))?;
+			if matrix[pivot][col].abs() <= 1e-12*scale {
+				return None;
+			}
+			matrix.swap(col, pivot);
+			rhs.swap(col, pivot);
+			let (upper, lower) = matrix.split_at_mut(col + 1);
+			let pivot_row = &upper[col];
+			for (offset, row) in lower.iter_mut().enumerate() {
+				let factor = row[col]/pivot_row[col];
+				for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+					*x -= factor*p;
+				}
+				rhs[col + 1 + offset] -= factor*rhs[col];
+			}
+		}
+		let mut solution = vec![0.0; n];
+		for row in (0..n).rev() {
+			let sum: Float = (row+1..n).map(|k| matrix[row][k]*solution[k]).sum();
+			solution[row] = (rhs[row] - sum)/matrix[row][row];
+		}
+		Some(solution.into_iter().map(Temperature::from_si).collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn simulate_steps() {
+		let mut network = RcNetwork::new(293.15*KELVIN);
+		let node = network.add_node(100.0*JOULE/KELVIN);
+		network.connect_ambient(node, 1.0*KELVIN/WATT);
+		network.set_power(node, 10.0*WATT);
+		assert_eq!(network.simulate(10.0*SECOND, 3.0*SECOND, node).len(), 4);
+		assert!(network.simulate(0.0*SECOND, 1.0*SECOND, node).is_empty());
+	}
+
+	#[test]
+	#[should_panic(expected = "interval must be positive")]
+	fn simulate_zero_interval() {
+		let mut network = RcNetwork::new(293.15*KELVIN);
+		let node = network.add_node(100.0*JOULE/KELVIN);
+		network.simulate(10.0*SECOND, 0.0*SECOND, node);
+	}
+}
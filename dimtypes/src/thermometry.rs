@@ -0,0 +1,228 @@
+//! Temperature from thermocouple voltages and RTD resistances, enabled with the `thermometry` feature
+//!
+//! Thermocouples use the NIST ITS-90 reference functions (NIST Monograph 175): the polynomial for the EMF of a junction relative to
+//! 0 °C, and the inverse polynomials for temperature from EMF, which agree with it to within 0.1 °C.  A thermocouple measures the
+//! difference between its hot and cold junctions, so [Thermocouple::temperature] compensates for the cold junction by adding the EMF
+//! it would produce at its temperature (usually measured by a sensor on the terminal block) before inverting.
+//!
+//! Platinum RTDs follow the Callendar-Van Dusen equation with the IEC 60751 coefficients.
+
+use std::fmt;
+use crate::Float;
+use crate::dimens::*;
+use crate::units::{CELSIUS,MILLI,OHM,VOLT};
+
+/// A temperature or measurement outside the range a conversion is defined over
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutOfRange {
+	pub found: Float,
+	pub low: Float,
+	pub high: Float,
+	/// Units of the values, e.g. `"degC"` or `"mV"`
+	pub units: &'static str,
+}
+
+impl fmt::Display for OutOfRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} {} is outside the range {} to {} {}", self.found, self.units, self.low, self.high, self.units)
+	}
+}
+
+impl std::error::Error for OutOfRange {}
+
+/// Polynomial valid between `low` and `high`, with coefficients in increasing order of power.  The outer limits of the inverse
+/// polynomials are rounded outwards from the tabulated EMFs so the EMF at each end of the temperature range converts back
+struct Segment {
+	low: Float,
+	high: Float,
+	coefficients: &'static [Float],
+}
+
+fn evaluate(segments: &[Segment], x: Float, units: &'static str) -> Result<Float, OutOfRange> {
+	let low = segments[0].low;
+	let high = segments[segments.len() - 1].high;
+	let segment = segments.iter().find(|s| x >= s.low && x <= s.high)
+		.ok_or(OutOfRange { found: x, low, high, units })?;
+	Ok(segment.coefficients.iter().rev().fold(0.0, |sum, c| sum*x + c))
+}
+
+/// Standard thermocouple types
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Thermocouple {
+	/// Chromel-alumel, -270 °C to 1372 °C
+	K,
+	/// Iron-constantan, -210 °C to 1200 °C
+	J,
+	/// Copper-constantan, -270 °C to 400 °C
+	T,
+}
+
+const K_EMF: [Segment; 2] = [
+	Segment { low: -270.0, high: 0.0, coefficients: &[
+		0.0, 0.394501280250e-1, 0.236223735980e-4, -0.328589067840e-6, -0.499048287770e-8, -0.675090591730e-10, -0.574103274280e-12,
+		-0.310888728940e-14, -0.104516093650e-16, -0.198892668780e-19, -0.163226974860e-22] },
+	Segment { low: 0.0, high: 1372.0, coefficients: &[
+		-0.176004136860e-1, 0.389212049750e-1, 0.185587700320e-4, -0.994575928740e-7, 0.318409457190e-9, -0.560728448890e-12,
+		0.560750590590e-15, -0.320207200030e-18, 0.971511471520e-22, -0.121047212750e-25] },
+];
+const K_TEMPERATURE: [Segment; 3] = [
+	Segment { low: -5.892, high: 0.0, coefficients: &[
+		0.0, 2.5173462e1, -1.1662878, -1.0833638, -8.9773540e-1, -3.7342377e-1, -8.6632643e-2, -1.0450598e-2, -5.1920577e-4] },
+	Segment { low: 0.0, high: 20.644, coefficients: &[
+		0.0, 2.508355e1, 7.860106e-2, -2.503131e-1, 8.315270e-2, -1.228034e-2, 9.804036e-4, -4.413030e-5, 1.057734e-6, -1.052755e-8] },
+	Segment { low: 20.644, high: 54.887, coefficients: &[
+		-1.318058e2, 4.830222e1, -1.646031, 5.464731e-2, -9.650715e-4, 8.802193e-6, -3.110810e-8] },
+];
+
+const J_EMF: [Segment; 2] = [
+	Segment { low: -210.0, high: 760.0, coefficients: &[
+		0.0, 0.503811878150e-1, 0.304758369300e-4, -0.856810657200e-7, 0.132281952950e-9, -0.170529583370e-12, 0.209480906970e-15,
+		-0.125383953360e-18, 0.156317256970e-22] },
+	Segment { low: 760.0, high: 1200.0, coefficients: &[
+		0.296456256810e3, -0.149761277860e1, 0.317871039240e-2, -0.318476867010e-5, 0.157208190040e-8, -0.306913690560e-12] },
+];
+const J_TEMPERATURE: [Segment; 3] = [
+	Segment { low: -8.096, high: 0.0, coefficients: &[
+		0.0, 1.9528268e1, -1.2286185, -1.0752178, -5.9086933e-1, -1.7256713e-1, -2.8131513e-2, -2.3963370e-3, -8.3823321e-5] },
+	Segment { low: 0.0, high: 42.919, coefficients: &[
+		0.0, 1.978425e1, -2.001204e-1, 1.036969e-2, -2.549687e-4, 3.585153e-6, -5.344285e-8, 5.099890e-10] },
+	Segment { low: 42.919, high: 69.554, coefficients: &[
+		-3.11358187e3, 3.00543684e2, -9.94773230, 1.70276630e-1, -1.43033468e-3, 4.73886084e-6] },
+];
+
+const T_EMF: [Segment; 2] = [
+	Segment { low: -270.0, high: 0.0, coefficients: &[
+		0.0, 0.387481063640e-1, 0.441944343470e-4, 0.118443231050e-6, 0.200329735540e-7, 0.901380195590e-9, 0.226511565930e-10,
+		0.360711542050e-12, 0.384939398830e-14, 0.282135219250e-16, 0.142515947790e-18, 0.487686622860e-21, 0.107955392700e-23,
+		0.139450270620e-26, 0.797951539270e-30] },
+	Segment { low: 0.0, high: 400.0, coefficients: &[
+		0.0, 0.387481063640e-1, 0.332922278800e-4, 0.206182434040e-6, -0.218822568460e-8, 0.109968809280e-10, -0.308157587720e-13,
+		0.454791352900e-16, -0.275129016730e-19] },
+];
+const T_TEMPERATURE: [Segment; 2] = [
+	Segment { low: -5.604, high: 0.0, coefficients: &[
+		0.0, 2.5949192e1, -2.1316967e-1, 7.9018692e-1, 4.2527777e-1, 1.3304473e-1, 2.0241446e-2, 1.2668171e-3] },
+	Segment { low: 0.0, high: 20.873, coefficients: &[
+		0.0, 2.592800e1, -7.602961e-1, 4.637791e-2, -2.165394e-3, 6.048144e-5, -7.293422e-7] },
+];
+
+impl Thermocouple {
+	fn segments(self) -> (&'static [Segment], &'static [Segment]) {
+		match self {
+			Thermocouple::K => (&K_EMF, &K_TEMPERATURE),
+			Thermocouple::J => (&J_EMF, &J_TEMPERATURE),
+			Thermocouple::T => (&T_EMF, &T_TEMPERATURE),
+		}
+	}
+
+	/// EMF of a junction at temperature `t` relative to one at 0 °C
+	pub fn emf(self, t: Temperature) -> Result<Voltage, OutOfRange> {
+		let t_c = t.as_unit(CELSIUS);
+		let mut mv = evaluate(self.segments().0, t_c, "degC")?;
+		if self == Thermocouple::K && t_c > 0.0 {
+			mv += 0.1185976*Float::exp(-0.1183432e-3*(t_c - 126.9686).powi(2));
+		}
+		Ok(mv*MILLI*VOLT)
+	}
+
+	/// Temperature of a junction with EMF `emf` relative to one at 0 °C
+	pub fn temperature_from_zero(self, emf: Voltage) -> Result<Temperature, OutOfRange> {
+		let mv = emf.as_unit(MILLI*VOLT);
+		Ok(evaluate(self.segments().1, mv, "mV")?*CELSIUS)
+	}
+
+	/// Temperature of the hot junction when the thermocouple reads `emf` with its cold junction at `cold_junction`
+	pub fn temperature(self, emf: Voltage, cold_junction: Temperature) -> Result<Temperature, OutOfRange> {
+		self.temperature_from_zero(emf + self.emf(cold_junction)?)
+	}
+}
+
+/// Platinum resistance thermometer with resistance `r0` at 0 °C, valid from -200 °C to 850 °C
+#[derive(Clone, Copy, Debug)]
+pub struct Rtd {
+	r0: Resistance,
+}
+
+pub const PT100: Rtd = Rtd::new(100.0*OHM);
+pub const PT1000: Rtd = Rtd::new(1000.0*OHM);
+
+const A: Float = 3.9083e-3;
+const B: Float = -5.775e-7;
+const C: Float = -4.183e-12;
+
+impl Rtd {
+	pub const fn new(r0: Resistance) -> Rtd {
+		Rtd { r0 }
+	}
+
+	fn ratio(t_c: Float) -> Float {
+		let below_zero = if t_c < 0.0 { C*(t_c - 100.0)*t_c.powi(3) } else { 0.0 };
+		1.0 + A*t_c + B*t_c*t_c + below_zero
+	}
+
+	/// Resistance at temperature `t`
+	pub fn resistance(&self, t: Temperature) -> Result<Resistance, OutOfRange> {
+		let t_c = t.as_unit(CELSIUS);
+		// Compared in kelvin, as converting back to degrees Celsius can round a limit such as 850 °C just past itself
+		if !((-200.0*CELSIUS).as_si()..=(850.0*CELSIUS).as_si()).contains(&t.as_si()) {
+			return Err(OutOfRange { found: t_c, low: -200.0, high: 850.0, units: "degC" });
+		}
+		Ok(self.r0*Rtd::ratio(t_c.clamp(-200.0, 850.0)))
+	}
+
+	/// Temperature at resistance `r`.  Solved exactly above 0 °C, and by Newton's method on the full equation below it
+	pub fn temperature(&self, r: Resistance) -> Result<Temperature, OutOfRange> {
+		// Limits computed as by [Rtd::resistance], so the resistances at the ends of the range convert back
+		let (low, high) = ((self.r0*Rtd::ratio(-200.0)).as_si(), (self.r0*Rtd::ratio(850.0)).as_si());
+		if !(low..=high).contains(&r.as_si()) {
+			return Err(OutOfRange { found: r.as_si(), low, high, units: "ohm" });
+		}
+		let ratio: Float = (r/self.r0).into();
+		let mut t_c = (-A + (A*A - 4.0*B*(1.0 - ratio)).sqrt())/(2.0*B);
+		if ratio < 1.0 {
+			for _ in 0..8 {
+				let slope = A + 2.0*B*t_c + C*(4.0*t_c - 300.0)*t_c*t_c;
+				t_c -= (Rtd::ratio(t_c) - ratio)/slope;
+			}
+		}
+		Ok(t_c*CELSIUS)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn table_points() {
+		assert!((Thermocouple::K.emf(100.0*CELSIUS).unwrap().as_unit(MILLI*VOLT) - 4.096).abs() < 5e-4);
+		assert!((PT100.resistance(-100.0*CELSIUS).unwrap().as_unit(OHM) - 60.26).abs() < 5e-3);
+		for t in [-200.0, -100.0, 0.0, 100.0, 850.0] {
+			let back = PT100.temperature(PT100.resistance(t*CELSIUS).unwrap()).unwrap().as_unit(CELSIUS);
+			assert!((back - t).abs() < 1e-6, "PT100 at {} degC converts back to {} degC", t, back);
+		}
+	}
+
+	#[test]
+	fn round_trip_at_segment_boundaries() {
+		let boundaries = [
+			(Thermocouple::K, &[-200.0, 0.0, 500.0, 1372.0][..]),
+			(Thermocouple::J, &[-210.0, 0.0, 760.0, 1200.0]),
+			(Thermocouple::T, &[-200.0, 0.0, 400.0]),
+		];
+		for (thermocouple, temperatures) in boundaries {
+			for &t in temperatures {
+				let emf = thermocouple.emf(t*CELSIUS).unwrap();
+				let back = thermocouple.temperature_from_zero(emf).unwrap().as_unit(CELSIUS);
+				assert!((back - t).abs() < 0.1, "{:?} at {} degC converts back to {} degC", thermocouple, t, back);
+			}
+		}
+	}
+
+	#[test]
+	fn out_of_range() {
+		assert!(Thermocouple::T.emf(500.0*CELSIUS).is_err());
+		assert!(Thermocouple::K.temperature_from_zero(60.0*MILLI*VOLT).is_err());
+		assert!(PT100.resistance(900.0*CELSIUS).is_err());
+	}
+}
@@ -0,0 +1,96 @@
+//! Queueing and throughput of systems from Little's law and utilization
+//!
+//! Arrival, service and completion rates are [CountRate] quantities, items such as requests, jobs or packets per unit time, and the
+//! work in a system is a [Count] of items.  [littles_law] and its rearrangements hold for any stable system in steady state
+//! regardless of the arrival and service distributions.  The `mm1_` and `mmc_` functions assume Poisson arrivals and exponentially
+//! distributed service times, the textbook M/M/1 and M/M/c queues, and are only meaningful below full utilization.
+
+use crate::Float;
+use crate::dimens::*;
+use crate::units::COUNT;
+
+/// Mean number of items in a system, L = λW, from the rate at which items arrive and their mean time in the system
+pub fn littles_law(arrival_rate: CountRate, time_in_system: Time) -> Count {
+	arrival_rate*time_in_system
+}
+
+/// Throughput sustaining `in_system` items each spending `time_in_system`, λ = L/W
+pub fn arrival_rate(in_system: Count, time_in_system: Time) -> CountRate {
+	in_system/time_in_system
+}
+
+/// Mean time an item spends in a system holding `in_system` items at throughput `arrival_rate`, W = L/λ
+pub fn time_in_system(in_system: Count, arrival_rate: CountRate) -> Time {
+	in_system/arrival_rate
+}
+
+/// Rate at which one server completes items taking `service_time` each, μ = 1/S
+pub fn service_rate(service_time: Time) -> CountRate {
+	COUNT/service_time
+}
+
+/// Fraction of the time `servers` servers of rate `service_rate` each are busy, ρ = λ/(cμ).  At or above 1 the queue grows without
+/// bound
+pub fn utilization(arrival_rate: CountRate, service_rate: CountRate, servers: usize) -> Unitless {
+	arrival_rate/(servers as Float*service_rate)
+}
+
+/// Largest arrival rate `servers` servers of rate `service_rate` each can sustain at `max_utilization`, e.g. 0.8 to leave headroom
+pub fn capacity(service_rate: CountRate, servers: usize, max_utilization: Unitless) -> CountRate {
+	servers as Float*service_rate*max_utilization
+}
+
+/// Servers of rate `service_rate` each needed to keep utilization at or below `max_utilization` at `arrival_rate`
+pub fn servers_needed(arrival_rate: CountRate, service_rate: CountRate, max_utilization: Unitless) -> usize {
+	Float::from(arrival_rate/(service_rate*max_utilization)).ceil() as usize
+}
+
+/// Mean time in an M/M/1 system, waiting plus service, W = 1/(μ - λ).  Infinite at or above full utilization
+pub fn mm1_time_in_system(arrival_rate: CountRate, service_rate: CountRate) -> Time {
+	if arrival_rate.as_si() >= service_rate.as_si() {
+		return Time::from_si(Float::INFINITY);
+	}
+	COUNT/(service_rate - arrival_rate)
+}
+
+/// Mean number of items in an M/M/1 system, L = ρ/(1 - ρ)
+pub fn mm1_in_system(arrival_rate: CountRate, service_rate: CountRate) -> Count {
+	littles_law(arrival_rate, mm1_time_in_system(arrival_rate, service_rate))
+}
+
+/// Mean time waiting for a server in an M/M/1 queue, W<sub>q</sub> = W - 1/μ
+pub fn mm1_wait(arrival_rate: CountRate, service_rate: CountRate) -> Time {
+	mm1_time_in_system(arrival_rate, service_rate) - COUNT/service_rate
+}
+
+/// Probability an arrival has to wait for one of `servers` servers in an M/M/c queue, the Erlang C formula
+pub fn erlang_c(arrival_rate: CountRate, service_rate: CountRate, servers: usize) -> Unitless {
+	let rho: Float = utilization(arrival_rate, service_rate, servers).into();
+	if rho >= 1.0 {
+		return Unitless::from(1.0);
+	}
+	let load: Float = (arrival_rate/service_rate).into();
+	// Terms a^k/k! of the Poisson sum, built incrementally
+	let mut term = 1.0;
+	let mut sum = 0.0;
+	for k in 0..servers {
+		sum += term;
+		term *= load/(k + 1) as Float;
+	}
+	let queued = term/(1.0 - rho);
+	Unitless::from(queued/(sum + queued))
+}
+
+/// Mean time waiting for one of `servers` servers in an M/M/c queue, W<sub>q</sub> = C/(cμ - λ)
+pub fn mmc_wait(arrival_rate: CountRate, service_rate: CountRate, servers: usize) -> Time {
+	let spare = servers as Float*service_rate - arrival_rate;
+	if spare.as_si() <= 0.0 {
+		return Time::from_si(Float::INFINITY);
+	}
+	erlang_c(arrival_rate, service_rate, servers)*COUNT/spare
+}
+
+/// Mean time in an M/M/c system, waiting plus service
+pub fn mmc_time_in_system(arrival_rate: CountRate, service_rate: CountRate, servers: usize) -> Time {
+	mmc_wait(arrival_rate, service_rate, servers) + COUNT/service_rate
+}
@@ -0,0 +1,121 @@
+//! Tolerance stack-up analysis
+//!
+//! A [Stack] is a one-dimensional chain of dimensioned contributors (lengths, angles, ...) each with a nominal value and tolerance band,
+//! added or subtracted to form an assembly dimension such as a gap.  The stack can be evaluated by worst-case, root-sum-square (RSS) or
+//! Monte-Carlo methods.
+
+use crate::{Float,Power,Quantity};
+use crate::uncertainty::{Distribution,MonteCarlo,Summary};
+
+/// Single contributor of a [Stack]
+#[derive(Clone, Debug)]
+pub struct Contributor<Q> {
+	pub name: String,
+	pub nominal: Q,
+	/// Upper deviation from nominal (positive for a tolerance above nominal)
+	pub plus: Q,
+	/// Lower deviation from nominal (positive for a tolerance below nominal)
+	pub minus: Q,
+	/// +1 if the contributor adds to the assembly dimension, -1 if it subtracts
+	pub direction: Float,
+	/// Distribution of the contributor's actual value used by [Stack::monte_carlo]
+	pub distribution: Distribution<Q>,
+}
+
+/// Range of an assembly dimension computed by a [Stack]
+#[derive(Clone, Copy, Debug)]
+pub struct StackResult<Q> {
+	pub nominal: Q,
+	pub min: Q,
+	pub max: Q,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+StackResult<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	/// Half the width of the range, i.e. the equivalent symmetric ± tolerance
+	pub fn tolerance(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { (self.max - self.min)/2.0 }
+	/// Midpoint of the range
+	pub fn center(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { (self.max + self.min)/2.0 }
+}
+
+/// Chain of toleranced contributors forming an assembly dimension
+#[derive(Clone, Debug)]
+pub struct Stack<Q> {
+	contributors: Vec<Contributor<Q>>,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Default for Stack<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	fn default() -> Self { Stack { contributors: Vec::new() } }
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Stack<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	pub fn new() -> Self {
+		Stack::default()
+	}
+
+	fn push(&mut self, name: &str, nominal: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, plus: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, minus: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, direction: Float) -> &mut Self {
+		// By default assume a centered normal process with the tolerance band at ±3σ
+		let distribution = Distribution::normal(nominal + (plus - minus)/2.0, (plus + minus)/6.0);
+		self.contributors.push(Contributor { name: name.to_string(), nominal, plus, minus, direction, distribution });
+		self
+	}
+
+	/// Add a contributor `nominal` ± `tolerance` which increases the assembly dimension
+	pub fn add(&mut self, name: &str, nominal: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, tolerance: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		self.push(name, nominal, tolerance, tolerance, 1.0)
+	}
+	/// Add a contributor `nominal` ± `tolerance` which decreases the assembly dimension
+	pub fn subtract(&mut self, name: &str, nominal: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, tolerance: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> &mut Self {
+		self.push(name, nominal, tolerance, tolerance, -1.0)
+	}
+	/// Add a contributor with unequal tolerances `nominal` +`plus` −`minus`.  Set `direction` to 1 for contributors which increase the
+	/// assembly dimension and -1 for those which decrease it
+	pub fn add_asymmetric(&mut self, name: &str, nominal: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, plus: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, minus: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, direction: Float) -> &mut Self {
+		self.push(name, nominal, plus, minus, direction.signum())
+	}
+	/// Override the distribution of the most recently added contributor used by [Stack::monte_carlo].  By default contributors are
+	/// normally distributed over their tolerance band at ±3σ
+	pub fn with_distribution(&mut self, distribution: Distribution<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> &mut Self {
+		if let Some(last) = self.contributors.last_mut() {
+			last.distribution = distribution;
+		}
+		self
+	}
+
+	pub fn contributors(&self) -> &[Contributor<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>] {
+		&self.contributors
+	}
+
+	/// Nominal assembly dimension
+	pub fn nominal(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		self.contributors.iter().fold(Quantity::from_si(0.0), |sum, c| sum + c.direction*c.nominal)
+	}
+
+	/// Worst-case range, with every contributor simultaneously at the limit of its band
+	pub fn worst_case(&self) -> StackResult<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		let (min, max) = self.contributors.iter().fold((Quantity::from_si(0.0), Quantity::from_si(0.0)), |(min, max), c| {
+			let (low, high) = (c.nominal - c.minus, c.nominal + c.plus);
+			if c.direction > 0.0 { (min + low, max + high) } else { (min - high, max - low) }
+		});
+		StackResult { nominal: self.nominal(), min, max }
+	}
+
+	/// Statistical range by root-sum-square of the contributor half-bands.  Asymmetric bands are first re-centered on their midpoints
+	pub fn rss(&self) -> StackResult<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		let center = self.contributors.iter().fold(Quantity::from_si(0.0), |sum, c| sum + c.direction*(c.nominal + (c.plus - c.minus)/2.0));
+		let half: Float = self.contributors.iter().map(|c| ((c.plus + c.minus).as_si()/2.0).powi(2)).sum::<Float>().sqrt();
+		let half = Quantity::from_si(half);
+		StackResult { nominal: self.nominal(), min: center - half, max: center + half }
+	}
+
+	/// Distribution of the assembly dimension from `trials` random assemblies drawn from each contributor's distribution
+	pub fn monte_carlo(&self, trials: usize) -> Summary<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> where
+		Power<2,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+	{
+		MonteCarlo::new(trials).run(|s| {
+			self.contributors.iter().fold(Quantity::from_si(0.0), |sum, c| sum + c.direction*s.draw(&c.distribution))
+		})
+	}
+}
@@ -0,0 +1,634 @@
+//! Uncertainty propagation for dimensioned models
+//!
+//! [MonteCarlo] propagates input distributions through an arbitrary closure over typed quantities by sampling, which remains valid
+//! when the model is too nonlinear for first-order propagation.  Inputs are declared as [Distribution]s and drawn inside the closure
+//! with [Sampler::draw], so the closure reads like the deterministic model (e.g. `|s| s.draw(&length)*s.draw(&width)`).
+//!
+//! [Budget] performs the first-order (GUM) analysis instead, reporting each input's sensitivity coefficient and contribution to the
+//! combined standard uncertainty as an uncertainty budget table.
+//!
+//! For everyday lab work a [Measurement] carries a value with its standard uncertainty through ordinary arithmetic, propagating the
+//! uncertainty by the first-order rules as it goes, and displays as e.g. `9.81 ± 0.02 m s^-2`.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add,Sub,Mul,Div,Neg};
+use crate::{Dimension,Float,Power,Product,Quotient,Quantity};
+use crate::stats;
+
+/// Small, fast pseudo-random number generator (xoshiro256**) used for sampling.  Not suitable for cryptographic use
+#[derive(Clone, Debug)]
+pub struct Rng {
+	state: [u64; 4],
+	/// Second normal value of the last Box-Muller pair, returned by the next call to [Rng::next_normal]
+	spare: Option<Float>,
+}
+
+impl Rng {
+	/// Create a generator from a `seed`.  The same seed always produces the same sequence
+	pub fn new(seed: u64) -> Rng {
+		// Expand the seed with splitmix64 so that similar seeds give unrelated states
+		let mut x = seed;
+		let mut next = || {
+			x = x.wrapping_add(0x9e3779b97f4a7c15);
+			let mut z = x;
+			z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+			z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+			z ^ (z >> 31)
+		};
+		Rng { state: [next(), next(), next(), next()], spare: None }
+	}
+
+	/// Next raw 64-bit output
+	pub fn next_u64(&mut self) -> u64 {
+		let s = &mut self.state;
+		let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+		let t = s[1] << 17;
+		s[2] ^= s[0];
+		s[3] ^= s[1];
+		s[1] ^= s[2];
+		s[0] ^= s[3];
+		s[2] ^= t;
+		s[3] = s[3].rotate_left(45);
+		result
+	}
+
+	/// Uniformly distributed value in [0, 1)
+	pub fn next_f64(&mut self) -> Float {
+		(self.next_u64() >> 11) as Float * (1.0/(1u64 << 53) as Float)
+	}
+
+	/// Standard normally distributed value (mean 0, standard deviation 1)
+	pub fn next_normal(&mut self) -> Float {
+		if let Some(z) = self.spare.take() {
+			return z;
+		}
+		// Box-Muller, which gives two independent values per pair of uniforms; 1-u keeps the argument of ln away from zero
+		let r = (-2.0*(1.0 - self.next_f64()).ln()).sqrt();
+		let theta = crate::float_consts::TAU*self.next_f64();
+		self.spare = Some(r*theta.sin());
+		r*theta.cos()
+	}
+}
+
+/// Probability distribution of an uncertain input quantity `Q`
+#[derive(Clone, Copy, Debug)]
+pub enum Distribution<Q> {
+	/// Exactly known value
+	Constant(Q),
+	/// Gaussian with the given mean and standard deviation
+	Normal { mean: Q, std_dev: Q },
+	/// Rectangular between `low` and `high`
+	Uniform { low: Q, high: Q },
+	/// Triangular between `low` and `high` peaking at `mode`
+	Triangular { low: Q, mode: Q, high: Q },
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Distribution<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	pub fn normal(mean: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, std_dev: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Distribution::Normal { mean, std_dev }
+	}
+	pub fn uniform(low: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, high: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Distribution::Uniform { low, high }
+	}
+	/// Uniform distribution of half-width `half_width` centered on `nominal`, as for a symmetric tolerance
+	pub fn uniform_about(nominal: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, half_width: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Distribution::Uniform { low: nominal - half_width, high: nominal + half_width }
+	}
+	pub fn triangular(low: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, mode: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, high: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Distribution::Triangular { low, mode, high }
+	}
+
+	/// Draw a single sample using `rng`
+	pub fn sample(&self, rng: &mut Rng) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		match *self {
+			Distribution::Constant(value) => value,
+			Distribution::Normal { mean, std_dev } => mean + rng.next_normal()*std_dev,
+			Distribution::Uniform { low, high } => low + rng.next_f64()*(high - low),
+			Distribution::Triangular { low, mode, high } => {
+				let (a, c, b) = (low.as_si(), mode.as_si(), high.as_si());
+				let u = rng.next_f64();
+				let split = (c - a)/(b - a);
+				Quantity::from_si(if u < split {
+					a + (u*(b - a)*(c - a)).sqrt()
+				} else {
+					b - ((1.0 - u)*(b - a)*(b - c)).sqrt()
+				})
+			},
+		}
+	}
+
+	/// Mean of this distribution
+	pub fn mean(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		match *self {
+			Distribution::Constant(value) => value,
+			Distribution::Normal { mean, .. } => mean,
+			Distribution::Uniform { low, high } => (low + high)/2.0,
+			Distribution::Triangular { low, mode, high } => (low + mode + high)/3.0,
+		}
+	}
+
+	/// Standard deviation (standard uncertainty) of this distribution
+	pub fn std_dev(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		match *self {
+			Distribution::Constant(_) => Quantity::from_si(0.0),
+			Distribution::Normal { std_dev, .. } => std_dev,
+			Distribution::Uniform { low, high } => (high - low)/Float::sqrt(12.0),
+			Distribution::Triangular { low, mode, high } => {
+				let (a, c, b) = (low.as_si(), mode.as_si(), high.as_si());
+				Quantity::from_si(((a*a + b*b + c*c - a*b - a*c - b*c)/18.0).sqrt())
+			},
+		}
+	}
+}
+
+/// Source of input samples handed to the model closure by [MonteCarlo::run]
+pub struct Sampler<'a> {
+	rng: &'a mut Rng
+}
+
+impl Sampler<'_> {
+	/// Draw a value of an input quantity from its `distribution`
+	pub fn draw<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, distribution: &Distribution<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		distribution.sample(self.rng)
+	}
+
+	/// Access the underlying generator, e.g. to draw correlated inputs
+	pub fn rng(&mut self) -> &mut Rng { self.rng }
+}
+
+/// Sampling-based uncertainty propagation engine
+#[derive(Clone, Copy, Debug)]
+pub struct MonteCarlo {
+	trials: usize,
+	seed: u64,
+}
+
+impl MonteCarlo {
+	/// Create an engine which evaluates the model `trials` times
+	pub fn new(trials: usize) -> MonteCarlo {
+		MonteCarlo { trials, seed: 0x5eed }
+	}
+
+	/// Use a specific random `seed` (results are reproducible for a given seed)
+	pub fn seed(self, seed: u64) -> MonteCarlo {
+		MonteCarlo { seed, ..self }
+	}
+
+	/// Evaluate `model` once per trial, drawing its inputs from the provided [Sampler], and summarize the distribution of the output
+	pub fn run<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, mut model: impl FnMut(&mut Sampler) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Summary<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		let mut rng = Rng::new(self.seed);
+		let mut samples: Vec<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> = (0..self.trials)
+			.map(|_| model(&mut Sampler { rng: &mut rng }))
+			.collect();
+		samples.sort_by(|a, b| a.as_si().total_cmp(&b.as_si()));
+		Summary { samples }
+	}
+}
+
+/// Empirical distribution of a model output produced by [MonteCarlo::run]
+#[derive(Clone, Debug)]
+pub struct Summary<Q> {
+	samples: Vec<Q>
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Summary<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> where
+	Power<2,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+{
+	pub fn mean(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { stats::mean(&self.samples) }
+	pub fn std_dev(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { stats::std_dev(&self.samples) }
+	/// The `p`th percentile (0 to 100) of the output
+	pub fn percentile(&self, p: Float) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { stats::percentile(&self.samples, p) }
+	/// Probabilistically symmetric interval containing the fraction `coverage` of the output (e.g. 0.95)
+	pub fn coverage_interval(&self, coverage: Float) -> (Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) {
+		let tail = 50.0*(1.0 - coverage);
+		(self.percentile(tail), self.percentile(100.0 - tail))
+	}
+	/// All output samples in ascending order
+	pub fn samples(&self) -> &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>] { &self.samples }
+}
+
+
+/// Handle to an input quantity declared in a [Budget], used to read its value inside the model closure
+#[derive(Debug)]
+pub struct Input<Q> {
+	index: usize,
+	kind: PhantomData<Q>,
+}
+impl<Q> Clone for Input<Q> {
+	fn clone(&self) -> Self { *self }
+}
+impl<Q> Copy for Input<Q> {}
+
+/// Values of the inputs of a [Budget] passed to the model closure
+pub struct Inputs<'a> {
+	values: &'a [Float]
+}
+
+impl Inputs<'_> {
+	/// Value of the declared `input`
+	pub fn get<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, input: Input<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.values[input.index])
+	}
+}
+
+#[derive(Clone, Debug)]
+struct BudgetInput {
+	name: String,
+	estimate: Float,
+	std_uncertainty: Float,
+	dimension: Dimension,
+}
+
+/// Builder for a GUM-style uncertainty budget.  Declare each input with its estimate and standard uncertainty using [Budget::input],
+/// then [evaluate][Budget::evaluate] a model written over the returned [Input] handles.  Sensitivity coefficients are found by central
+/// differences with a step of one standard uncertainty, as suggested by the GUM for models without convenient analytic derivatives.
+#[derive(Clone, Debug, Default)]
+pub struct Budget {
+	inputs: Vec<BudgetInput>,
+}
+
+impl Budget {
+	pub fn new() -> Budget {
+		Budget::default()
+	}
+
+	/// Declare an input named `name` with best `estimate` and standard uncertainty `std_uncertainty`
+	pub fn input<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, estimate: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, std_uncertainty: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Input<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		self.inputs.push(BudgetInput {
+			name: name.to_string(),
+			estimate: estimate.as_si(),
+			std_uncertainty: std_uncertainty.as_si().abs(),
+			dimension: Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION,
+		});
+		Input { index: self.inputs.len() - 1, kind: PhantomData }
+	}
+
+	/// Declare an input named `name` whose estimate and standard uncertainty are the mean and standard deviation of `distribution`
+	/// (e.g. a rectangular distribution for a resolution or tolerance limit)
+	pub fn input_from<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&mut self, name: &str, distribution: &Distribution<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>>) -> Input<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		self.input(name, distribution.mean(), distribution.std_dev())
+	}
+
+	/// Evaluate `model` at the input estimates and compute each input's sensitivity coefficient and uncertainty contribution
+	pub fn evaluate<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+		(&self, model: impl Fn(&Inputs) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> BudgetReport<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		let mut values: Vec<Float> = self.inputs.iter().map(|x| x.estimate).collect();
+		let value = model(&Inputs { values: &values });
+		let output_dimension = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		let rows = self.inputs.iter().enumerate().map(|(i, input)| {
+			let step = if input.std_uncertainty > 0.0 {
+				input.std_uncertainty
+			} else {
+				input.estimate.abs().max(1.0)*1e-6
+			};
+			values[i] = input.estimate + step;
+			let upper = model(&Inputs { values: &values }).as_si();
+			values[i] = input.estimate - step;
+			let lower = model(&Inputs { values: &values }).as_si();
+			values[i] = input.estimate;
+			let sensitivity = (upper - lower)/(2.0*step);
+			BudgetRow {
+				name: input.name.clone(),
+				estimate: input.estimate,
+				std_uncertainty: input.std_uncertainty,
+				dimension: input.dimension,
+				sensitivity,
+				sensitivity_dimension: output_dimension/input.dimension,
+				contribution: (sensitivity*input.std_uncertainty).abs(),
+			}
+		}).collect();
+		BudgetReport { value, rows, coverage_factor: 2.0 }
+	}
+}
+
+/// One line of a [BudgetReport].  Values are in SI base units of the stated dimensions
+#[derive(Clone, Debug)]
+pub struct BudgetRow {
+	pub name: String,
+	pub estimate: Float,
+	pub std_uncertainty: Float,
+	/// Dimension of the input's estimate and standard uncertainty
+	pub dimension: Dimension,
+	/// Sensitivity coefficient c<sub>i</sub> = ∂y/∂x<sub>i</sub>
+	pub sensitivity: Float,
+	pub sensitivity_dimension: Dimension,
+	/// Uncertainty contribution |c<sub>i</sub>|·u(x<sub>i</sub>), in the dimension of the output
+	pub contribution: Float,
+}
+
+/// Result of evaluating a [Budget].  [Display][fmt::Display] renders the GUM-style budget table followed by the combined and expanded uncertainty.
+/// The precision flag sets the significant digits shown (default 4)
+#[derive(Clone, Debug)]
+pub struct BudgetReport<Q> {
+	value: Q,
+	rows: Vec<BudgetRow>,
+	coverage_factor: Float,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+BudgetReport<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	/// Output estimate evaluated at the input estimates
+	pub fn value(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { self.value }
+	/// Rows of the budget in the order the inputs were declared
+	pub fn rows(&self) -> &[BudgetRow] { &self.rows }
+	/// Combined standard uncertainty u<sub>c</sub>, the root sum of squares of the contributions (inputs are assumed uncorrelated)
+	pub fn combined_uncertainty(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		Quantity::from_si(self.rows.iter().map(|r| r.contribution*r.contribution).sum::<Float>().sqrt())
+	}
+	/// Use coverage factor `k` for the expanded uncertainty (the default is k = 2, approximately 95 % coverage)
+	pub fn with_coverage_factor(self, k: Float) -> Self {
+		BudgetReport { coverage_factor: k, ..self }
+	}
+	/// Expanded uncertainty U = k·u<sub>c</sub>
+	pub fn expanded_uncertainty(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> {
+		self.coverage_factor*self.combined_uncertainty()
+	}
+	/// Fraction of the output variance contributed by each input, in declaration order
+	pub fn variance_fractions(&self) -> Vec<Float> {
+		let total: Float = self.rows.iter().map(|r| r.contribution*r.contribution).sum();
+		self.rows.iter().map(|r| r.contribution*r.contribution/total).collect()
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Display for BudgetReport<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let digits = f.precision().unwrap_or(4).max(1) - 1;
+		let with_unit = |value: Float, dimension: Dimension| {
+			if dimension.is_dimensionless() {
+				format!("{:.*e}", digits, value)
+			} else {
+				format!("{:.*e} {}", digits, value, dimension)
+			}
+		};
+		let output_dimension = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		let header = ["Quantity", "Estimate", "Std. uncertainty", "Sensitivity coeff.", "Contribution", "Index"];
+		let mut table: Vec<[String; 6]> = vec![header.map(String::from)];
+		for (row, fraction) in self.rows.iter().zip(self.variance_fractions()) {
+			table.push([
+				row.name.clone(),
+				with_unit(row.estimate, row.dimension),
+				with_unit(row.std_uncertainty, row.dimension),
+				with_unit(row.sensitivity, row.sensitivity_dimension),
+				with_unit(row.contribution, output_dimension),
+				format!("{:.1} %", 100.0*fraction),
+			]);
+		}
+		let mut widths = [0; 6];
+		for line in &table {
+			for (width, cell) in widths.iter_mut().zip(line) {
+				*width = (*width).max(cell.chars().count());
+			}
+		}
+		for line in &table {
+			let cells: Vec<String> = line.iter().zip(widths).map(|(cell, width)| format!("{:<1$}", cell, width)).collect();
+			writeln!(f, "{}", cells.join(" | ").trim_end())?;
+		}
+		writeln!(f, "Result: {}", with_unit(self.value.as_si(), output_dimension))?;
+		writeln!(f, "Combined standard uncertainty: {}", with_unit(self.combined_uncertainty().as_si(), output_dimension))?;
+		write!(f, "Expanded uncertainty (k = {}): {}", self.coverage_factor, with_unit(self.expanded_uncertainty().as_si(), output_dimension))
+	}
+}
+
+
+/// Measured value with its standard uncertainty.  Arithmetic propagates the uncertainty to first order assuming the operands are
+/// uncorrelated, so `x - x` has uncertainty √2·u(x) rather than zero; use a [Budget] for models with shared inputs.  Quantities and
+/// floats combine with measurements as exact values.  [Display][fmt::Display] rounds the uncertainty to one significant digit (two if
+/// it starts with a 1) and the value to match, including to tens or more for large uncertainties, unless a precision is given
+#[derive(Clone, Copy, Debug)]
+pub struct Measurement<Q> {
+	value: Q,
+	uncertainty: Q,
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	/// Measurement of `value` with standard uncertainty `uncertainty`
+	pub fn new(value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>, uncertainty: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Measurement { value, uncertainty: Quantity::from_si(uncertainty.as_si().abs()) }
+	}
+
+	/// Exactly known value
+	pub fn exact(value: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Measurement::new(value, Quantity::from_si(0.0))
+	}
+
+	/// Mean of repeated readings `values`, with the standard deviation of the mean as its uncertainty (a GUM type A evaluation)
+	pub fn from_readings(values: &[Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>]) -> Self where
+		Power<2,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+	{
+		Measurement::new(stats::mean(values), stats::std_dev(values)/(values.len() as Float).sqrt())
+	}
+
+	pub fn value(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { self.value }
+	/// Standard uncertainty
+	pub fn uncertainty(&self) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { self.uncertainty }
+	/// Standard uncertainty as a fraction of the magnitude of the value
+	pub fn relative_uncertainty(&self) -> Float { (self.uncertainty.as_si()/self.value.as_si()).abs() }
+	/// Expanded uncertainty with coverage factor `k`, e.g. 2 for approximately 95 % coverage
+	pub fn expanded_uncertainty(&self, k: Float) -> Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D> { k*self.uncertainty }
+
+	/// Normal distribution of this measurement, for use as an input to a [MonteCarlo] model
+	pub fn distribution(&self) -> Distribution<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+		Distribution::normal(self.value, self.uncertainty)
+	}
+
+	/// Raise to the integer power `P`, which multiplies the relative uncertainty by |P|.  An uncertain zero raised to a positive power
+	/// above 1 has zero uncertainty to first order, and to a negative power an infinite one
+	pub fn pow<const P: isize>(self) -> Measurement<Power<P,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> where
+		Power<P,1,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+	{
+		let value = self.value.pow::<P>();
+		// u·|P·x^(P-1)|, written without dividing by x so a zero value does not give NaN
+		let uncertainty = match (P, self.uncertainty.as_si()) {
+			(0, _) | (_, 0.0) => 0.0,
+			(_, u) => (P as Float*self.value.as_si().powi(P as i32 - 1)*u).abs(),
+		};
+		Measurement { value, uncertainty: Quantity::from_si(uncertainty) }
+	}
+
+	/// Take the `R`th root, which divides the relative uncertainty by R
+	pub fn root<const R: isize>(self) -> Measurement<Power<1,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> where
+		Power<1,R,T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>: Sized
+	{
+		let value = self.value.root::<R>();
+		let r = R as Float;
+		Measurement { value, uncertainty: Quantity::from_si((value.as_si()*self.uncertainty.as_si()/(r*self.value.as_si())).abs()) }
+	}
+}
+
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Add for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn add(self, rhs: Self) -> Self {
+		Measurement { value: self.value + rhs.value, uncertainty: Quantity::from_si(self.uncertainty.as_si().hypot(rhs.uncertainty.as_si())) }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Sub for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn sub(self, rhs: Self) -> Self {
+		Measurement { value: self.value - rhs.value, uncertainty: Quantity::from_si(self.uncertainty.as_si().hypot(rhs.uncertainty.as_si())) }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Neg for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn neg(self) -> Self {
+		Measurement { value: -self.value, ..self }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Add<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn add(self, rhs: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Measurement { value: self.value + rhs, ..self }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Sub<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn sub(self, rhs: Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>) -> Self {
+		Measurement { value: self.value - rhs, ..self }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Mul<Float> for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn mul(self, rhs: Float) -> Self {
+		Measurement { value: self.value*rhs, uncertainty: self.uncertainty*rhs.abs() }
+	}
+}
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+Div<Float> for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	type Output = Self;
+	fn div(self, rhs: Float) -> Self {
+		Measurement { value: self.value/rhs, uncertainty: self.uncertainty/rhs.abs() }
+	}
+}
+
+/// Uncertainty of the product of uncorrelated `a` and `b`, u = √((b·u<sub>a</sub>)² + (a·u<sub>b</sub>)²)
+fn product_uncertainty(a: Float, ua: Float, b: Float, ub: Float) -> Float {
+	(b*ua).hypot(a*ub)
+}
+
+/// Uncertainty of the quotient of uncorrelated `a` and `b`, u = √((u<sub>a</sub>/b)² + (a·u<sub>b</sub>/b²)²)
+fn quotient_uncertainty(a: Float, ua: Float, b: Float, ub: Float) -> Float {
+	(ua/b).hypot(a*ub/(b*b))
+}
+
+impl<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+Mul<Measurement<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for Measurement<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Measurement<Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>;
+	fn mul(self, rhs: Measurement<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output {
+		let u = product_uncertainty(self.value.as_si(), self.uncertainty.as_si(), rhs.value.as_si(), rhs.uncertainty.as_si());
+		Measurement { value: self.value*rhs.value, uncertainty: Quantity::from_si(u) }
+	}
+}
+impl<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+Div<Measurement<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>> for Measurement<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Measurement<Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>;
+	fn div(self, rhs: Measurement<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>) -> Self::Output {
+		let u = quotient_uncertainty(self.value.as_si(), self.uncertainty.as_si(), rhs.value.as_si(), rhs.uncertainty.as_si());
+		Measurement { value: self.value/rhs.value, uncertainty: Quantity::from_si(u) }
+	}
+}
+impl<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+Mul<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for Measurement<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Measurement<Product<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>;
+	fn mul(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Self::Output {
+		Measurement { value: self.value*rhs, uncertainty: Quantity::from_si((self.uncertainty.as_si()*rhs.as_si()).abs()) }
+	}
+}
+impl<const T1: isize, const L1: isize, const M1: isize, const I1: isize, const TEMP1: isize, const MOL1: isize, const LUM1: isize, const ANG1: isize, const CNT1: isize, const D1: isize,
+	const T2: isize, const L2: isize, const M2: isize, const I2: isize, const TEMP2: isize, const MOL2: isize, const LUM2: isize, const ANG2: isize, const CNT2: isize, const D2: isize>
+Div<Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>> for Measurement<Quantity<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1>> where
+	Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>: Sized
+{
+	type Output = Measurement<Quotient<T1,L1,M1,I1,TEMP1,MOL1,LUM1,ANG1,CNT1,D1,T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>>;
+	fn div(self, rhs: Quantity<T2,L2,M2,I2,TEMP2,MOL2,LUM2,ANG2,CNT2,D2>) -> Self::Output {
+		Measurement { value: self.value/rhs, uncertainty: Quantity::from_si((self.uncertainty.as_si()/rhs.as_si()).abs()) }
+	}
+}
+
+/// Formats as `value ± uncertainty units`, e.g. `9.81 ± 0.02 m s^-2`.  The alternate form (`{:#}`) names the units by their SI
+/// derived unit where there is one, as for [Quantity]
+impl<const T: isize, const L: isize, const M: isize, const I: isize, const TEMP: isize, const MOL: isize, const LUM: isize, const ANG: isize, const CNT: isize, const D: isize>
+fmt::Display for Measurement<Quantity<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (mut value, mut uncertainty) = (self.value.as_si(), self.uncertainty.as_si());
+		let decimals = match f.precision() {
+			Some(digits) => digits,
+			None if uncertainty > 0.0 && uncertainty.is_finite() => {
+				let magnitude = uncertainty.log10().floor();
+				let leading = (uncertainty/(10.0 as Float).powf(magnitude)).floor();
+				let significant = if leading < 2.0 { 2.0 } else { 1.0 };
+				let place = significant - 1.0 - magnitude;
+				if place < 0.0 {
+					// Significant digits end left of the decimal point, so round to tens, hundreds, ... as well as printing no decimals
+					let step = (10.0 as Float).powf(-place);
+					value = (value/step).round()*step;
+					uncertainty = (uncertainty/step).round()*step;
+				}
+				place.max(0.0) as usize
+			},
+			None => return fmt::Display::fmt(&self.value, f),
+		};
+		write!(f, "{:.*} ± {:.*}", decimals, value, decimals, uncertainty)?;
+		let dimension = Quantity::<T,L,M,I,TEMP,MOL,LUM,ANG,CNT,D>::DIMENSION;
+		if !dimension.is_dimensionless() {
+			if f.alternate() {
+				write!(f, " {:#}", dimension)?;
+			} else {
+				write!(f, " {}", dimension)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::units::*;
+
+	#[test]
+	fn display_rounding() {
+		assert_eq!(Measurement::new(9.8123*METER, 0.0234*METER).to_string(), "9.81 ± 0.02 m");
+		assert_eq!(Measurement::new(9.8123*METER, 0.0134*METER).to_string(), "9.812 ± 0.013 m");
+		assert_eq!(Measurement::new(123.4*METER, 1.6*METER).to_string(), "123.4 ± 1.6 m");
+		assert_eq!(Measurement::new(1234.5*METER, 37.0*METER).to_string(), "1230 ± 40 m");
+		assert_eq!(Measurement::new(1234.4*METER, 12.0*METER).to_string(), "1234 ± 12 m");
+		assert_eq!(Measurement::new(98765.0*METER, 1540.0*METER).to_string(), "98800 ± 1500 m");
+		assert_eq!(format!("{:.1}", Measurement::new(1234.5*METER, 37.0*METER)), "1234.5 ± 37.0 m");
+	}
+
+	#[test]
+	fn pow_of_zero() {
+		let x = Measurement::new(0.0*METER, 0.1*METER);
+		assert_eq!(x.pow::<1>().uncertainty().as_si(), 0.1);
+		assert_eq!(x.pow::<2>().uncertainty().as_si(), 0.0);
+		assert_eq!(x.pow::<0>().uncertainty().as_si(), 0.0);
+		assert_eq!(x.pow::<-1>().uncertainty().as_si(), Float::INFINITY);
+		assert_eq!(Measurement::exact(0.0*METER).pow::<-1>().uncertainty().as_si(), 0.0);
+		let y = Measurement::new(2.0*METER, 0.1*METER).pow::<3>();
+		assert!((y.uncertainty().as_si() - 1.2).abs() < 1e-5);
+	}
+}
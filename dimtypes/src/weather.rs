@@ -0,0 +1,39 @@
+//! Apparent temperature correlations used in weather forecasting
+//!
+//! The NOAA correlations are defined in terms of °F, mph and percent relative humidity.  These functions take typed inputs and handle
+//! the conversions internally, so callers can work in whatever units they have.
+
+use crate::dimens::*;
+use crate::units::{FAHRENHEIT,HOUR,MILE,PERCENT};
+
+/// Wind chill temperature for air temperature `t` and wind speed `v` (measured at 10 m) using the 2001 NWS/MSC formula.
+/// The index is only defined for temperatures at or below 50 °F and wind speeds above 3 mph; outside that range the air temperature is returned unchanged
+pub fn wind_chill(t: Temperature, v: Velocity) -> Temperature {
+	let t_f = t.as_unit(FAHRENHEIT);
+	let v_mph = v.as_unit(MILE/HOUR);
+	if t_f > 50.0 || v_mph <= 3.0 {
+		return t;
+	}
+	let v16 = v_mph.powf(0.16);
+	(35.74 + 0.6215*t_f - 35.75*v16 + 0.4275*t_f*v16)*FAHRENHEIT
+}
+
+/// Heat index (apparent temperature) for air temperature `t` and relative humidity `rh` (e.g. `60.0*PERCENT`) using the NWS algorithm:
+/// Steadman's simple formula for mild conditions, and the Rothfusz regression with its low- and high-humidity adjustments above 80 °F
+pub fn heat_index(t: Temperature, rh: Unitless) -> Temperature {
+	let t_f = t.as_unit(FAHRENHEIT);
+	let rh_pct = rh.as_unit(PERCENT);
+	let simple = 0.5*(t_f + 61.0 + (t_f - 68.0)*1.2 + rh_pct*0.094);
+	if (simple + t_f)/2.0 < 80.0 {
+		return simple*FAHRENHEIT;
+	}
+	let mut hi = -42.379 + 2.04901523*t_f + 10.14333127*rh_pct - 0.22475541*t_f*rh_pct
+		- 6.83783e-3*t_f*t_f - 5.481717e-2*rh_pct*rh_pct + 1.22874e-3*t_f*t_f*rh_pct
+		+ 8.5282e-4*t_f*rh_pct*rh_pct - 1.99e-6*t_f*t_f*rh_pct*rh_pct;
+	if rh_pct < 13.0 && (80.0..=112.0).contains(&t_f) {
+		hi -= (13.0 - rh_pct)/4.0*((17.0 - (t_f - 95.0).abs())/17.0).sqrt();
+	} else if rh_pct > 85.0 && (80.0..=87.0).contains(&t_f) {
+		hi += (rh_pct - 85.0)/10.0*(87.0 - t_f)/5.0;
+	}
+	hi*FAHRENHEIT
+}
@@ -0,0 +1,49 @@
+//! Compiles the code [Shim::to_rust] generates for a sample struct, kept in `migrate/motor.rs`, and checks it is still what the
+//! generator writes
+#![cfg(all(feature = "std", not(feature = "stable")))]
+#![cfg_attr(feature = "f32", allow(clippy::excessive_precision))]
+
+use dimtypes::Float;
+use dimtypes::dimens::*;
+#[cfg(not(feature = "f32"))]
+use dimtypes::migrate::Shim;
+use dimtypes::units::*;
+
+#[derive(Clone, Debug)]
+pub struct Motor {
+	pub torque: Float,
+	pub speed: Float,
+	pub temperature: Float,
+	pub poles: u32,
+}
+
+include!("migrate/motor.rs");
+
+#[cfg(not(feature = "f32"))]
+fn shim() -> Shim {
+	let mut shim = Shim::new("Motor", "TypedMotor");
+	shim.quantity("torque", "Torque", "lbf ft").unwrap();
+	shim.quantity("speed", "AngularVelocity", "rpm").unwrap();
+	shim.quantity("temperature", "Temperature", "degC").unwrap();
+	shim.keep("poles", "u32").unwrap();
+	shim
+}
+
+// The unit scales are written with the precision of Float
+#[cfg(not(feature = "f32"))]
+#[test]
+fn generated_code_is_current() {
+	assert_eq!(shim().to_rust(), include_str!("migrate/motor.rs"));
+}
+
+#[test]
+fn conversions() {
+	let raw = Motor { torque: 30.0, speed: 6000.0, temperature: 25.0, poles: 14 };
+	let typed = TypedMotor::from(raw.clone());
+	assert!((typed.torque.as_unit(POUND_FOOT) - 30.0).abs() < 1e-4);
+	assert!((typed.speed.as_unit(RPM) - 6000.0).abs() < 1e-2);
+	assert!((typed.temperature.as_unit(KELVIN) - 298.15).abs() < 1e-3);
+	let back = Motor::from(typed);
+	assert!((back.torque - raw.torque).abs() < 1e-4);
+	assert_eq!(back.poles, raw.poles);
+}
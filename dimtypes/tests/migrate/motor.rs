@@ -0,0 +1,52 @@
+/// Typed copy of [Motor], generated by `dimtypes::migrate`
+#[derive(Clone, Debug)]
+pub struct TypedMotor {
+	/// Raw value in lbf ft
+	pub torque: Torque,
+	/// Raw value in rpm
+	pub speed: AngularVelocity,
+	/// Raw value in degC
+	pub temperature: Temperature,
+	pub poles: u32,
+}
+
+impl TypedMotor {
+	/// Unit of `Motor.torque`, lbf ft
+	pub const TORQUE_UNIT: ::dimtypes::parse::ParsedUnit = ::dimtypes::parse::ParsedUnit::with_offset(1.3558179483314003, 0.0,
+		::dimtypes::Dimension::new(-2,2,1,0,0,0,0,0,0).over(1))
+		.per_radian_as(<Torque as ::dimtypes::AnyQuantity>::DIMENSION);
+	/// Unit of `Motor.speed`, rpm
+	pub const SPEED_UNIT: ::dimtypes::parse::ParsedUnit = ::dimtypes::parse::ParsedUnit::with_offset(0.10471975511965977, 0.0,
+		::dimtypes::Dimension::new(-1,0,0,0,0,0,0,1,0).over(1))
+		.per_radian_as(<AngularVelocity as ::dimtypes::AnyQuantity>::DIMENSION);
+	/// Unit of `Motor.temperature`, degC
+	pub const TEMPERATURE_UNIT: ::dimtypes::parse::ParsedUnit = ::dimtypes::parse::ParsedUnit::with_offset(1.0, 273.15,
+		::dimtypes::Dimension::new(0,0,0,0,1,0,0,0,0).over(1))
+		.per_radian_as(<Temperature as ::dimtypes::AnyQuantity>::DIMENSION);
+}
+
+const _: () = ::core::assert!(TypedMotor::TORQUE_UNIT.dimension().equals(&<Torque as ::dimtypes::AnyQuantity>::DIMENSION), "unit `lbf ft` of Motor.torque does not have the dimension of `Torque`");
+const _: () = ::core::assert!(TypedMotor::SPEED_UNIT.dimension().equals(&<AngularVelocity as ::dimtypes::AnyQuantity>::DIMENSION), "unit `rpm` of Motor.speed does not have the dimension of `AngularVelocity`");
+const _: () = ::core::assert!(TypedMotor::TEMPERATURE_UNIT.dimension().equals(&<Temperature as ::dimtypes::AnyQuantity>::DIMENSION), "unit `degC` of Motor.temperature does not have the dimension of `Temperature`");
+
+impl From<Motor> for TypedMotor {
+	fn from(raw: Motor) -> TypedMotor {
+		TypedMotor {
+			torque: <Torque as ::dimtypes::AnyQuantity>::from_si(TypedMotor::TORQUE_UNIT.to_si(raw.torque)),
+			speed: <AngularVelocity as ::dimtypes::AnyQuantity>::from_si(TypedMotor::SPEED_UNIT.to_si(raw.speed)),
+			temperature: <Temperature as ::dimtypes::AnyQuantity>::from_si(TypedMotor::TEMPERATURE_UNIT.to_si(raw.temperature)),
+			poles: raw.poles,
+		}
+	}
+}
+
+impl From<TypedMotor> for Motor {
+	fn from(typed: TypedMotor) -> Motor {
+		Motor {
+			torque: TypedMotor::TORQUE_UNIT.from_si(::dimtypes::AnyQuantity::as_si(typed.torque)),
+			speed: TypedMotor::SPEED_UNIT.from_si(::dimtypes::AnyQuantity::as_si(typed.speed)),
+			temperature: TypedMotor::TEMPERATURE_UNIT.from_si(::dimtypes::AnyQuantity::as_si(typed.temperature)),
+			poles: typed.poles,
+		}
+	}
+}